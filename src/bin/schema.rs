@@ -1,7 +1,7 @@
 #[cfg(not(target_arch = "wasm32"))]
 use cosmwasm_schema::write_api;
 #[cfg(not(target_arch = "wasm32"))]
-use wasm_vault::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use wasm_vault::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {
@@ -9,6 +9,7 @@ fn main() {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
 
@@ -0,0 +1,243 @@
+use cosmwasm_std::{attr, DepsMut, MessageInfo, Response, Uint128};
+
+use crate::{
+    helpers::{require_owner, update_config},
+    state::OPERATOR,
+    ContractError,
+};
+
+pub fn set_operator(
+    deps: DepsMut,
+    info: MessageInfo,
+    operator: Option<String>,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    let operator = match operator {
+        Some(operator) => {
+            let operator = deps.api.addr_validate(&operator)?;
+            OPERATOR.save(deps.storage, &operator)?;
+            operator.into_string()
+        }
+        None => {
+            OPERATOR.remove(deps.storage);
+            String::new()
+        }
+    };
+
+    Ok(
+        Response::new()
+            .add_attributes([attr("action", "set_operator"), attr("operator", operator)]),
+    )
+}
+
+pub fn set_unbonding_seconds(
+    deps: DepsMut,
+    info: MessageInfo,
+    seconds: u64,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    if seconds == 0 {
+        return Err(ContractError::InvalidConfig {});
+    }
+
+    update_config(deps.storage, |config| {
+        config.unbonding_seconds = Some(seconds)
+    })?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "set_unbonding_seconds"),
+        attr("unbonding_seconds", seconds.to_string()),
+    ]))
+}
+
+pub fn set_max_per_validator(
+    deps: DepsMut,
+    info: MessageInfo,
+    cap: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    if cap.is_some_and(|cap| cap.is_zero()) {
+        return Err(ContractError::InvalidConfig {});
+    }
+    update_config(deps.storage, |config| config.max_per_validator = cap)?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "set_max_per_validator"),
+        attr(
+            "max_per_validator",
+            cap.map(|cap| cap.to_string()).unwrap_or_default(),
+        ),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::{query, staking, withdraw};
+    use crate::helpers::load_config;
+    use crate::msg::{LiquidationSettlementResponse, QueryMsg};
+    use crate::state::{LAST_LIQUIDATION_UNBONDING, LIQUIDATION_UNBONDING_AMOUNT, OWNER};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{from_json, Timestamp, Uint256};
+
+    #[test]
+    fn rejects_non_owner_and_zero() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+
+        let intruder = deps.api.addr_make("intruder");
+        let err =
+            set_unbonding_seconds(deps.as_mut(), message_info(&intruder, &[]), 60).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = set_unbonding_seconds(deps.as_mut(), message_info(&owner, &[]), 0).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidConfig {}));
+    }
+
+    #[test]
+    fn updating_unbonding_seconds_moves_settlement_estimate() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+        LIQUIDATION_UNBONDING_AMOUNT
+            .save(deps.as_mut().storage, &Uint256::from(10u128))
+            .unwrap();
+        LAST_LIQUIDATION_UNBONDING
+            .save(deps.as_mut().storage, &Some(Timestamp::from_seconds(100)))
+            .unwrap();
+
+        let estimate = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+            let response = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::LiquidationSettlement {},
+            )
+            .unwrap();
+            from_json::<LiquidationSettlementResponse>(response)
+                .unwrap()
+                .estimated_completion
+        };
+
+        set_unbonding_seconds(deps.as_mut(), message_info(&owner, &[]), 1_000).unwrap();
+        assert_eq!(estimate(&deps), Some(Timestamp::from_seconds(1_100)));
+
+        let response =
+            set_unbonding_seconds(deps.as_mut(), message_info(&owner, &[]), 5_000).unwrap();
+        assert!(response
+            .attributes
+            .contains(&attr("unbonding_seconds", "5000")));
+        assert_eq!(estimate(&deps), Some(Timestamp::from_seconds(5_100)));
+    }
+
+    #[test]
+    fn set_operator_is_owner_only_and_revocable() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+        let operator = deps.api.addr_make("operator");
+
+        let err = set_operator(
+            deps.as_mut(),
+            message_info(&operator, &[]),
+            Some(operator.to_string()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        set_operator(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Some(operator.to_string()),
+        )
+        .unwrap();
+        assert_eq!(OPERATOR.load(deps.as_ref().storage).unwrap(), operator);
+
+        set_operator(deps.as_mut(), message_info(&owner, &[]), None).unwrap();
+        assert!(!OPERATOR.exists(deps.as_ref().storage));
+    }
+
+    #[test]
+    fn operator_can_stake_but_not_withdraw() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+        let operator = deps.api.addr_make("operator");
+        set_operator(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Some(operator.to_string()),
+        )
+        .unwrap();
+
+        let validator = deps.api.addr_make("validator").into_string();
+        let err = staking::undelegate::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            validator,
+            Uint128::new(10),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DelegationNotFound { .. }));
+
+        let err = withdraw::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            "ucosm".to_string(),
+            Uint128::new(10),
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn set_max_per_validator_is_owner_only_and_removable() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+
+        let intruder = deps.api.addr_make("intruder");
+        let err = set_max_per_validator(
+            deps.as_mut(),
+            message_info(&intruder, &[]),
+            Some(Uint128::new(100)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = set_max_per_validator(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Some(Uint128::zero()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidConfig {}));
+
+        set_max_per_validator(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Some(Uint128::new(100)),
+        )
+        .unwrap();
+        assert_eq!(
+            load_config(deps.as_ref().storage)
+                .unwrap()
+                .max_per_validator,
+            Some(Uint128::new(100))
+        );
+
+        set_max_per_validator(deps.as_mut(), message_info(&owner, &[]), None).unwrap();
+        assert_eq!(
+            load_config(deps.as_ref().storage)
+                .unwrap()
+                .max_per_validator,
+            None
+        );
+    }
+}
@@ -1,15 +1,21 @@
 use cosmwasm_std::{
-    attr, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    attr, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
+    Uint256,
 };
 
 use crate::{
-    contract::open_interest::set_active_lender,
+    contract::open_interest::{
+        build_repayment_amounts, set_active_lender, validate_loan_reference, validate_open_interest,
+    },
     error::ContractError,
-    helpers::require_owner,
-    state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST, OUTSTANDING_DEBT},
+    helpers::{hook_submessage, require_owner},
+    msg::HookMsg,
+    state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST, OUTSTANDING_DEBT, QUEUED_INTEREST},
     types::OpenInterest,
 };
 
+use super::helpers::is_offer_expired;
+
 pub fn accept(
     deps: DepsMut,
     env: Env,
@@ -19,7 +25,7 @@ pub fn accept(
 ) -> Result<Response, ContractError> {
     require_owner(&deps, &info)?;
 
-    OPEN_INTEREST
+    let active_interest = OPEN_INTEREST
         .load(deps.storage)?
         .ok_or(ContractError::NoOpenInterest {})?;
 
@@ -38,6 +44,23 @@ pub fn accept(
         return Err(ContractError::CounterOfferMismatch { proposer });
     }
 
+    if let Some(expired_at) = accepted_offer.offer_expiry {
+        if is_offer_expired(&accepted_offer, env.block.time) {
+            return Err(ContractError::CounterOfferExpired {
+                proposer,
+                expired_at,
+            });
+        }
+    }
+
+    // Only the amount comes from the offer; every other term stays as the owner set it.
+    let accepted_terms = OpenInterest {
+        liquidity_coin: accepted_offer.liquidity_coin.clone(),
+        ..active_interest
+    };
+    // Keep the accept path consistent with open: the repayment total must stay representable.
+    build_repayment_amounts(&accepted_terms)?;
+
     let offers = COUNTER_OFFERS
         .range(deps.storage, None, None, Order::Ascending)
         .collect::<StdResult<Vec<(Addr, OpenInterest)>>>()?;
@@ -53,14 +76,24 @@ pub fn accept(
         })
         .collect();
 
+    ensure_escrow_matches_debt(&deps, &refunds, &accepted_offer.liquidity_coin)?;
+
     COUNTER_OFFERS.clear(deps.storage);
 
     let expiry = env.block.time.plus_seconds(accepted_offer.expiry_duration);
-    OPEN_INTEREST.save(deps.storage, &Some(accepted_offer.clone()))?;
+    OPEN_INTEREST.save(deps.storage, &Some(accepted_terms.clone()))?;
     OUTSTANDING_DEBT.save(deps.storage, &None)?;
-    set_active_lender(deps.storage, lender_addr.clone(), expiry)?;
+    set_active_lender(deps.storage, lender_addr.clone(), env.block.time, expiry)?;
 
-    let mut response = Response::new().add_attributes([
+    let hook = hook_submessage(
+        deps.storage,
+        HookMsg::Funded {
+            lender: lender_addr.to_string(),
+            open_interest: accepted_terms,
+        },
+    )?;
+
+    let mut response = Response::new().add_submessages(hook).add_attributes([
         attr("action", "accept_counter_offer"),
         attr("lender", lender_addr.as_str()),
         attr(
@@ -80,13 +113,116 @@ pub fn accept(
     Ok(response)
 }
 
+pub fn accept_and_reopen(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposer: String,
+    next_interest: OpenInterest,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    let lender_addr = deps.api.addr_validate(&proposer)?;
+    let expected_interest = COUNTER_OFFERS
+        .may_load(deps.storage, &lender_addr)?
+        .ok_or_else(|| ContractError::CounterOfferNotFound {
+            proposer: proposer.clone(),
+        })?;
+
+    validate_open_interest(&deps.as_ref(), &env, &next_interest)?;
+    validate_loan_reference(next_interest.reference.as_deref())?;
+
+    let response = accept(deps.branch(), env, info, proposer, expected_interest)?;
+    QUEUED_INTEREST.save(deps.storage, &Some(next_interest.clone()))?;
+
+    Ok(response.add_attributes([
+        attr("queued_interest", next_interest.liquidity_coin.to_string()),
+        attr(
+            "queued_expiry_duration",
+            next_interest.expiry_duration.to_string(),
+        ),
+    ]))
+}
+
+/// Accepts the largest unexpired counter offer, ties going to the lowest proposer address, so the
+/// owner does not race offers changing between a query and the accept.
+pub fn accept_best(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    min_liquidity: Uint256,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    let mut best: Option<(Addr, OpenInterest)> = None;
+    // Ascending address order, so only a strictly larger offer replaces the current best.
+    for entry in COUNTER_OFFERS.range(deps.storage, None, None, Order::Ascending) {
+        let (proposer, offer) = entry?;
+        if is_offer_expired(&offer, env.block.time) {
+            continue;
+        }
+        let is_better = best
+            .as_ref()
+            .is_none_or(|(_, current)| offer.liquidity_coin.amount > current.liquidity_coin.amount);
+        if is_better {
+            best = Some((proposer, offer));
+        }
+    }
+
+    let Some((proposer, offer)) = best else {
+        return Err(ContractError::NoQualifyingOffer {
+            best: Uint256::zero(),
+        });
+    };
+    if offer.liquidity_coin.amount < min_liquidity {
+        return Err(ContractError::NoQualifyingOffer {
+            best: offer.liquidity_coin.amount,
+        });
+    }
+
+    let response = accept(deps, env, info, proposer.into_string(), offer)?;
+    Ok(response.add_attribute("min_liquidity", min_liquidity.to_string()))
+}
+
+/// Refunds plus the accepted escrow must account for exactly the tracked outstanding debt.
+fn ensure_escrow_matches_debt(
+    deps: &DepsMut,
+    refunds: &[(Addr, Coin)],
+    accepted: &Coin,
+) -> Result<(), ContractError> {
+    let escrowed = refunds
+        .iter()
+        .try_fold(accepted.amount, |total, (_, coin)| {
+            total.checked_add(coin.amount)
+        })
+        .map_err(StdError::from)?;
+
+    let tracked = match OUTSTANDING_DEBT.load(deps.storage)? {
+        Some(debt) if debt.denom == accepted.denom => debt.amount,
+        _ => Uint256::zero(),
+    };
+
+    if escrowed != tracked {
+        return Err(ContractError::DebtAccountingMismatch {
+            denom: accepted.denom.clone(),
+            escrowed,
+            tracked,
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::contract::counter_offer::propose;
     use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::open_interest::repay;
     use crate::error::ContractError;
-    use crate::state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST, OUTSTANDING_DEBT};
+    use crate::state::{
+        COUNTER_OFFERS, LENDER, OPEN_INTEREST, OPEN_INTEREST_FUNDED, OUTSTANDING_DEBT,
+    };
     use crate::types::OpenInterest;
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
     use cosmwasm_std::{attr, BankMsg, Coin, CosmosMsg, Order, Uint256};
@@ -315,6 +451,7 @@ mod tests {
             interest_coin: Coin::new(50u128, "ujuno"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(2_000u128, "uatom"),
+            ..Default::default()
         };
 
         propose(
@@ -422,4 +559,253 @@ mod tests {
             other => panic!("unexpected error: {:?}", other),
         }
     }
+
+    #[test]
+    fn accept_rejects_repayment_overflow() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let active = OpenInterest {
+            liquidity_coin: Coin::new(u128::MAX, "uusd"),
+            interest_coin: Coin::new(50u128, "uusd"),
+            expiry_duration: 86_400u64,
+            collateral: Coin::new(2_000u128, "uatom"),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(active.clone()))
+            .expect("open interest stored");
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(u128::MAX - 1);
+        COUNTER_OFFERS
+            .save(deps.as_mut().storage, &proposer, &offer)
+            .expect("offer stored");
+
+        let err = accept(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            proposer.to_string(),
+            offer,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::RepaymentAmountOverflow { denom, .. } if denom == "uusd"
+        ));
+        assert!(COUNTER_OFFERS.has(deps.as_ref().storage, &proposer));
+    }
+
+    #[test]
+    fn accept_rejects_when_tracked_debt_is_corrupted() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let accepted = deps.api.addr_make("accepted");
+        let mut accepted_offer = active.clone();
+        accepted_offer.liquidity_coin.amount = accepted_offer
+            .liquidity_coin
+            .amount
+            .checked_sub(Uint256::from(10u128))
+            .expect("amount stays positive");
+
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&accepted, &[accepted_offer.liquidity_coin.clone()]),
+            accepted_offer.clone(),
+        )
+        .expect("accepted proposer funds escrow");
+
+        let mut corrupted = accepted_offer.liquidity_coin.clone();
+        corrupted.amount += Uint256::from(1u128);
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(corrupted.clone()))
+            .expect("debt overwritten");
+
+        let err = accept(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            accepted.to_string(),
+            accepted_offer.clone(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::DebtAccountingMismatch { escrowed, tracked, .. }
+                if escrowed == accepted_offer.liquidity_coin.amount && tracked == corrupted.amount
+        ));
+        assert!(LENDER.load(deps.as_ref().storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn accept_and_reopen_queues_validated_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![Coin::new(2_000u128, "uatom")],
+        );
+
+        let accepted = deps.api.addr_make("accepted");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&accepted, &[offer.liquidity_coin.clone()]),
+            offer.clone(),
+        )
+        .expect("proposal stored");
+
+        let mut invalid_next = active.clone();
+        invalid_next.expiry_duration = 0;
+        let err = accept_and_reopen(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            accepted.to_string(),
+            invalid_next,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidExpiryDuration {}));
+        assert!(LENDER.load(deps.as_ref().storage).unwrap().is_none());
+
+        let response = accept_and_reopen(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            accepted.to_string(),
+            active.clone(),
+        )
+        .expect("accept and queue");
+
+        assert!(response
+            .attributes
+            .contains(&attr("action", "accept_counter_offer")));
+        assert!(response
+            .attributes
+            .contains(&attr("queued_interest", "1000uusd")));
+        assert_eq!(LENDER.load(deps.as_ref().storage).unwrap(), Some(accepted));
+        assert_eq!(
+            OPEN_INTEREST.load(deps.as_ref().storage).unwrap(),
+            Some(offer)
+        );
+        assert_eq!(
+            QUEUED_INTEREST.load(deps.as_ref().storage).unwrap(),
+            Some(active)
+        );
+    }
+
+    #[test]
+    fn accept_best_takes_largest_offer_above_minimum() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let mut offers = Vec::new();
+        for (label, amount) in [("small", 800u128), ("large", 950), ("expired", 990)] {
+            let proposer = deps.api.addr_make(label);
+            let mut offer = active.clone();
+            offer.liquidity_coin.amount = Uint256::from(amount);
+            if label == "expired" {
+                offer.offer_expiry = Some(mock_env().block.time.plus_seconds(60));
+            }
+            propose(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&proposer, &[offer.liquidity_coin.clone()]),
+                offer.clone(),
+            )
+            .expect("offer stored");
+            offers.push((proposer, offer));
+        }
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(60);
+        let err = accept_best(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            Uint256::from(960u128),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::NoQualifyingOffer { best } if best == Uint256::from(950u128)
+        ));
+
+        let response = accept_best(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            Uint256::from(900u128),
+        )
+        .expect("best offer accepted");
+
+        assert!(response
+            .attributes
+            .contains(&attr("lender", offers[1].0.as_str())));
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            LENDER.load(deps.as_ref().storage).unwrap(),
+            Some(offers[1].0.clone())
+        );
+    }
+
+    #[test]
+    fn accepted_loan_owes_late_fee_after_deadline() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let mut active = setup_open_interest(deps.as_mut(), &owner);
+        active.repayment_deadline_duration = Some(3_600);
+        active.late_fee_bps = Some(2_000);
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(active.clone()))
+            .expect("terms stored");
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer.clone(),
+        )
+        .expect("proposal stored");
+
+        let mut env = mock_env();
+        accept(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            proposer.to_string(),
+            offer,
+        )
+        .expect("offer accepted");
+        assert_eq!(
+            OPEN_INTEREST_FUNDED.load(deps.as_ref().storage).unwrap(),
+            env.block.time
+        );
+
+        env.block.time = env.block.time.plus_seconds(3_601);
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![Coin::new(900u128, "uusd"), Coin::new(60u128, "ujuno")],
+        );
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert!(response.attributes.contains(&attr("late_fee", "10ujuno")));
+    }
 }
@@ -7,7 +7,7 @@ use crate::{
 
 use super::helpers::release_outstanding_debt;
 
-pub fn cancel(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn cancel(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     OPEN_INTEREST
         .load(deps.storage)?
         .ok_or(ContractError::NoOpenInterest {})?;
@@ -19,6 +19,13 @@ pub fn cancel(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, C
             proposer: proposer.to_string(),
         })?;
 
+    // Keeps a proposer from pulling the offer the moment they see the owner accepting it.
+    if let Some(unlockable_at) = stored_offer.cancellable_after {
+        if env.block.time < unlockable_at {
+            return Err(ContractError::CancelLocked { unlockable_at });
+        }
+    }
+
     release_outstanding_debt(deps.storage, &stored_offer.liquidity_coin)?;
     COUNTER_OFFERS.remove(deps.storage, &proposer);
 
@@ -154,4 +161,35 @@ mod tests {
 
         assert!(matches!(err, ContractError::NoOpenInterest {}));
     }
+
+    #[test]
+    fn cancel_waits_for_cancellable_after() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut env = mock_env();
+        let unlockable_at = env.block.time.plus_seconds(600);
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        offer.cancellable_after = Some(unlockable_at);
+        propose(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+
+        let err = cancel(deps.as_mut(), env.clone(), message_info(&proposer, &[])).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::CancelLocked { unlockable_at: at } if at == unlockable_at
+        ));
+
+        env.block.time = unlockable_at;
+        cancel(deps.as_mut(), env, message_info(&proposer, &[])).expect("cancel unlocked");
+        assert!(!COUNTER_OFFERS.has(deps.as_ref().storage, &proposer));
+    }
 }
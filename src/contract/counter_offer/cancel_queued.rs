@@ -0,0 +1,77 @@
+use cosmwasm_std::{attr, DepsMut, MessageInfo, Response};
+
+use crate::{error::ContractError, helpers::require_owner, state::QUEUED_INTEREST};
+
+pub fn cancel_queued_interest(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    let queued = QUEUED_INTEREST
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::NoQueuedInterest {})?;
+    QUEUED_INTEREST.remove(deps.storage);
+
+    Ok(Response::new().add_attributes([
+        attr("action", "cancel_queued_interest"),
+        attr("queued_interest", queued.liquidity_coin.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::counter_offer::{accept_and_reopen, propose};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{Coin, Uint256};
+
+    #[test]
+    fn owner_cancels_queued_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let err = cancel_queued_interest(deps.as_mut(), message_info(&owner, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::NoQueuedInterest {}));
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![Coin::new(2_000u128, "uatom")],
+        );
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+        accept_and_reopen(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            proposer.to_string(),
+            active,
+        )
+        .expect("accept and queue");
+
+        let intruder = deps.api.addr_make("intruder");
+        let err = cancel_queued_interest(deps.as_mut(), message_info(&intruder, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let response = cancel_queued_interest(deps.as_mut(), message_info(&owner, &[]))
+            .expect("queued interest cancelled");
+
+        assert!(response
+            .attributes
+            .contains(&attr("action", "cancel_queued_interest")));
+        assert!(QUEUED_INTEREST
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+}
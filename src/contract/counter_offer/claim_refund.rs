@@ -0,0 +1,124 @@
+use cosmwasm_std::{
+    attr, BankMsg, Coin, DepsMut, MessageInfo, Order, Response, StdError, StdResult, Uint256,
+};
+
+use crate::{
+    error::ContractError,
+    state::{UNCLAIMED_REFUNDS, UNCLAIMED_REFUND_TOTALS},
+};
+
+pub fn claim_refund(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let claimant = info.sender;
+    let credited = UNCLAIMED_REFUNDS
+        .prefix(&claimant)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint256)>>>()?;
+
+    if credited.is_empty() {
+        return Err(ContractError::NoUnclaimedRefunds {
+            address: claimant.into_string(),
+        });
+    }
+
+    let mut refund = Vec::with_capacity(credited.len());
+    for (denom, amount) in credited {
+        UNCLAIMED_REFUNDS.remove(deps.storage, (&claimant, denom.as_str()));
+        let total = UNCLAIMED_REFUND_TOTALS
+            .load(deps.storage, denom.as_str())?
+            .checked_sub(amount)
+            .map_err(StdError::from)?;
+        if total.is_zero() {
+            UNCLAIMED_REFUND_TOTALS.remove(deps.storage, denom.as_str());
+        } else {
+            UNCLAIMED_REFUND_TOTALS.save(deps.storage, denom.as_str(), &total)?;
+        }
+        refund.push(Coin::new(amount, denom));
+    }
+
+    Ok(Response::new()
+        .add_attributes([
+            attr("action", "claim_refund"),
+            attr("claimant", claimant.as_str()),
+            attr("claimed_denoms", refund.len().to_string()),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: claimant.into_string(),
+            amount: refund,
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::propose;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::open_interest::close_to_unclaimed;
+    use crate::state::{COUNTER_OFFERS, OPEN_INTEREST, OUTSTANDING_DEBT};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::CosmosMsg;
+
+    #[test]
+    fn proposers_claim_refunds_credited_on_close() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer_a = deps.api.addr_make("proposer-a");
+        let proposer_b = deps.api.addr_make("proposer-b");
+        for (proposer, amount) in [(&proposer_a, 900u128), (&proposer_b, 800u128)] {
+            let mut offer = active.clone();
+            offer.liquidity_coin.amount = Uint256::from(amount);
+            propose(
+                deps.as_mut(),
+                mock_env(),
+                message_info(proposer, &[offer.liquidity_coin.clone()]),
+                offer,
+            )
+            .expect("proposal stored");
+        }
+
+        let response = close_to_unclaimed(deps.as_mut(), mock_env(), message_info(&owner, &[]))
+            .expect("close succeeds");
+
+        assert!(response.messages.is_empty());
+        assert!(response.attributes.contains(&attr("credited_refunds", "2")));
+        assert!(OPEN_INTEREST.load(deps.as_ref().storage).unwrap().is_none());
+        assert!(OUTSTANDING_DEBT
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+        assert!(COUNTER_OFFERS.is_empty(deps.as_ref().storage));
+        assert_eq!(
+            UNCLAIMED_REFUND_TOTALS
+                .load(deps.as_ref().storage, "uusd")
+                .unwrap(),
+            Uint256::from(1_700u128)
+        );
+
+        let response =
+            claim_refund(deps.as_mut(), message_info(&proposer_a, &[])).expect("claim succeeds");
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: proposer_a.to_string(),
+                amount: vec![Coin::new(900u128, "uusd")],
+            })
+        );
+        assert_eq!(
+            UNCLAIMED_REFUND_TOTALS
+                .load(deps.as_ref().storage, "uusd")
+                .unwrap(),
+            Uint256::from(800u128)
+        );
+
+        let err = claim_refund(deps.as_mut(), message_info(&proposer_a, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::NoUnclaimedRefunds { .. }));
+
+        claim_refund(deps.as_mut(), message_info(&proposer_b, &[])).expect("claim succeeds");
+        assert!(UNCLAIMED_REFUND_TOTALS
+            .may_load(deps.as_ref().storage, "uusd")
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,125 @@
+use cosmwasm_std::{attr, BankMsg, DepsMut, Env, Response};
+
+use crate::{error::ContractError, state::COUNTER_OFFERS};
+
+use super::helpers::{is_offer_expired, release_outstanding_debt};
+
+pub fn expire(deps: DepsMut, env: Env, proposer: String) -> Result<Response, ContractError> {
+    let proposer_addr = deps.api.addr_validate(&proposer)?;
+    let stored_offer = COUNTER_OFFERS
+        .may_load(deps.storage, &proposer_addr)?
+        .ok_or_else(|| ContractError::CounterOfferNotFound {
+            proposer: proposer.clone(),
+        })?;
+
+    if !is_offer_expired(&stored_offer, env.block.time) {
+        return Err(ContractError::CounterOfferNotExpired {
+            proposer,
+            expires_at: stored_offer.offer_expiry,
+        });
+    }
+
+    release_outstanding_debt(deps.storage, &stored_offer.liquidity_coin)?;
+    COUNTER_OFFERS.remove(deps.storage, &proposer_addr);
+
+    Ok(Response::new()
+        .add_attributes([
+            attr("action", "expire_counter_offer"),
+            attr("proposer", proposer_addr.as_str()),
+            attr(
+                "liquidity_amount",
+                stored_offer.liquidity_coin.amount.to_string(),
+            ),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: proposer_addr.into_string(),
+            amount: vec![stored_offer.liquidity_coin],
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::counter_offer::{accept, propose};
+    use crate::state::OUTSTANDING_DEBT;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{Coin, CosmosMsg, Uint256};
+
+    #[test]
+    fn only_expired_offers_can_be_removed() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut env = mock_env();
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        offer.offer_expiry = Some(env.block.time.plus_seconds(60));
+        propose(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer.clone(),
+        )
+        .expect("proposal stored");
+
+        let err = expire(deps.as_mut(), env.clone(), proposer.to_string()).unwrap_err();
+        assert!(matches!(err, ContractError::CounterOfferNotExpired { .. }));
+
+        env.block.time = env.block.time.plus_seconds(60);
+        let err = accept(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            proposer.to_string(),
+            offer,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CounterOfferExpired { .. }));
+
+        let response =
+            expire(deps.as_mut(), env, proposer.to_string()).expect("expired offer removed");
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: proposer.to_string(),
+                amount: vec![Coin::new(900u128, "uusd")],
+            })
+        );
+        assert!(!COUNTER_OFFERS.has(deps.as_ref().storage, &proposer));
+        assert_eq!(OUTSTANDING_DEBT.load(deps.as_ref().storage).unwrap(), None);
+    }
+
+    #[test]
+    fn offers_without_expiry_never_expire() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(10_000_000);
+        let err = expire(deps.as_mut(), env, proposer.to_string()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::CounterOfferNotExpired {
+                expires_at: None,
+                ..
+            }
+        ));
+    }
+}
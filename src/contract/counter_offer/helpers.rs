@@ -1,8 +1,12 @@
-use cosmwasm_std::{Addr, Coin, MessageInfo, Order, StdError, StdResult, Storage, Uint256};
+use cosmwasm_std::{
+    Addr, Coin, MessageInfo, Order, StdError, StdResult, Storage, Timestamp, Uint256,
+};
 
 use crate::{
+    contract::open_interest::validate_denom,
     error::ContractError,
-    state::{COUNTER_OFFERS, MAX_COUNTER_OFFERS, OUTSTANDING_DEBT},
+    helpers::load_config,
+    state::{COUNTER_OFFERS, MAX_BPS, MAX_CONFIGURABLE_COUNTER_OFFERS, OUTSTANDING_DEBT},
     types::OpenInterest,
 };
 
@@ -10,10 +14,25 @@ pub(crate) fn validate_counter_offer(
     active: &OpenInterest,
     proposed: &OpenInterest,
 ) -> Result<(), ContractError> {
+    // A malformed denom is reported as such rather than as a terms mismatch.
+    validate_denom(&proposed.liquidity_coin.denom, "liquidity_coin")?;
+    validate_denom(&proposed.interest_coin.denom, "interest_coin")?;
+    validate_denom(&proposed.collateral.denom, "collateral")?;
+
+    if proposed.late_fee_bps.unwrap_or(0) > MAX_BPS {
+        return Err(ContractError::InvalidConfig {});
+    }
+
+    // Accepting adopts the offer as the live terms, so everything but the amount must match.
     if proposed.liquidity_coin.denom != active.liquidity_coin.denom
         || proposed.interest_coin != active.interest_coin
         || proposed.collateral != active.collateral
         || proposed.expiry_duration != active.expiry_duration
+        || proposed.min_open_duration != active.min_open_duration
+        || proposed.repayment_deadline_duration != active.repayment_deadline_duration
+        || proposed.late_fee_bps != active.late_fee_bps
+        || proposed.reference != active.reference
+        || proposed.interest_nonce != active.interest_nonce
     {
         return Err(ContractError::CounterOfferTermsMismatch {});
     }
@@ -31,17 +50,32 @@ pub(crate) fn validate_counter_offer(
     Ok(())
 }
 
+/// Offers without an `offer_expiry` never expire.
+pub(crate) fn is_offer_expired(offer: &OpenInterest, now: Timestamp) -> bool {
+    offer.offer_expiry.is_some_and(|expiry| now >= expiry)
+}
+
 pub(crate) fn validate_counter_offer_escrow(
     info: &MessageInfo,
     proposed: &OpenInterest,
 ) -> Result<(), ContractError> {
     let denom = &proposed.liquidity_coin.denom;
+    // Coins in any other denom would be locked with no refund path.
+    if let Some(stray) = info.funds.iter().find(|coin| coin.denom != *denom) {
+        return Err(ContractError::UnexpectedFunds {
+            denom: stray.denom.clone(),
+        });
+    }
+
     let expected = proposed.liquidity_coin.amount;
     let received = info
         .funds
         .iter()
         .filter(|coin| coin.denom == *denom)
-        .fold(Uint256::zero(), |acc, coin| acc + coin.amount);
+        .try_fold(Uint256::zero(), |acc, coin| acc.checked_add(coin.amount))
+        .map_err(|_| ContractError::EscrowSumOverflow {
+            denom: denom.clone(),
+        })?;
 
     if received != expected {
         return Err(ContractError::CounterOfferEscrowMismatch {
@@ -101,7 +135,7 @@ pub(crate) fn determine_eviction_candidate(
     let Some((count, (worst_addr, worst_offer))) = snapshot else {
         return Ok(None);
     };
-    let max_capacity = MAX_COUNTER_OFFERS;
+    let max_capacity = max_counter_offers(storage)?;
 
     if count < max_capacity {
         return Ok(None);
@@ -121,6 +155,17 @@ pub(crate) fn determine_eviction_candidate(
     Ok(Some((worst_addr, worst_offer)))
 }
 
+pub(crate) fn validate_max_counter_offers(limit: u8) -> Result<(), ContractError> {
+    if limit == 0 || limit > MAX_CONFIGURABLE_COUNTER_OFFERS {
+        return Err(ContractError::InvalidConfig {});
+    }
+    Ok(())
+}
+
+pub(crate) fn max_counter_offers(storage: &dyn Storage) -> StdResult<u8> {
+    Ok(load_config(storage)?.max_counter_offers)
+}
+
 fn snapshot_counter_offer_capacity(
     storage: &mut dyn Storage,
 ) -> StdResult<Option<(u8, (Addr, OpenInterest))>> {
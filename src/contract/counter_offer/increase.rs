@@ -0,0 +1,249 @@
+use cosmwasm_std::{attr, Coin, DepsMut, Env, MessageInfo, Response, Uint128, Uint256};
+
+use crate::{
+    error::ContractError,
+    state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST},
+};
+
+use super::helpers::{add_outstanding_debt, is_offer_expired};
+
+/// Raises the sender's counter offer by `additional`, escrowed in the attached funds.
+pub fn increase(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    additional: Uint128,
+) -> Result<Response, ContractError> {
+    let active_interest = OPEN_INTEREST
+        .load(deps.storage)?
+        .ok_or(ContractError::NoOpenInterest {})?;
+
+    if LENDER.load(deps.storage)?.is_some() {
+        return Err(ContractError::LenderAlreadySet {});
+    }
+
+    let proposer = info.sender.clone();
+    let mut stored_offer = COUNTER_OFFERS
+        .may_load(deps.storage, &proposer)?
+        .ok_or_else(|| ContractError::CounterOfferNotFound {
+            proposer: proposer.to_string(),
+        })?;
+
+    if let Some(expired_at) = stored_offer.offer_expiry {
+        if is_offer_expired(&stored_offer, env.block.time) {
+            return Err(ContractError::CounterOfferExpired {
+                proposer: proposer.to_string(),
+                expired_at,
+            });
+        }
+    }
+
+    if additional.is_zero() {
+        return Err(ContractError::InvalidCoinAmount {
+            field: "additional",
+        });
+    }
+
+    let denom = stored_offer.liquidity_coin.denom.clone();
+    if let Some(stray) = info.funds.iter().find(|coin| coin.denom != denom) {
+        return Err(ContractError::UnexpectedFunds {
+            denom: stray.denom.clone(),
+        });
+    }
+
+    let expected = Uint256::from(additional);
+    let received = info
+        .funds
+        .iter()
+        .try_fold(Uint256::zero(), |acc, coin| acc.checked_add(coin.amount))
+        .map_err(|_| ContractError::EscrowSumOverflow {
+            denom: denom.clone(),
+        })?;
+    if received != expected {
+        return Err(ContractError::CounterOfferEscrowMismatch {
+            denom,
+            expected,
+            received,
+        });
+    }
+
+    let increased = stored_offer
+        .liquidity_coin
+        .amount
+        .checked_add(expected)
+        .map_err(|_| ContractError::EscrowSumOverflow {
+            denom: denom.clone(),
+        })?;
+    if increased >= active_interest.liquidity_coin.amount {
+        return Err(ContractError::CounterOfferNotSmaller {});
+    }
+
+    add_outstanding_debt(deps.storage, &Coin::new(expected, denom))?;
+    stored_offer.liquidity_coin.amount = increased;
+    COUNTER_OFFERS.save(deps.storage, &proposer, &stored_offer)?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "increase_counter_offer"),
+        attr("proposer", proposer.as_str()),
+        attr("added_amount", additional.to_string()),
+        attr("liquidity_amount", increased.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::propose;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::state::OUTSTANDING_DEBT;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+
+    #[test]
+    fn rejects_without_existing_offer() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let err = increase(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[Coin::new(50u128, "uusd")]),
+            Uint128::new(50),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::CounterOfferNotFound { proposer: p } if p == proposer.as_str()
+        ));
+    }
+
+    #[test]
+    fn raises_offer_and_outstanding_debt() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+
+        let err = increase(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[Coin::new(40u128, "uusd")]),
+            Uint128::new(50),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::CounterOfferEscrowMismatch { received, .. }
+                if received == Uint256::from(40u128)
+        ));
+
+        let response = increase(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[Coin::new(50u128, "uusd")]),
+            Uint128::new(50),
+        )
+        .expect("offer increased");
+
+        assert!(response
+            .attributes
+            .contains(&attr("liquidity_amount", "950")));
+        let stored = COUNTER_OFFERS
+            .load(deps.as_ref().storage, &proposer)
+            .expect("offer stored");
+        assert_eq!(stored.liquidity_coin, Coin::new(950u128, "uusd"));
+        assert_eq!(
+            OUTSTANDING_DEBT.load(deps.as_ref().storage).unwrap(),
+            Some(Coin::new(950u128, "uusd"))
+        );
+    }
+
+    #[test]
+    fn rejects_increase_reaching_active_liquidity() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+
+        let gap = active
+            .liquidity_coin
+            .amount
+            .checked_sub(Uint256::from(900u128))
+            .expect("active exceeds offer");
+        let additional = Uint128::try_from(gap).expect("gap fits in u128");
+        let err = increase(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[Coin::new(additional, "uusd")]),
+            additional,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::CounterOfferNotSmaller {}));
+    }
+
+    #[test]
+    fn rejects_increase_of_expired_offer() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(800u128);
+        let expiry = mock_env().block.time.plus_seconds(60);
+        offer.offer_expiry = Some(expiry);
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+
+        let mut env = mock_env();
+        env.block.time = expiry;
+        let err = increase(
+            deps.as_mut(),
+            env,
+            message_info(&proposer, &[Coin::new(50u128, "uusd")]),
+            Uint128::new(50),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::CounterOfferExpired { proposer: p, expired_at }
+                if p == proposer.as_str() && expired_at == expiry
+        ));
+        assert_eq!(
+            COUNTER_OFFERS
+                .load(deps.as_ref().storage, &proposer)
+                .expect("offer kept")
+                .liquidity_coin,
+            Coin::new(800u128, "uusd")
+        );
+    }
+}
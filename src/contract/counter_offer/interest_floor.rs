@@ -0,0 +1,153 @@
+use cosmwasm_std::{attr, Addr, BankMsg, Coin, DepsMut, MessageInfo, Order, Response, StdResult};
+
+use crate::{
+    contract::open_interest::build_repayment_amounts,
+    error::ContractError,
+    helpers::require_owner,
+    state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST},
+    types::OpenInterest,
+};
+
+use super::helpers::release_outstanding_debt;
+
+pub fn set_interest_floor(
+    deps: DepsMut,
+    info: MessageInfo,
+    interest_coin: Coin,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    let mut active = OPEN_INTEREST
+        .load(deps.storage)?
+        .ok_or(ContractError::NoOpenInterest {})?;
+
+    if LENDER.may_load(deps.storage)?.flatten().is_some() {
+        return Err(ContractError::LenderAlreadySet {});
+    }
+
+    if interest_coin.denom != active.interest_coin.denom
+        || interest_coin.amount <= active.interest_coin.amount
+    {
+        return Err(ContractError::InterestFloorNotRaised {
+            current: active.interest_coin,
+        });
+    }
+
+    active.interest_coin = interest_coin.clone();
+    build_repayment_amounts(&active)?;
+    OPEN_INTEREST.save(deps.storage, &Some(active))?;
+
+    let below_floor = COUNTER_OFFERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|entry| match entry {
+            Ok((_, offer)) => offer.interest_coin.amount < interest_coin.amount,
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<(Addr, OpenInterest)>>>()?;
+
+    let refunded = below_floor.len();
+    let mut response = Response::new();
+    for (proposer, offer) in below_floor {
+        release_outstanding_debt(deps.storage, &offer.liquidity_coin)?;
+        COUNTER_OFFERS.remove(deps.storage, &proposer);
+
+        response = response.add_message(BankMsg::Send {
+            to_address: proposer.into_string(),
+            amount: vec![offer.liquidity_coin],
+        });
+    }
+
+    Ok(response.add_attributes([
+        attr("action", "interest_floor_updated"),
+        attr("interest_coin", interest_coin.to_string()),
+        attr("refunded_offers", refunded.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::propose;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::open_interest::set_active_lender;
+    use crate::state::OUTSTANDING_DEBT;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{CosmosMsg, Timestamp, Uint256};
+
+    #[test]
+    fn rejects_lower_floor_and_active_lender() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let err = set_interest_floor(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Coin::new(50u128, "ujuno"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InterestFloorNotRaised { .. }));
+
+        let lender = deps.api.addr_make("lender");
+        set_active_lender(
+            deps.as_mut().storage,
+            lender,
+            Timestamp::from_seconds(0),
+            Timestamp::from_seconds(10),
+        )
+        .expect("lender stored");
+        let err = set_interest_floor(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Coin::new(80u128, "ujuno"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::LenderAlreadySet {}));
+    }
+
+    #[test]
+    fn raising_floor_refunds_sub_floor_bids() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .expect("proposal stored");
+
+        let response = set_interest_floor(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            Coin::new(80u128, "ujuno"),
+        )
+        .expect("floor raised");
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: proposer.to_string(),
+                amount: vec![Coin::new(900u128, "uusd")],
+            })
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("action", "interest_floor_updated")));
+        assert!(!COUNTER_OFFERS.has(deps.as_ref().storage, &proposer));
+        assert_eq!(OUTSTANDING_DEBT.load(deps.as_ref().storage).unwrap(), None);
+        assert_eq!(
+            OPEN_INTEREST
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .unwrap()
+                .interest_coin,
+            Coin::new(80u128, "ujuno")
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use cosmwasm_std::{attr, Addr, BankMsg, DepsMut, MessageInfo, Order, Response, StdResult};
+
+use crate::{
+    error::ContractError,
+    helpers::{require_owner, update_config},
+    state::{COUNTER_OFFERS, LENDER},
+    types::OpenInterest,
+};
+
+use super::helpers::{release_outstanding_debt, validate_max_counter_offers};
+
+pub fn set_max_counter_offers(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: u8,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    validate_max_counter_offers(limit)?;
+
+    if LENDER.may_load(deps.storage)?.flatten().is_some() {
+        return Err(ContractError::LenderAlreadySet {});
+    }
+
+    let mut offers = COUNTER_OFFERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, OpenInterest)>>>()?;
+
+    // Lowest offers are evicted first; ties keep the ascending address order.
+    offers.sort_by(|(_, a), (_, b)| a.liquidity_coin.amount.cmp(&b.liquidity_coin.amount));
+    let excess = offers.len().saturating_sub(usize::from(limit));
+
+    let mut response = Response::new();
+    for (proposer, offer) in offers.into_iter().take(excess) {
+        release_outstanding_debt(deps.storage, &offer.liquidity_coin)?;
+        COUNTER_OFFERS.remove(deps.storage, &proposer);
+
+        response = response.add_message(BankMsg::Send {
+            to_address: proposer.into_string(),
+            amount: vec![offer.liquidity_coin],
+        });
+    }
+
+    update_config(deps.storage, |config| config.max_counter_offers = limit)?;
+
+    Ok(response.add_attributes([
+        attr("action", "set_max_counter_offers"),
+        attr("limit", limit.to_string()),
+        attr("evicted_on_resize", excess.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::propose;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::open_interest::set_active_lender;
+    use crate::helpers::load_config;
+    use crate::state::{MAX_COUNTER_OFFERS, OUTSTANDING_DEBT};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{Coin, CosmosMsg, Timestamp, Uint256};
+
+    #[test]
+    fn rejects_non_owner() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let intruder = deps.api.addr_make("intruder");
+        let err =
+            set_max_counter_offers(deps.as_mut(), message_info(&intruder, &[]), 3).unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn rejects_zero_limit() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let err = set_max_counter_offers(deps.as_mut(), message_info(&owner, &[]), 0).unwrap_err();
+
+        assert!(matches!(err, ContractError::InvalidConfig {}));
+    }
+
+    #[test]
+    fn rejects_when_lender_set() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let lender = deps.api.addr_make("lender");
+        set_active_lender(
+            deps.as_mut().storage,
+            lender,
+            Timestamp::from_seconds(0),
+            Timestamp::from_seconds(10),
+        )
+        .expect("lender stored");
+
+        let err = set_max_counter_offers(deps.as_mut(), message_info(&owner, &[]), 3).unwrap_err();
+
+        assert!(matches!(err, ContractError::LenderAlreadySet {}));
+    }
+
+    #[test]
+    fn lowering_cap_on_full_auction_evicts_lowest_offers() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        set_max_counter_offers(deps.as_mut(), message_info(&owner, &[]), 4)
+            .expect("initial limit stored");
+
+        let amounts = [600u128, 900, 700, 800];
+        let mut proposers = Vec::new();
+        for (index, amount) in amounts.iter().enumerate() {
+            let proposer = deps.api.addr_make(&format!("proposer-{index}"));
+            let mut offer = active.clone();
+            offer.liquidity_coin.amount = Uint256::from(*amount);
+            propose(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&proposer, &[offer.liquidity_coin.clone()]),
+                offer,
+            )
+            .expect("proposal stored");
+            proposers.push(proposer);
+        }
+
+        let response = set_max_counter_offers(deps.as_mut(), message_info(&owner, &[]), 2)
+            .expect("limit lowered");
+
+        assert!(response
+            .attributes
+            .contains(&attr("evicted_on_resize", "2")));
+        let refunds: Vec<(String, Vec<Coin>)> = response
+            .messages
+            .iter()
+            .map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    (to_address.clone(), amount.clone())
+                }
+                other => panic!("unexpected message: {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            refunds,
+            vec![
+                (proposers[0].to_string(), vec![Coin::new(600u128, "uusd")]),
+                (proposers[2].to_string(), vec![Coin::new(700u128, "uusd")]),
+            ]
+        );
+
+        let remaining: Vec<Addr> = COUNTER_OFFERS
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .expect("keys load");
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.contains(&proposers[1]));
+        assert!(remaining.contains(&proposers[3]));
+
+        let debt = OUTSTANDING_DEBT
+            .load(deps.as_ref().storage)
+            .expect("debt loads")
+            .expect("debt remains");
+        assert_eq!(debt, Coin::new(1_700u128, "uusd"));
+        assert_eq!(
+            load_config(deps.as_ref().storage)
+                .unwrap()
+                .max_counter_offers,
+            2
+        );
+    }
+
+    #[test]
+    fn raising_cap_keeps_existing_offers() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let response = set_max_counter_offers(deps.as_mut(), message_info(&owner, &[]), 10)
+            .expect("limit stored");
+
+        assert!(response.messages.is_empty());
+        assert!(response
+            .attributes
+            .contains(&attr("evicted_on_resize", "0")));
+        assert_ne!(
+            load_config(deps.as_ref().storage)
+                .unwrap()
+                .max_counter_offers,
+            MAX_COUNTER_OFFERS
+        );
+    }
+}
@@ -1,11 +1,26 @@
 mod accept;
 mod cancel;
+mod cancel_queued;
+mod claim_refund;
+mod expire;
 mod helpers;
+mod increase;
+mod interest_floor;
+mod limit;
 mod propose;
+mod reject;
 
 #[cfg(test)]
 pub mod test_helpers;
 
-pub use accept::accept;
+pub use accept::{accept, accept_and_reopen, accept_best};
 pub use cancel::cancel;
+pub use cancel_queued::cancel_queued_interest;
+pub use claim_refund::claim_refund;
+pub use expire::expire;
+pub(crate) use helpers::validate_max_counter_offers;
+pub use increase::increase;
+pub use interest_floor::set_interest_floor;
+pub use limit::set_max_counter_offers;
 pub use propose::propose;
+pub use reject::reject;
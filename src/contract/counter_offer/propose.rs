@@ -7,13 +7,13 @@ use crate::{
 };
 
 use super::helpers::{
-    add_outstanding_debt, determine_eviction_candidate, release_outstanding_debt,
+    add_outstanding_debt, determine_eviction_candidate, is_offer_expired, release_outstanding_debt,
     validate_counter_offer, validate_counter_offer_escrow,
 };
 
 pub fn propose(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     proposed_interest: OpenInterest,
 ) -> Result<Response, ContractError> {
@@ -26,6 +26,14 @@ pub fn propose(
     }
 
     validate_counter_offer(&active_interest, &proposed_interest)?;
+    if let Some(expired_at) = proposed_interest.offer_expiry {
+        if is_offer_expired(&proposed_interest, env.block.time) {
+            return Err(ContractError::CounterOfferExpired {
+                proposer: info.sender.into_string(),
+                expired_at,
+            });
+        }
+    }
     validate_counter_offer_escrow(&info, &proposed_interest)?;
 
     if COUNTER_OFFERS
@@ -95,6 +103,7 @@ mod tests {
                 interest_coin: Coin::new(50u128, "ujuno"),
                 expiry_duration: 86_400u64,
                 collateral: Coin::new(2_000u128, "uatom"),
+                ..Default::default()
             },
         )
         .unwrap_err();
@@ -127,6 +136,7 @@ mod tests {
                 interest_coin: active.interest_coin.clone(),
                 expiry_duration: active.expiry_duration,
                 collateral: active.collateral.clone(),
+                ..Default::default()
             },
         )
         .unwrap_err();
@@ -150,6 +160,7 @@ mod tests {
                 interest_coin: Coin::new(55u128, "ujuno"),
                 expiry_duration: active.expiry_duration,
                 collateral: active.collateral.clone(),
+                ..Default::default()
             },
         )
         .unwrap_err();
@@ -157,6 +168,72 @@ mod tests {
         assert!(matches!(err, ContractError::CounterOfferTermsMismatch {}));
     }
 
+    #[test]
+    fn rejects_offers_changing_repayment_terms() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+        let proposer = deps.api.addr_make("proposer");
+
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        offer.late_fee_bps = Some(u16::MAX);
+        let err = propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidConfig {}));
+
+        offer.late_fee_bps = Some(500);
+        let err = propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CounterOfferTermsMismatch {}));
+
+        offer.late_fee_bps = active.late_fee_bps;
+        offer.repayment_deadline_duration = Some(60);
+        let err = propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[offer.liquidity_coin.clone()]),
+            offer,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CounterOfferTermsMismatch {}));
+    }
+
+    #[test]
+    fn reports_malformed_denoms_before_term_mismatch() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+        let proposer = deps.api.addr_make("proposer");
+
+        let mut offer = active.clone();
+        offer.liquidity_coin = Coin::new(900u128, "UUSD");
+        let err = propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &[]),
+            offer,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InvalidCoinDenom {
+                field: "liquidity_coin"
+            }
+        ));
+    }
+
     #[test]
     fn rejects_non_lower_amounts() {
         let mut deps = mock_dependencies();
@@ -173,6 +250,7 @@ mod tests {
                 interest_coin: active.interest_coin.clone(),
                 expiry_duration: active.expiry_duration,
                 collateral: active.collateral.clone(),
+                ..Default::default()
             },
         )
         .unwrap_err();
@@ -198,6 +276,7 @@ mod tests {
             interest_coin: active.interest_coin.clone(),
             expiry_duration: active.expiry_duration,
             collateral: active.collateral.clone(),
+            ..Default::default()
         };
 
         let err = propose(
@@ -232,6 +311,7 @@ mod tests {
             interest_coin: active.interest_coin.clone(),
             expiry_duration: active.expiry_duration,
             collateral: active.collateral.clone(),
+            ..Default::default()
         };
 
         let smaller_amount = offer
@@ -257,6 +337,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn rejects_funds_in_other_denoms() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = active.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+
+        let funds = vec![offer.liquidity_coin.clone(), Coin::new(5u128, "ujuno")];
+        let err = propose(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&proposer, &funds),
+            offer,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::UnexpectedFunds { denom } if denom == "ujuno"
+        ));
+    }
+
     #[test]
     fn rejects_duplicate_counter_offers_from_same_proposer() {
         let mut deps = mock_dependencies();
@@ -275,6 +379,7 @@ mod tests {
             interest_coin: active.interest_coin.clone(),
             expiry_duration: active.expiry_duration,
             collateral: active.collateral.clone(),
+            ..Default::default()
         };
 
         let funds = vec![offer.liquidity_coin.clone()];
@@ -317,6 +422,7 @@ mod tests {
             interest_coin: active.interest_coin.clone(),
             expiry_duration: active.expiry_duration,
             collateral: active.collateral.clone(),
+            ..Default::default()
         };
 
         propose(
@@ -347,6 +453,7 @@ mod tests {
             interest_coin: active.interest_coin.clone(),
             expiry_duration: active.expiry_duration,
             collateral: active.collateral.clone(),
+            ..Default::default()
         };
 
         propose(
@@ -391,6 +498,7 @@ mod tests {
                 interest_coin: active.interest_coin.clone(),
                 expiry_duration: active.expiry_duration,
                 collateral: active.collateral.clone(),
+                ..Default::default()
             };
 
             let refund_coin = offer.liquidity_coin.clone();
@@ -450,6 +558,7 @@ mod tests {
             interest_coin: active.interest_coin.clone(),
             expiry_duration: active.expiry_duration,
             collateral: active.collateral.clone(),
+            ..Default::default()
         };
 
         let response = propose(
@@ -514,6 +623,7 @@ mod tests {
                 interest_coin: active.interest_coin.clone(),
                 expiry_duration: active.expiry_duration,
                 collateral: active.collateral.clone(),
+                ..Default::default()
             };
 
             lowest_amount = match lowest_amount {
@@ -572,6 +682,7 @@ mod tests {
                 interest_coin: active.interest_coin.clone(),
                 expiry_duration: active.expiry_duration,
                 collateral: active.collateral.clone(),
+                ..Default::default()
             };
 
             lowest_amount = match lowest_amount {
@@ -0,0 +1,143 @@
+use cosmwasm_std::{attr, BankMsg, DepsMut, MessageInfo, Response};
+
+use crate::{
+    error::ContractError,
+    helpers::require_owner,
+    state::{COUNTER_OFFERS, LENDER},
+};
+
+use super::helpers::release_outstanding_debt;
+
+pub fn reject(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposer: String,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    if LENDER.may_load(deps.storage)?.flatten().is_some() {
+        return Err(ContractError::LenderAlreadySet {});
+    }
+
+    let proposer_addr = deps.api.addr_validate(&proposer)?;
+    let stored_offer = COUNTER_OFFERS
+        .may_load(deps.storage, &proposer_addr)?
+        .ok_or(ContractError::CounterOfferNotFound { proposer })?;
+
+    release_outstanding_debt(deps.storage, &stored_offer.liquidity_coin)?;
+    COUNTER_OFFERS.remove(deps.storage, &proposer_addr);
+
+    Ok(Response::new()
+        .add_attributes([
+            attr("action", "reject_counter_offer"),
+            attr("rejected_proposer", proposer_addr.as_str()),
+            attr(
+                "liquidity_amount",
+                stored_offer.liquidity_coin.amount.to_string(),
+            ),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: proposer_addr.into_string(),
+            amount: vec![stored_offer.liquidity_coin],
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::counter_offer::propose;
+    use crate::contract::counter_offer::test_helpers::setup_open_interest;
+    use crate::contract::open_interest::set_active_lender;
+    use crate::state::OUTSTANDING_DEBT;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{Coin, CosmosMsg, Timestamp, Uint256};
+
+    #[test]
+    fn rejects_non_owner_and_missing_offer() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let proposer = deps.api.addr_make("proposer").into_string();
+        let intruder = deps.api.addr_make("intruder");
+        let err = reject(
+            deps.as_mut(),
+            message_info(&intruder, &[]),
+            proposer.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = reject(deps.as_mut(), message_info(&owner, &[]), proposer.clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::CounterOfferNotFound { proposer: missing } if missing == proposer
+        ));
+    }
+
+    #[test]
+    fn rejects_once_lender_is_set() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_open_interest(deps.as_mut(), &owner);
+
+        let lender = deps.api.addr_make("lender");
+        set_active_lender(
+            deps.as_mut().storage,
+            lender,
+            Timestamp::from_seconds(0),
+            Timestamp::from_seconds(10),
+        )
+        .expect("lender stored");
+
+        let proposer = deps.api.addr_make("proposer").into_string();
+        let err = reject(deps.as_mut(), message_info(&owner, &[]), proposer).unwrap_err();
+
+        assert!(matches!(err, ContractError::LenderAlreadySet {}));
+    }
+
+    #[test]
+    fn owner_rejects_single_offer_and_keeps_the_rest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let active = setup_open_interest(deps.as_mut(), &owner);
+
+        let spammer = deps.api.addr_make("spammer");
+        let keeper = deps.api.addr_make("keeper");
+        for (proposer, amount) in [(&spammer, 600u128), (&keeper, 800u128)] {
+            let mut offer = active.clone();
+            offer.liquidity_coin.amount = Uint256::from(amount);
+            propose(
+                deps.as_mut(),
+                mock_env(),
+                message_info(proposer, &[offer.liquidity_coin.clone()]),
+                offer,
+            )
+            .expect("proposal stored");
+        }
+
+        let response = reject(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            spammer.to_string(),
+        )
+        .expect("owner rejects offer");
+
+        assert!(response
+            .attributes
+            .contains(&attr("rejected_proposer", spammer.as_str())));
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: spammer.to_string(),
+                amount: vec![Coin::new(600u128, "uusd")],
+            })
+        );
+        assert!(!COUNTER_OFFERS.has(deps.as_ref().storage, &spammer));
+        assert!(COUNTER_OFFERS.has(deps.as_ref().storage, &keeper));
+        assert_eq!(
+            OUTSTANDING_DEBT.load(deps.as_ref().storage).unwrap(),
+            Some(Coin::new(800u128, "uusd"))
+        );
+    }
+}
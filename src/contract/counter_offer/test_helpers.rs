@@ -11,6 +11,7 @@ pub fn setup_open_interest(deps: DepsMut, owner: &Addr) -> OpenInterest {
         interest_coin: Coin::new(50u128, "ujuno"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(2_000u128, "uatom"),
+        ..Default::default()
     };
 
     OWNER.save(deps.storage, owner).expect("owner stored");
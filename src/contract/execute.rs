@@ -2,9 +2,11 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 
-use super::{counter_offer, open_interest, staking, transfer, vote, withdraw};
+use super::{config, counter_offer, open_interest, snapshot, staking, transfer, vote, withdraw};
 use crate::error::ContractError;
+use crate::helpers::reject_funds;
 use crate::msg::ExecuteMsg;
+use crate::state::MAX_NOOP_MEMO_LENGTH;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
@@ -13,10 +15,19 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if !accepts_funds(&msg) {
+        reject_funds(&info)?;
+    }
+
     match msg {
-        ExecuteMsg::Noop {} => Ok(Response::new()),
-        ExecuteMsg::Delegate { validator, amount } => {
-            staking::delegate::execute(deps, env, info, validator, amount)
+        ExecuteMsg::Noop { memo } => noop(memo),
+        ExecuteMsg::Delegate {
+            validator,
+            amount,
+            max_commission,
+        } => staking::delegate::execute(deps, env, info, validator, amount, max_commission),
+        ExecuteMsg::DelegateBatch { delegations } => {
+            staking::delegate_batch::execute(deps, env, info, delegations)
         }
         ExecuteMsg::Undelegate { validator, amount } => {
             staking::undelegate::execute(deps, env, info, validator, amount)
@@ -26,12 +37,26 @@ pub fn execute(
             dst_validator,
             amount,
         } => staking::redelegate::execute(deps, env, info, src_validator, dst_validator, amount),
+        ExecuteMsg::RedelegateBatch { moves } => {
+            staking::redelegate_batch::execute(deps, env, info, moves)
+        }
         ExecuteMsg::ClaimDelegatorRewards {} => staking::claim::execute(deps, env, info),
+        ExecuteMsg::ClaimSplit {
+            validator,
+            restake_bps,
+            recipient,
+        } => staking::claim_split::execute(deps, env, info, validator, restake_bps, recipient),
         ExecuteMsg::Withdraw {
             denom,
             amount,
             recipient,
         } => withdraw::execute(deps, env, info, denom, amount, recipient),
+        ExecuteMsg::WithdrawAll { denom, recipient } => {
+            withdraw::execute_all(deps, env, info, denom, recipient)
+        }
+        ExecuteMsg::WithdrawExcess { denom, recipient } => {
+            withdraw::execute_excess(deps, env, info, denom, recipient)
+        }
         ExecuteMsg::Vote {
             proposal_id,
             option,
@@ -40,7 +65,11 @@ pub fn execute(
             proposal_id,
             options,
         } => vote::execute_weighted_vote(deps, env, info, proposal_id, options),
-        ExecuteMsg::TransferOwnership { new_owner } => transfer::execute(deps, info, new_owner),
+        ExecuteMsg::VoteBatch { votes } => vote::execute_vote_batch(deps, env, info, votes),
+        ExecuteMsg::TransferOwnership {
+            new_owner,
+            validate_receiver,
+        } => transfer::execute(deps, info, new_owner, validate_receiver.unwrap_or(false)),
         ExecuteMsg::OpenInterest(open_interest_msg) => {
             open_interest::execute(deps, env, info, open_interest_msg)
         }
@@ -54,11 +83,78 @@ pub fn execute(
             proposer,
             open_interest,
         } => counter_offer::accept(deps, env, info, proposer, open_interest),
+        ExecuteMsg::AcceptAndReopen {
+            proposer,
+            next_interest,
+        } => counter_offer::accept_and_reopen(deps, env, info, proposer, next_interest),
+        ExecuteMsg::AcceptBestCounterOffer { min_liquidity } => {
+            counter_offer::accept_best(deps, env, info, min_liquidity)
+        }
+        ExecuteMsg::CancelQueuedInterest {} => counter_offer::cancel_queued_interest(deps, info),
         ExecuteMsg::CancelCounterOffer {} => counter_offer::cancel(deps, env, info),
-        ExecuteMsg::CloseOpenInterest {} => open_interest::close(deps, info),
+        ExecuteMsg::IncreaseCounterOffer { additional } => {
+            counter_offer::increase(deps, env, info, additional)
+        }
+        ExecuteMsg::ExpireCounterOffer { proposer } => counter_offer::expire(deps, env, proposer),
+        ExecuteMsg::RejectCounterOffer { proposer } => counter_offer::reject(deps, info, proposer),
+        ExecuteMsg::CloseOpenInterest {} => open_interest::close(deps, env, info),
+        ExecuteMsg::CloseToUnclaimed {} => open_interest::close_to_unclaimed(deps, env, info),
+        ExecuteMsg::ClaimRefund {} => counter_offer::claim_refund(deps, info),
         ExecuteMsg::RepayOpenInterest {} => open_interest::repay(deps, env, info),
+        ExecuteMsg::RepayPartial { amount } => {
+            open_interest::repay_partial(deps, env, info, amount)
+        }
+        ExecuteMsg::SetPayoutAddress { address } => {
+            open_interest::set_payout_address(deps, info, address)
+        }
+        ExecuteMsg::AddCollateral {} => open_interest::add_collateral(deps, info),
+        ExecuteMsg::TransferLenderPosition { new_lender } => {
+            open_interest::transfer_lender_position(deps, info, new_lender)
+        }
         ExecuteMsg::LiquidateOpenInterest {} => open_interest::liquidate(deps, env, info),
+        ExecuteMsg::DryRunLiquidate {} => open_interest::dry_run_liquidate(deps, env, info),
+        ExecuteMsg::SnapshotTerms {} => snapshot::execute(deps, env, info),
+        ExecuteMsg::SetUnbondingSeconds { seconds } => {
+            config::set_unbonding_seconds(deps, info, seconds)
+        }
+        ExecuteMsg::SetOperator { operator } => config::set_operator(deps, info, operator),
+        ExecuteMsg::SetMaxPerValidator { cap } => config::set_max_per_validator(deps, info, cap),
+        ExecuteMsg::SetInterestFloor { interest_coin } => {
+            counter_offer::set_interest_floor(deps, info, interest_coin)
+        }
+        ExecuteMsg::SetMaxCounterOffers { limit } => {
+            counter_offer::set_max_counter_offers(deps, info, limit)
+        }
+    }
+}
+
+/// Messages that may legitimately carry coins: escrow, funding, repayment, collateral top-ups, and
+/// deposits made alongside a no-op. Every other message, staking included, rejects attached funds.
+fn accepts_funds(msg: &ExecuteMsg) -> bool {
+    matches!(
+        msg,
+        ExecuteMsg::Noop { .. }
+            | ExecuteMsg::FundOpenInterest(_)
+            | ExecuteMsg::ProposeCounterOffer(_)
+            | ExecuteMsg::IncreaseCounterOffer { .. }
+            | ExecuteMsg::RepayOpenInterest {}
+            | ExecuteMsg::RepayPartial { .. }
+            | ExecuteMsg::AddCollateral {}
+    )
+}
+
+fn noop(memo: Option<String>) -> Result<Response, ContractError> {
+    let Some(memo) = memo else {
+        return Ok(Response::new());
+    };
+
+    if memo.len() > MAX_NOOP_MEMO_LENGTH {
+        return Err(ContractError::MemoTooLong {
+            max: MAX_NOOP_MEMO_LENGTH,
+        });
     }
+
+    Ok(Response::new().add_attribute("memo", memo))
 }
 
 #[cfg(test)]
@@ -77,13 +173,61 @@ mod tests {
         let caller = deps.api.addr_make("caller");
         let info = message_info(&caller, &[]);
 
-        let response = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Noop {})
-            .expect("execute succeeds");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Noop { memo: None },
+        )
+        .expect("execute succeeds");
 
         assert!(response.messages.is_empty());
         assert!(response.attributes.is_empty());
     }
 
+    #[test]
+    fn execute_noop_echoes_memo_attribute() {
+        let mut deps = mock_dependencies();
+        let caller = deps.api.addr_make("caller");
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&caller, &[]),
+            ExecuteMsg::Noop {
+                memo: Some("trace-1234".to_string()),
+            },
+        )
+        .expect("execute succeeds");
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            response.attributes,
+            vec![cosmwasm_std::attr("memo", "trace-1234")]
+        );
+    }
+
+    #[test]
+    fn execute_noop_rejects_oversized_memo() {
+        let mut deps = mock_dependencies();
+        let caller = deps.api.addr_make("caller");
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&caller, &[]),
+            ExecuteMsg::Noop {
+                memo: Some("x".repeat(MAX_NOOP_MEMO_LENGTH + 1)),
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::MemoTooLong { max } if max == MAX_NOOP_MEMO_LENGTH
+        ));
+    }
+
     #[test]
     fn execute_delegate_flows_through_module() {
         let mut deps = mock_dependencies();
@@ -113,6 +257,7 @@ mod tests {
             ExecuteMsg::Delegate {
                 validator,
                 amount: Uint128::new(50),
+                max_commission: None,
             },
         )
         .unwrap_err();
@@ -254,6 +399,7 @@ mod tests {
             message_info(&owner, &[]),
             ExecuteMsg::TransferOwnership {
                 new_owner: owner.to_string(),
+                validate_receiver: None,
             },
         )
         .unwrap_err();
@@ -281,6 +427,7 @@ mod tests {
                 interest_coin: cosmwasm_std::Coin::new(5u128, "ujuno"),
                 expiry_duration: 86_400,
                 collateral: cosmwasm_std::Coin::new(200u128, "uatom"),
+                ..Default::default()
             }),
         )
         .unwrap_err();
@@ -308,6 +455,7 @@ mod tests {
             interest_coin: cosmwasm_std::Coin::new(1u128, "ujuno"),
             expiry_duration: 100,
             collateral: cosmwasm_std::Coin::new(2u128, "uatom"),
+            ..Default::default()
         };
         OPEN_INTEREST
             .save(deps.as_mut().storage, &Some(open_interest))
@@ -362,6 +510,7 @@ mod tests {
             interest_coin: cosmwasm_std::Coin::new(50u128, "ujuno"),
             expiry_duration: 86_400,
             collateral: cosmwasm_std::Coin::new(2_000u128, "uatom"),
+            ..Default::default()
         };
 
         OPEN_INTEREST
@@ -387,4 +536,75 @@ mod tests {
             .expect("counter offer stored");
         assert_eq!(stored, offer);
     }
+
+    #[test]
+    fn non_funding_messages_reject_attached_funds() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER
+            .save(deps.as_mut().storage, &owner)
+            .expect("owner stored");
+
+        let validator = deps.api.addr_make("validator").into_string();
+        let messages = vec![
+            ExecuteMsg::Delegate {
+                validator: validator.clone(),
+                amount: Uint128::new(10),
+                max_commission: None,
+            },
+            ExecuteMsg::DelegateBatch {
+                delegations: vec![(validator.clone(), Uint128::new(10))],
+            },
+            ExecuteMsg::Undelegate {
+                validator: validator.clone(),
+                amount: Uint128::new(10),
+            },
+            ExecuteMsg::Redelegate {
+                src_validator: validator.clone(),
+                dst_validator: deps.api.addr_make("validator-two").into_string(),
+                amount: Uint128::new(10),
+            },
+            ExecuteMsg::Withdraw {
+                denom: "ucosm".to_string(),
+                amount: Uint128::new(10),
+                recipient: None,
+            },
+            ExecuteMsg::TransferOwnership {
+                new_owner: deps.api.addr_make("new-owner").into_string(),
+                validate_receiver: None,
+            },
+            ExecuteMsg::CloseOpenInterest {},
+            ExecuteMsg::Vote {
+                proposal_id: 1,
+                option: cosmwasm_std::VoteOption::Yes,
+            },
+            ExecuteMsg::ClaimDelegatorRewards {},
+        ];
+
+        for msg in messages {
+            let err = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&owner, &coins(1, "ucosm")),
+                msg,
+            )
+            .unwrap_err();
+
+            assert!(matches!(err, ContractError::FundsNotAccepted {}));
+        }
+    }
+
+    #[test]
+    fn funding_messages_still_accept_funds() {
+        let mut deps = mock_dependencies();
+        let caller = deps.api.addr_make("caller");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&caller, &coins(1, "ucosm")),
+            ExecuteMsg::Noop { memo: None },
+        )
+        .expect("noop accepts deposits");
+    }
 }
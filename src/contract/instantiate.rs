@@ -3,23 +3,28 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use cw2::set_contract_version;
 
-use crate::contract::open_interest::clear_active_lender;
+use crate::contract::counter_offer::validate_max_counter_offers;
+use crate::contract::open_interest::{
+    assign_interest_nonce, clear_active_lender, store_loan_reference, validate_denom,
+    validate_open_interest,
+};
 use crate::error::ContractError;
 use crate::msg::InstantiateMsg;
 use crate::state::{
-    DEFAULT_LIQUIDATION_UNBONDING_SECONDS, LAST_LIQUIDATION_UNBONDING,
-    LIQUIDATION_UNBONDING_DURATION, MAX_LIQUIDATION_UNBONDING_SECONDS, OPEN_INTEREST,
+    CONFIG, DEFAULT_LIQUIDATION_UNBONDING_SECONDS, LAST_LIQUIDATION_UNBONDING, MAX_BPS,
+    MAX_COUNTER_OFFERS, MAX_LIQUIDATION_UNBONDING_SECONDS, OPEN_INTEREST, OPEN_INTEREST_CREATED,
     OUTSTANDING_DEBT, OWNER,
 };
+use crate::types::Config;
 
 // version info for migration info
-const CONTRACT_NAME: &str = "crates.io:wasm_vault";
-const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub(crate) const CONTRACT_NAME: &str = "crates.io:wasm_vault";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
@@ -32,8 +37,9 @@ pub fn instantiate(
     OWNER.save(deps.storage, &owner)?;
     OUTSTANDING_DEBT.save(deps.storage, &None)?;
     OPEN_INTEREST.save(deps.storage, &None)?;
+    OPEN_INTEREST_CREATED.save(deps.storage, &None)?;
     clear_active_lender(deps.storage)?;
-    let duration = match msg.liquidation_unbonding_duration {
+    let liquidation_unbonding_duration = match msg.liquidation_unbonding_duration {
         Some(duration) => {
             if duration > MAX_LIQUIDATION_UNBONDING_SECONDS {
                 return Err(ContractError::LiquidationUnbondingDurationTooLong {
@@ -44,22 +50,131 @@ pub fn instantiate(
         }
         None => DEFAULT_LIQUIDATION_UNBONDING_SECONDS,
     };
-    LIQUIDATION_UNBONDING_DURATION.save(deps.storage, &duration)?;
     LAST_LIQUIDATION_UNBONDING.save(deps.storage, &None)?;
 
-    Ok(Response::new()
+    if let Some(denoms) = &msg.allowed_denoms {
+        for denom in denoms {
+            validate_denom(denom, "allowed_denoms")?;
+        }
+    }
+
+    let liquidation_bonus_bps = msg.liquidation_bonus_bps.unwrap_or(0);
+    validate_liquidation_deductions(&[liquidation_bonus_bps])?;
+
+    let slashing_haircut_bps = msg.slashing_haircut_bps.unwrap_or(0);
+    if slashing_haircut_bps > MAX_BPS {
+        return Err(ContractError::InvalidConfig {});
+    }
+
+    let fee_bps = msg.fee_bps.unwrap_or(0);
+    if fee_bps > MAX_BPS {
+        return Err(ContractError::InvalidConfig {});
+    }
+    let fee_recipient = msg
+        .fee_recipient
+        .as_deref()
+        .map(|recipient| deps.api.addr_validate(recipient))
+        .transpose()?;
+
+    if msg.unbonding_seconds == Some(0) {
+        return Err(ContractError::InvalidConfig {});
+    }
+    if msg.max_expiry_duration == Some(0) {
+        return Err(ContractError::InvalidExpiryDuration {});
+    }
+    if msg.max_per_validator.is_some_and(|cap| cap.is_zero()) {
+        return Err(ContractError::InvalidConfig {});
+    }
+
+    let undelegation_priority = msg
+        .undelegation_priority
+        .unwrap_or_default()
+        .iter()
+        .map(|validator| Ok(deps.api.addr_validate(validator)?.into_string()))
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    let hook_contract = msg
+        .hook_contract
+        .as_deref()
+        .map(|hook| deps.api.addr_validate(hook))
+        .transpose()?;
+
+    if let Some(limit) = msg.max_counter_offers {
+        validate_max_counter_offers(limit)?;
+    }
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            liquidation_unbonding_duration,
+            allowed_denoms: msg.allowed_denoms,
+            max_counter_offers: msg.max_counter_offers.unwrap_or(MAX_COUNTER_OFFERS),
+            liquidation_bonus_bps,
+            unbonding_seconds: msg.unbonding_seconds,
+            max_expiry_duration: msg.max_expiry_duration,
+            min_liquidity: msg.min_liquidity,
+            max_per_validator: msg.max_per_validator,
+            hook_contract,
+            slashing_haircut_bps,
+            return_liquidation_surplus: msg.return_liquidation_surplus.unwrap_or(false),
+            undelegation_priority,
+            fee_bps,
+            fee_recipient,
+        },
+    )?;
+
+    let mut response = Response::new()
         .add_attribute("method", "instantiate")
-        .add_attribute("owner", owner))
+        .add_attribute("owner", owner);
+
+    if let Some(mut open_interest) = msg.initial_open_interest {
+        let collateral = &open_interest.collateral;
+        let attached = info
+            .funds
+            .iter()
+            .find(|coin| coin.denom == collateral.denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default();
+        if attached < collateral.amount {
+            return Err(ContractError::InsufficientBalance {
+                denom: collateral.denom.clone(),
+                available: attached,
+                requested: collateral.amount,
+            });
+        }
+
+        store_loan_reference(deps.storage, &mut open_interest)?;
+        validate_open_interest(&deps.as_ref(), &env, &open_interest)?;
+        assign_interest_nonce(deps.storage, &mut open_interest)?;
+        OPEN_INTEREST.save(deps.storage, &Some(open_interest.clone()))?;
+        OPEN_INTEREST_CREATED.save(deps.storage, &Some(env.block.time))?;
+        response =
+            response.add_attribute("open_interest", open_interest.liquidity_coin.to_string());
+    }
+
+    Ok(response)
+}
+
+/// Rejects bps settings that together take more than the whole liquidation payout. Only
+/// deductions from the payout belong here; the protocol fee is charged on repaid interest.
+fn validate_liquidation_deductions(deductions_bps: &[u16]) -> Result<(), ContractError> {
+    let sum: u32 = deductions_bps.iter().copied().map(u32::from).sum();
+    if sum > u32::from(MAX_BPS) {
+        return Err(ContractError::CombinedBpsTooHigh { sum });
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contract::open_interest::test_helpers::{build_open_interest, sample_coin};
     use crate::state::{
-        COUNTER_OFFERS, DEFAULT_LIQUIDATION_UNBONDING_SECONDS, LENDER,
-        LIQUIDATION_UNBONDING_DURATION, MAX_LIQUIDATION_UNBONDING_SECONDS,
+        COUNTER_OFFERS, LENDER, LOAN_REFERENCE, MAX_CONFIGURABLE_COUNTER_OFFERS,
+        MAX_LOAN_REFERENCE_LENGTH,
     };
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::Uint256;
 
     #[test]
     fn instantiate_respects_explicit_owner() {
@@ -70,6 +185,7 @@ mod tests {
         let msg = InstantiateMsg {
             owner: Some(owner.to_string()),
             liquidation_unbonding_duration: None,
+            ..Default::default()
         };
         let info = message_info(&sender, &[]);
 
@@ -94,10 +210,8 @@ mod tests {
         let stored_open_interest = OPEN_INTEREST.load(&deps.storage).unwrap();
         assert_eq!(stored_open_interest, None);
 
-        let stored_duration = LIQUIDATION_UNBONDING_DURATION
-            .load(deps.as_ref().storage)
-            .expect("duration stored");
-        assert_eq!(stored_duration, DEFAULT_LIQUIDATION_UNBONDING_SECONDS);
+        let config = CONFIG.load(deps.as_ref().storage).expect("config stored");
+        assert_eq!(config, Config::default());
 
         let mut offers =
             COUNTER_OFFERS.range(&deps.storage, None, None, cosmwasm_std::Order::Ascending);
@@ -112,6 +226,7 @@ mod tests {
         let msg = InstantiateMsg {
             owner: None,
             liquidation_unbonding_duration: None,
+            ..Default::default()
         };
         let info = message_info(&sender, &[]);
 
@@ -143,15 +258,14 @@ mod tests {
         let msg = InstantiateMsg {
             owner: Some(owner.to_string()),
             liquidation_unbonding_duration: Some(3_600),
+            ..Default::default()
         };
         let info = message_info(&sender, &[]);
 
         instantiate(deps.as_mut(), mock_env(), info, msg).expect("instantiate succeeds");
 
-        let stored_duration = LIQUIDATION_UNBONDING_DURATION
-            .load(deps.as_ref().storage)
-            .expect("duration stored");
-        assert_eq!(stored_duration, 3_600);
+        let config = CONFIG.load(deps.as_ref().storage).expect("config stored");
+        assert_eq!(config.liquidation_unbonding_duration, 3_600);
     }
 
     #[test]
@@ -163,6 +277,7 @@ mod tests {
         let msg = InstantiateMsg {
             owner: Some(owner.to_string()),
             liquidation_unbonding_duration: Some(MAX_LIQUIDATION_UNBONDING_SECONDS + 1),
+            ..Default::default()
         };
         let info = message_info(&sender, &[]);
 
@@ -177,4 +292,286 @@ mod tests {
             "unexpected error: {err:?}"
         );
     }
+
+    #[test]
+    fn instantiate_stores_allowed_denoms() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            allowed_denoms: Some(vec!["uatom".to_string(), "uusd".to_string()]),
+            ..Default::default()
+        };
+
+        instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg)
+            .expect("instantiate succeeds");
+
+        let config = CONFIG.load(deps.as_ref().storage).expect("config stored");
+        assert_eq!(
+            config.allowed_denoms,
+            Some(vec!["uatom".to_string(), "uusd".to_string()])
+        );
+    }
+
+    #[test]
+    fn instantiate_rejects_empty_allowed_denom() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            allowed_denoms: Some(vec![String::new()]),
+            ..Default::default()
+        };
+
+        let err =
+            instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InvalidCoinDenom {
+                field: "allowed_denoms"
+            }
+        ));
+    }
+
+    #[test]
+    fn instantiate_rejects_liquidation_bonus_above_max_bps() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            liquidation_bonus_bps: Some(MAX_BPS + 1),
+            ..Default::default()
+        };
+
+        let err =
+            instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg).unwrap_err();
+
+        assert!(matches!(err, ContractError::CombinedBpsTooHigh { .. }));
+    }
+
+    #[test]
+    fn instantiate_rejects_slashing_haircut_above_max_bps() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            slashing_haircut_bps: Some(MAX_BPS + 1),
+            ..Default::default()
+        };
+
+        let err =
+            instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg).unwrap_err();
+
+        assert!(matches!(err, ContractError::InvalidConfig {}));
+    }
+
+    #[test]
+    fn instantiate_rejects_fee_above_max_bps() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            fee_bps: Some(MAX_BPS + 1),
+            ..Default::default()
+        };
+
+        let err =
+            instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg).unwrap_err();
+
+        assert!(matches!(err, ContractError::InvalidConfig {}));
+    }
+
+    #[test]
+    fn instantiate_accepts_bonus_and_fee_that_apply_to_different_amounts() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            liquidation_bonus_bps: Some(6_000),
+            fee_bps: Some(5_000),
+            ..Default::default()
+        };
+        instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg)
+            .expect("the interest fee does not come out of the liquidation payout");
+    }
+
+    #[test]
+    fn instantiate_caps_liquidation_deductions_at_max_bps() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+
+        let msg = InstantiateMsg {
+            liquidation_bonus_bps: Some(MAX_BPS),
+            ..Default::default()
+        };
+        instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg)
+            .expect("deductions at exactly the maximum are accepted");
+
+        let msg = InstantiateMsg {
+            liquidation_bonus_bps: Some(MAX_BPS + 1),
+            ..Default::default()
+        };
+        let err =
+            instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::CombinedBpsTooHigh { sum: 10_001 }
+        ));
+    }
+
+    #[test]
+    fn instantiate_bounds_max_counter_offers() {
+        for limit in [0, MAX_CONFIGURABLE_COUNTER_OFFERS + 1] {
+            let mut deps = mock_dependencies();
+            let sender = deps.api.addr_make("sender");
+            let msg = InstantiateMsg {
+                max_counter_offers: Some(limit),
+                ..Default::default()
+            };
+
+            let err = instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg)
+                .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidConfig {}));
+        }
+
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+        let msg = InstantiateMsg {
+            max_counter_offers: Some(5),
+            ..Default::default()
+        };
+        instantiate(deps.as_mut(), mock_env(), message_info(&sender, &[]), msg)
+            .expect("instantiate succeeds");
+
+        assert_eq!(CONFIG.load(&deps.storage).unwrap().max_counter_offers, 5);
+    }
+
+    #[test]
+    fn instantiate_requires_attached_collateral_for_initial_interest() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+        let request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+
+        let msg = InstantiateMsg {
+            initial_open_interest: Some(request),
+            ..Default::default()
+        };
+        let err = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sender, &[sample_coin(150, "uatom")]),
+            msg,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InsufficientBalance { available, requested, .. }
+                if available == Uint256::new(150) && requested == Uint256::new(200)
+        ));
+    }
+
+    #[test]
+    fn instantiate_opens_funded_initial_interest() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+        let env = mock_env();
+        let request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+
+        // Instantiate funds reach the contract before it runs.
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(200, "uatom")],
+        );
+
+        let msg = InstantiateMsg {
+            initial_open_interest: Some(request.clone()),
+            ..Default::default()
+        };
+        let response = instantiate(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&sender, &[sample_coin(200, "uatom")]),
+            msg,
+        )
+        .expect("instantiate succeeds");
+
+        assert_eq!(response.attributes[2].value, "100uusd");
+        assert_eq!(
+            OPEN_INTEREST.load(&deps.storage).unwrap(),
+            Some(crate::types::OpenInterest {
+                interest_nonce: 1,
+                ..request
+            })
+        );
+        assert_eq!(
+            OPEN_INTEREST_CREATED.load(&deps.storage).unwrap(),
+            Some(env.block.time)
+        );
+    }
+
+    #[test]
+    fn instantiate_stores_initial_interest_reference_separately() {
+        let mut deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+        let env = mock_env();
+        let mut request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(200, "uatom")],
+        );
+
+        request.reference = Some("x".repeat(MAX_LOAN_REFERENCE_LENGTH + 1));
+        let msg = InstantiateMsg {
+            initial_open_interest: Some(request.clone()),
+            ..Default::default()
+        };
+        let err = instantiate(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&sender, &[sample_coin(200, "uatom")]),
+            msg,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ReferenceTooLong { max } if max == MAX_LOAN_REFERENCE_LENGTH
+        ));
+
+        request.reference = Some("invoice-1".to_string());
+        let msg = InstantiateMsg {
+            initial_open_interest: Some(request.clone()),
+            ..Default::default()
+        };
+        instantiate(
+            deps.as_mut(),
+            env,
+            message_info(&sender, &[sample_coin(200, "uatom")]),
+            msg,
+        )
+        .expect("instantiate succeeds");
+
+        assert_eq!(
+            LOAN_REFERENCE.load(&deps.storage).unwrap(),
+            Some("invoice-1".to_string())
+        );
+        let stored = OPEN_INTEREST.load(&deps.storage).unwrap().unwrap();
+        assert_eq!(stored.reference, None);
+    }
 }
@@ -0,0 +1,255 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{Addr, DepsMut, Env, MigrateInfo, Response, StdResult, Storage};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Item;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::instantiate::{CONTRACT_NAME, CONTRACT_VERSION};
+use crate::error::ContractError;
+use crate::msg::MigrateMsg;
+use crate::state::{
+    CONFIG, LAST_LIQUIDATION_UNBONDING, LENDER, OPEN_INTEREST, OPEN_INTEREST_CREATED,
+    OPEN_INTEREST_EXPIRY, OUTSTANDING_DEBT,
+};
+use crate::types::Config;
+
+type MigrationStep = fn(&mut dyn Storage) -> StdResult<()>;
+
+/// Ordered state upgrades. Every step must be idempotent so re-running a migration is safe.
+const MIGRATION_STEPS: &[MigrationStep] = &[initialize_missing_items, initialize_config];
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+    _info: MigrateInfo,
+) -> Result<Response, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::MigrationContractMismatch {
+            expected: CONTRACT_NAME.to_string(),
+            found: stored.contract,
+        });
+    }
+
+    if parse_version(&stored.version)? > parse_version(CONTRACT_VERSION)? {
+        return Err(ContractError::MigrationDowngrade {
+            stored: stored.version,
+            current: CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    for step in MIGRATION_STEPS {
+        step(deps.storage)?;
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", stored.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+/// Parses the `major.minor.patch` core of a semver string, ignoring pre-release and build metadata.
+fn parse_version(version: &str) -> Result<(u64, u64, u64), ContractError> {
+    let invalid = || ContractError::InvalidContractVersion {
+        version: version.to_string(),
+    };
+
+    let core = version.split(['-', '+']).next().unwrap_or_default();
+    let mut parts = core.split('.').map(|part| part.parse::<u64>());
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => Ok((major, minor, patch)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Vaults deployed before these items existed would fail on `load`, so seed them with defaults.
+fn initialize_missing_items(storage: &mut dyn Storage) -> StdResult<()> {
+    if OUTSTANDING_DEBT.may_load(storage)?.is_none() {
+        OUTSTANDING_DEBT.save(storage, &None)?;
+    }
+    if OPEN_INTEREST.may_load(storage)?.is_none() {
+        OPEN_INTEREST.save(storage, &None)?;
+    }
+    if OPEN_INTEREST_CREATED.may_load(storage)?.is_none() {
+        OPEN_INTEREST_CREATED.save(storage, &None)?;
+    }
+    if LENDER.may_load(storage)?.is_none() {
+        LENDER.save(storage, &None)?;
+    }
+    if OPEN_INTEREST_EXPIRY.may_load(storage)?.is_none() {
+        OPEN_INTEREST_EXPIRY.save(storage, &None)?;
+    }
+    if LAST_LIQUIDATION_UNBONDING.may_load(storage)?.is_none() {
+        LAST_LIQUIDATION_UNBONDING.save(storage, &None)?;
+    }
+
+    Ok(())
+}
+
+/// Builds `CONFIG` from the per-setting items older vaults stored, falling back to the
+/// instantiate defaults, and deletes those items.
+fn initialize_config(storage: &mut dyn Storage) -> StdResult<()> {
+    if CONFIG.may_load(storage)?.is_some() {
+        return Ok(());
+    }
+
+    let defaults = Config::default();
+    let config = Config {
+        liquidation_unbonding_duration: take_legacy(storage, "liquidation_unbonding_duration")?
+            .unwrap_or(defaults.liquidation_unbonding_duration),
+        allowed_denoms: take_legacy::<Option<Vec<String>>>(storage, "allowed_denoms")?.flatten(),
+        max_counter_offers: take_legacy(storage, "max_counter_offers_limit")?
+            .unwrap_or(defaults.max_counter_offers),
+        liquidation_bonus_bps: take_legacy(storage, "liquidation_bonus_bps")?
+            .unwrap_or(defaults.liquidation_bonus_bps),
+        unbonding_seconds: take_legacy(storage, "unbonding_seconds")?,
+        max_expiry_duration: take_legacy(storage, "max_expiry_duration")?,
+        min_liquidity: take_legacy(storage, "min_liquidity")?,
+        max_per_validator: take_legacy(storage, "max_per_validator")?,
+        hook_contract: take_legacy::<Addr>(storage, "hook_contract")?,
+        slashing_haircut_bps: take_legacy(storage, "slashing_haircut_bps")?
+            .unwrap_or(defaults.slashing_haircut_bps),
+        return_liquidation_surplus: take_legacy(storage, "return_liquidation_surplus")?
+            .unwrap_or(defaults.return_liquidation_surplus),
+        undelegation_priority: take_legacy(storage, "undelegation_priority")?
+            .unwrap_or(defaults.undelegation_priority),
+        fee_bps: take_legacy(storage, "protocol_fee_bps")?.unwrap_or(defaults.fee_bps),
+        fee_recipient: take_legacy::<Addr>(storage, "protocol_fee_recipient")?,
+    };
+    CONFIG.save(storage, &config)
+}
+
+/// Loads and removes a per-setting item stored under `key` before the tunables moved to `CONFIG`.
+fn take_legacy<T: Serialize + DeserializeOwned>(
+    storage: &mut dyn Storage,
+    key: &'static str,
+) -> StdResult<Option<T>> {
+    let item: Item<T> = Item::new(key);
+    let value = item.may_load(storage)?;
+    item.remove(storage);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::OWNER;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Timestamp;
+
+    fn migrate_info() -> MigrateInfo {
+        MigrateInfo {
+            sender: mock_dependencies().api.addr_make("admin"),
+            old_migrate_version: None,
+        }
+    }
+
+    #[test]
+    fn initializes_items_missing_from_older_vaults() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+
+        let response =
+            migrate(deps.as_mut(), mock_env(), MigrateMsg {}, migrate_info()).expect("migrates");
+
+        assert_eq!(response.attributes[1].value, "0.0.1");
+        assert_eq!(OPEN_INTEREST_EXPIRY.load(&deps.storage).unwrap(), None);
+        assert_eq!(LENDER.load(&deps.storage).unwrap(), None);
+        assert_eq!(OUTSTANDING_DEBT.load(&deps.storage).unwrap(), None);
+        assert_eq!(CONFIG.load(&deps.storage).unwrap(), Config::default());
+        assert_eq!(
+            get_contract_version(&deps.storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+    }
+
+    #[test]
+    fn preserves_existing_state() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+        let expiry = Some(Timestamp::from_seconds(42));
+        OPEN_INTEREST_EXPIRY
+            .save(deps.as_mut().storage, &expiry)
+            .unwrap();
+        let config = Config {
+            liquidation_unbonding_duration: 7,
+            fee_bps: 250,
+            ..Config::default()
+        };
+        CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}, migrate_info()).expect("migrates");
+
+        assert_eq!(OPEN_INTEREST_EXPIRY.load(&deps.storage).unwrap(), expiry);
+        assert_eq!(CONFIG.load(&deps.storage).unwrap(), config);
+    }
+
+    #[test]
+    fn builds_config_from_per_setting_items() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+        let duration: Item<u64> = Item::new("liquidation_unbonding_duration");
+        duration.save(deps.as_mut().storage, &7).unwrap();
+        let fee_bps: Item<u16> = Item::new("protocol_fee_bps");
+        fee_bps.save(deps.as_mut().storage, &250).unwrap();
+        let hook: Item<Addr> = Item::new("hook_contract");
+        let hook_contract = deps.api.addr_make("hook");
+        hook.save(deps.as_mut().storage, &hook_contract).unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}, migrate_info()).expect("migrates");
+
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap(),
+            Config {
+                liquidation_unbonding_duration: 7,
+                fee_bps: 250,
+                hook_contract: Some(hook_contract),
+                ..Config::default()
+            }
+        );
+        assert!(!duration.exists(&deps.storage));
+        assert!(!fee_bps.exists(&deps.storage));
+        assert!(!hook.exists(&deps.storage));
+    }
+
+    #[test]
+    fn refuses_downgrades() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}, migrate_info()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::MigrationDowngrade { stored, .. } if stored == "99.0.0"
+        ));
+    }
+
+    #[test]
+    fn refuses_foreign_contracts() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, "crates.io:other", "0.1.0").unwrap();
+
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}, migrate_info()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::MigrationContractMismatch { found, .. } if found == "crates.io:other"
+        ));
+    }
+
+    #[test]
+    fn parses_semver_core() {
+        assert_eq!(parse_version("1.2.3").unwrap(), (1, 2, 3));
+        assert_eq!(parse_version("1.2.3-rc.1+build").unwrap(), (1, 2, 3));
+        assert!(parse_version("1.2").is_err());
+        assert!(parse_version("1.x.3").is_err());
+    }
+}
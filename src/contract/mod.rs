@@ -1,8 +1,12 @@
+mod config;
 mod counter_offer;
 mod execute;
 mod instantiate;
+mod migrate;
 mod open_interest;
 mod query;
+pub(crate) mod reply;
+mod snapshot;
 mod staking;
 mod transfer;
 mod vote;
@@ -10,4 +14,6 @@ mod withdraw;
 
 pub use execute::execute;
 pub use instantiate::instantiate;
+pub use migrate::migrate;
 pub use query::query;
+pub use reply::reply;
@@ -0,0 +1,159 @@
+use cosmwasm_std::{attr, DepsMut, MessageInfo, Response, Uint256};
+
+use crate::{
+    helpers::require_owner,
+    state::{LENDER, OPEN_INTEREST},
+    ContractError,
+};
+
+/// Raises the collateral pledged on the funded loan by the coins attached in its denom.
+pub fn add_collateral(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    let mut open_interest = OPEN_INTEREST
+        .load(deps.storage)?
+        .ok_or(ContractError::NoOpenInterest {})?;
+    if LENDER.load(deps.storage)?.is_none() {
+        return Err(ContractError::NoOpenInterest {});
+    }
+
+    let denom = open_interest.collateral.denom.clone();
+    if let Some(stray) = info.funds.iter().find(|coin| coin.denom != denom) {
+        return Err(ContractError::UnexpectedFunds {
+            denom: stray.denom.clone(),
+        });
+    }
+
+    let added = info
+        .funds
+        .iter()
+        .try_fold(Uint256::zero(), |acc, coin| acc.checked_add(coin.amount))
+        .map_err(|_| ContractError::EscrowSumOverflow {
+            denom: denom.clone(),
+        })?;
+    if added.is_zero() {
+        return Err(ContractError::InvalidCoinAmount { field: "funds" });
+    }
+
+    open_interest.collateral.amount =
+        open_interest
+            .collateral
+            .amount
+            .checked_add(added)
+            .map_err(|_| ContractError::EscrowSumOverflow {
+                denom: denom.clone(),
+            })?;
+    OPEN_INTEREST.save(deps.storage, &Some(open_interest.clone()))?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "add_collateral"),
+        attr("collateral_denom", denom),
+        attr("added_amount", added.to_string()),
+        attr(
+            "collateral_amount",
+            open_interest.collateral.amount.to_string(),
+        ),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::open_interest::test_helpers::{
+        build_open_interest, sample_coin, setup, setup_active_open_interest,
+    };
+    use cosmwasm_std::testing::{message_info, mock_dependencies};
+
+    #[test]
+    fn rejects_without_active_loan() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let err = add_collateral(
+            deps.as_mut(),
+            message_info(&owner, &[sample_coin(50, "uatom")]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoOpenInterest {}));
+
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(interest))
+            .expect("interest stored");
+
+        let err = add_collateral(
+            deps.as_mut(),
+            message_info(&owner, &[sample_coin(50, "uatom")]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoOpenInterest {}));
+    }
+
+    #[test]
+    fn rejects_funds_in_other_denoms() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let err = add_collateral(
+            deps.as_mut(),
+            message_info(&owner, &[sample_coin(50, "uatom"), sample_coin(5, "uusd")]),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::UnexpectedFunds { denom } if denom == "uusd"
+        ));
+    }
+
+    #[test]
+    fn increases_stored_collateral() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let intruder = deps.api.addr_make("intruder");
+        let err = add_collateral(
+            deps.as_mut(),
+            message_info(&intruder, &[sample_coin(50, "uatom")]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let response = add_collateral(
+            deps.as_mut(),
+            message_info(&owner, &[sample_coin(50, "uatom")]),
+        )
+        .expect("collateral added");
+
+        assert!(response
+            .attributes
+            .contains(&attr("collateral_amount", "250")));
+        let stored = OPEN_INTEREST
+            .load(deps.as_ref().storage)
+            .expect("interest fetched")
+            .expect("interest present");
+        assert_eq!(stored.collateral, sample_coin(250, "uatom"));
+    }
+}
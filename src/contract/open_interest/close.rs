@@ -1,15 +1,51 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
 
 use crate::{
     helpers::require_owner,
-    state::{LENDER, OPEN_INTEREST},
+    state::{LENDER, OPEN_INTEREST, OPEN_INTEREST_CREATED},
+    types::OpenInterest,
     ContractError,
 };
 
-use super::helpers::{clear_active_lender, open_interest_attributes, refund_counter_offer_escrow};
+use super::helpers::{
+    clear_active_lender, credit_counter_offer_escrow, open_interest_attributes, refund_attributes,
+    refund_counter_offer_escrow, refund_messages,
+};
+
+pub fn close(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let open_interest = close_open_interest(&mut deps, &env, &info)?;
+    let refunds = refund_counter_offer_escrow(deps.storage)?;
+
+    let mut attrs = open_interest_attributes("close_open_interest", &open_interest);
+    attrs.push(attr("refunded_offers", refunds.len().to_string()));
+    attrs.extend(refund_attributes(&refunds));
+
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_messages(refund_messages(&refunds)))
+}
+
+pub fn close_to_unclaimed(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let open_interest = close_open_interest(&mut deps, &env, &info)?;
+    let credited = credit_counter_offer_escrow(deps.storage)?;
+
+    let mut attrs = open_interest_attributes("close_open_interest", &open_interest);
+    attrs.push(attr("credited_refunds", credited.to_string()));
 
-pub fn close(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-    require_owner(&deps, &info)?;
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Validates that the open interest can be closed and clears it, leaving escrow handling to the caller.
+fn close_open_interest(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<OpenInterest, ContractError> {
+    require_owner(deps, info)?;
 
     if LENDER.load(deps.storage)?.is_some() {
         return Err(ContractError::LenderAlreadySet {});
@@ -19,15 +55,21 @@ pub fn close(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError
         .load(deps.storage)?
         .ok_or(ContractError::NoOpenInterest {})?;
 
+    if let (Some(min_open_duration), Some(created)) = (
+        open_interest.min_open_duration,
+        OPEN_INTEREST_CREATED.may_load(deps.storage)?.flatten(),
+    ) {
+        let unlockable_at = created.plus_seconds(min_open_duration);
+        if env.block.time < unlockable_at {
+            return Err(ContractError::OpenInterestLocked { unlockable_at });
+        }
+    }
+
     OPEN_INTEREST.save(deps.storage, &None)?;
+    OPEN_INTEREST_CREATED.save(deps.storage, &None)?;
     clear_active_lender(deps.storage)?;
-    let refund_msgs = refund_counter_offer_escrow(deps.storage)?;
-
-    let attrs = open_interest_attributes("close_open_interest", &open_interest);
 
-    Ok(Response::new()
-        .add_attributes(attrs)
-        .add_messages(refund_msgs))
+    Ok(open_interest)
 }
 
 #[cfg(test)]
@@ -54,7 +96,7 @@ mod tests {
         setup(deps.as_mut().storage, &owner);
         let intruder = deps.api.addr_make("intruder");
 
-        let err = close(deps.as_mut(), message_info(&intruder, &[])).unwrap_err();
+        let err = close(deps.as_mut(), mock_env(), message_info(&intruder, &[])).unwrap_err();
 
         assert!(matches!(err, ContractError::Unauthorized {}));
     }
@@ -65,7 +107,7 @@ mod tests {
         let owner = deps.api.addr_make("owner");
         setup(deps.as_mut().storage, &owner);
 
-        let err = close(deps.as_mut(), message_info(&owner, &[])).unwrap_err();
+        let err = close(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap_err();
 
         assert!(matches!(err, ContractError::NoOpenInterest {}));
     }
@@ -91,7 +133,7 @@ mod tests {
             .save(deps.as_mut().storage, &Some(lender))
             .expect("lender stored");
 
-        let err = close(deps.as_mut(), message_info(&owner, &[])).unwrap_err();
+        let err = close(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap_err();
 
         assert!(matches!(err, ContractError::LenderAlreadySet {}));
     }
@@ -113,7 +155,8 @@ mod tests {
             .save(deps.as_mut().storage, &Some(request.clone()))
             .expect("open interest stored");
 
-        let response = close(deps.as_mut(), message_info(&owner, &[])).expect("close succeeds");
+        let response =
+            close(deps.as_mut(), mock_env(), message_info(&owner, &[])).expect("close succeeds");
 
         assert!(response.messages.is_empty());
         assert_eq!(
@@ -154,7 +197,8 @@ mod tests {
             .save(deps.as_mut().storage, &proposer, &request)
             .expect("counter offer stored");
 
-        let response = close(deps.as_mut(), message_info(&owner, &[])).expect("close succeeds");
+        let response =
+            close(deps.as_mut(), mock_env(), message_info(&owner, &[])).expect("close succeeds");
 
         assert_eq!(response.messages.len(), 1);
         let message = &response.messages[0];
@@ -215,7 +259,7 @@ mod tests {
             .save(deps.as_mut().storage, &Some(offer.liquidity_coin.clone()))
             .expect("debt stored");
 
-        close(deps.as_mut(), message_info(&owner, &[])).expect("close succeeds");
+        close(deps.as_mut(), mock_env(), message_info(&owner, &[])).expect("close succeeds");
 
         let reopened_request = build_open_interest(
             sample_coin(200, "uusd"),
@@ -241,7 +285,13 @@ mod tests {
         let stored = OPEN_INTEREST
             .load(deps.as_ref().storage)
             .expect("open interest fetched");
-        assert_eq!(stored, Some(reopened_request));
+        assert_eq!(
+            stored,
+            Some(OpenInterest {
+                interest_nonce: 2,
+                ..reopened_request
+            })
+        );
 
         let debt = OUTSTANDING_DEBT
             .load(deps.as_ref().storage)
@@ -296,7 +346,8 @@ mod tests {
             .save(deps.as_mut().storage, &Some(Coin::new(170u128, "uusd")))
             .expect("debt stored");
 
-        let response = close(deps.as_mut(), message_info(&owner, &[])).expect("close succeeds");
+        let response =
+            close(deps.as_mut(), mock_env(), message_info(&owner, &[])).expect("close succeeds");
 
         assert_eq!(response.messages.len(), 2);
         let mut recipients = response
@@ -326,9 +377,70 @@ mod tests {
 
         assert_eq!(recipients, expected);
 
+        assert!(response.attributes.contains(&attr("refunded_offers", "2")));
+        for (index, (address, coins)) in expected.iter().enumerate() {
+            assert!(response
+                .attributes
+                .contains(&attr(format!("refund_{index}_address"), *address)));
+            assert!(response.attributes.contains(&attr(
+                format!("refund_{index}_amount"),
+                coins[0].to_string()
+            )));
+        }
+
         let debt = OUTSTANDING_DEBT
             .load(deps.as_ref().storage)
             .expect("debt queried");
         assert!(debt.is_none());
     }
+
+    #[test]
+    fn close_respects_minimum_open_duration() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let mut request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        request.min_open_duration = Some(3_600);
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            request,
+        )
+        .expect("open interest succeeds");
+
+        let mut early_env = env.clone();
+        early_env.block.time = env.block.time.plus_seconds(3_599);
+        let err = close(deps.as_mut(), early_env, message_info(&owner, &[])).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::OpenInterestLocked { unlockable_at }
+                if unlockable_at == env.block.time.plus_seconds(3_600)
+        ));
+
+        let mut unlocked_env = env.clone();
+        unlocked_env.block.time = env.block.time.plus_seconds(3_600);
+        close(deps.as_mut(), unlocked_env, message_info(&owner, &[]))
+            .expect("close succeeds once unlocked");
+
+        assert!(OPEN_INTEREST
+            .load(deps.as_ref().storage)
+            .expect("state loaded")
+            .is_none());
+        assert!(OPEN_INTEREST_CREATED
+            .load(deps.as_ref().storage)
+            .expect("state loaded")
+            .is_none());
+    }
 }
@@ -1,32 +1,50 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
 
 use crate::{
     helpers::require_owner,
-    state::{COUNTER_OFFERS, OPEN_INTEREST},
+    state::{
+        COUNTER_OFFERS, LIQUIDATED_COLLATERAL_DENOM, LOAN_SETTLED, OPEN_INTEREST,
+        OPEN_INTEREST_CREATED,
+    },
     types::OpenInterest,
     ContractError,
 };
 
-use super::helpers::{open_interest_attributes, validate_open_interest};
+use super::helpers::{
+    assign_interest_nonce, open_interest_attributes, store_loan_reference, validate_open_interest,
+};
 
 pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    open_interest: OpenInterest,
+    mut open_interest: OpenInterest,
 ) -> Result<Response, ContractError> {
     require_owner(&deps, &info)?;
 
     if OPEN_INTEREST.load(deps.storage)?.is_some() {
         return Err(ContractError::OpenInterestAlreadyExists {});
     }
+    let reference = store_loan_reference(deps.storage, &mut open_interest)?;
     let deps_ref = deps.as_ref();
     validate_open_interest(&deps_ref, &env, &open_interest)?;
+    assign_interest_nonce(deps.storage, &mut open_interest)?;
 
     OPEN_INTEREST.save(deps.storage, &Some(open_interest.clone()))?;
+    OPEN_INTEREST_CREATED.save(deps.storage, &Some(env.block.time))?;
     COUNTER_OFFERS.clear(deps.storage);
-
-    let attrs = open_interest_attributes("open_interest", &open_interest);
+    // Collateral left by an earlier liquidation is pledged to the new interest from here on.
+    LIQUIDATED_COLLATERAL_DENOM.remove(deps.storage);
+    LOAN_SETTLED.remove(deps.storage);
+
+    let mut attrs = open_interest_attributes("open_interest", &open_interest);
+    attrs.push(attr(
+        "interest_nonce",
+        open_interest.interest_nonce.to_string(),
+    ));
+    if let Some(reference) = reference {
+        attrs.push(attr("reference", reference));
+    }
     Ok(Response::new().add_attributes(attrs))
 }
 
@@ -34,12 +52,17 @@ pub fn execute(
 mod tests {
     use super::*;
     use crate::{
-        contract::open_interest::test_helpers::{build_open_interest, sample_coin, setup},
-        state::OPEN_INTEREST,
+        contract::{
+            open_interest::test_helpers::{build_open_interest, sample_coin, setup},
+            query::query,
+        },
+        msg::QueryMsg,
+        state::{MAX_LOAN_REFERENCE_LENGTH, OPEN_INTEREST},
+        types::InfoResponse,
         ContractError,
     };
     use cosmwasm_std::{
-        coins,
+        coins, from_json,
         testing::{message_info, mock_dependencies, mock_env},
         Coin, Uint128, Uint256,
     };
@@ -174,6 +197,30 @@ mod tests {
         assert!(matches!(err, ContractError::InvalidExpiryDuration {}));
     }
 
+    #[test]
+    fn rejects_repayment_deadline_at_or_past_expiry() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+        let mut request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        request.repayment_deadline_duration = Some(86_400);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            request,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::InvalidRepaymentDeadline {}));
+    }
+
     #[test]
     fn rejects_repayment_overflow() {
         let mut deps = mock_dependencies();
@@ -229,12 +276,71 @@ mod tests {
         .expect("open interest succeeds");
 
         assert!(response.messages.is_empty());
-        assert_eq!(response.attributes.len(), 8);
+        assert_eq!(response.attributes.len(), 9);
+        assert!(response.attributes.contains(&attr("interest_nonce", "1")));
 
         let stored = OPEN_INTEREST
             .load(deps.as_ref().storage)
             .expect("interest fetched");
 
-        assert_eq!(stored, Some(request));
+        assert_eq!(
+            stored,
+            Some(OpenInterest {
+                interest_nonce: 1,
+                ..request
+            })
+        );
+    }
+
+    #[test]
+    fn reference_round_trips_through_info() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+        let mut request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+
+        request.reference = Some("x".repeat(MAX_LOAN_REFERENCE_LENGTH + 1));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            request.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ReferenceTooLong { max } if max == MAX_LOAN_REFERENCE_LENGTH
+        ));
+
+        request.reference = Some("loan-42".to_string());
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            request.clone(),
+        )
+        .expect("open interest succeeds");
+        assert!(response.attributes.contains(&attr("reference", "loan-42")));
+
+        let stored = OPEN_INTEREST
+            .load(deps.as_ref().storage)
+            .expect("interest fetched")
+            .expect("interest present");
+        assert_eq!(stored.reference, None);
+
+        let info: InfoResponse =
+            from_json(query(deps.as_ref(), env, QueryMsg::Info).expect("query succeeds"))
+                .expect("valid json");
+        assert_eq!(info.reference, Some("loan-42".to_string()));
     }
 }
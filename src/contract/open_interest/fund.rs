@@ -1,14 +1,16 @@
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint256};
 
 use crate::{
-    state::{LENDER, OPEN_INTEREST},
+    helpers::{balance_change_attributes, hook_submessage},
+    msg::HookMsg,
+    state::{LENDER, OPEN_INTEREST, OWNER},
     types::OpenInterest,
     ContractError,
 };
 
 use super::helpers::{
-    open_interest_attributes, refund_counter_offer_escrow, set_active_lender,
-    validate_liquidity_funding,
+    open_interest_attributes, refund_attributes, refund_counter_offer_escrow, refund_messages,
+    set_active_lender, validate_liquidity_funding,
 };
 
 pub fn fund(
@@ -25,6 +27,11 @@ pub fn fund(
         return Err(ContractError::LenderAlreadySet {});
     }
 
+    // A self-loan has no economic meaning and would make repayment a round trip.
+    if info.sender == OWNER.load(deps.storage)? {
+        return Err(ContractError::OwnerCannotFund {});
+    }
+
     if open_interest != expected_interest {
         return Err(ContractError::OpenInterestMismatch {});
     }
@@ -33,17 +40,38 @@ pub fn fund(
 
     let lender = info.sender;
     let expiry = env.block.time.plus_seconds(open_interest.expiry_duration);
-    set_active_lender(deps.storage, lender.clone(), expiry)?;
+    set_active_lender(deps.storage, lender.clone(), env.block.time, expiry)?;
 
-    let refund_msgs = refund_counter_offer_escrow(deps.storage)?;
-    let refund_count = refund_msgs.len();
+    let refunds = refund_counter_offer_escrow(deps.storage)?;
 
     let mut attrs = open_interest_attributes("fund_open_interest", &open_interest);
     attrs.push(attr("lender", lender.as_str()));
-    attrs.push(attr("refunded_offers", refund_count.to_string()));
+    attrs.push(attr("refunded_offers", refunds.len().to_string()));
+    attrs.extend(refund_attributes(&refunds));
+
+    let liquidity_denom = &open_interest.liquidity_coin.denom;
+    let refunded: Uint256 = refunds
+        .iter()
+        .filter(|(_, coin)| coin.denom == *liquidity_denom)
+        .fold(Uint256::zero(), |acc, (_, coin)| acc + coin.amount);
+    attrs.extend(balance_change_attributes(
+        &deps.as_ref(),
+        &env,
+        liquidity_denom,
+        refunded,
+    )?);
+
+    let hook = hook_submessage(
+        deps.storage,
+        HookMsg::Funded {
+            lender: lender.to_string(),
+            open_interest,
+        },
+    )?;
 
     Ok(Response::new()
-        .add_messages(refund_msgs)
+        .add_messages(refund_messages(&refunds))
+        .add_submessages(hook)
         .add_attributes(attrs))
 }
 
@@ -51,8 +79,14 @@ pub fn fund(
 mod tests {
     use super::*;
     use crate::{
-        contract::open_interest::test_helpers::{build_open_interest, sample_coin, setup},
-        state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST, OPEN_INTEREST_EXPIRY, OUTSTANDING_DEBT},
+        contract::open_interest::{
+            close, execute,
+            test_helpers::{build_open_interest, sample_coin, setup},
+        },
+        state::{
+            COUNTER_OFFERS, LENDER, OPEN_INTEREST, OPEN_INTEREST_EXPIRY, OPEN_INTEREST_FUNDED,
+            OUTSTANDING_DEBT,
+        },
         ContractError,
     };
     use cosmwasm_std::coins;
@@ -194,6 +228,95 @@ mod tests {
         assert!(matches!(err, ContractError::OpenInterestMismatch {}));
     }
 
+    #[test]
+    fn fund_rejects_owner_funding_own_vault() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(request.clone()))
+            .expect("open interest stored");
+
+        let err = fund(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[request.liquidity_coin.clone()]),
+            request,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::OwnerCannotFund {}));
+        assert!(LENDER.load(deps.as_ref().storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn fund_rejects_terms_from_a_previous_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(200, "uatom")],
+        );
+        let request = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            request.clone(),
+        )
+        .expect("first interest opens");
+        let stale = OPEN_INTEREST
+            .load(deps.as_ref().storage)
+            .expect("interest fetched")
+            .expect("interest present");
+        close(deps.as_mut(), env.clone(), message_info(&owner, &[])).expect("close succeeds");
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            request.clone(),
+        )
+        .expect("identical interest reopens");
+
+        let lender = deps.api.addr_make("lender");
+        let err = fund(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&lender, &[request.liquidity_coin.clone()]),
+            stale.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OpenInterestMismatch {}));
+
+        let live = OpenInterest {
+            interest_nonce: stale.interest_nonce + 1,
+            ..stale
+        };
+        fund(
+            deps.as_mut(),
+            env,
+            message_info(&lender, &[request.liquidity_coin]),
+            live,
+        )
+        .expect("live terms fund");
+    }
+
     #[test]
     fn fund_sets_lender_and_refunds_counter_offers() {
         let mut deps = mock_dependencies();
@@ -236,16 +359,35 @@ mod tests {
             .save(deps.as_mut().storage, &Some(request.liquidity_coin.clone()))
             .expect("debt stored");
 
+        // Escrowed offers plus the attached funding are already held by the vault.
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(2_700, "uusd"));
+
         let lender = deps.api.addr_make("lender");
         let response = fund(
             deps.as_mut(),
-            mock_env(),
+            env,
             message_info(&lender, &[request.liquidity_coin.clone()]),
             request.clone(),
         )
         .expect("fund succeeds");
 
         assert_eq!(response.attributes[0], attr("action", "fund_open_interest"));
+        assert!(response
+            .attributes
+            .contains(&attr("balance_before", "2700")));
+        assert!(response.attributes.contains(&attr("balance_after", "1000")));
+        let refunded_to: Vec<&str> = response
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.key.ends_with("_address"))
+            .map(|attribute| attribute.value.as_str())
+            .collect();
+        let mut proposers = vec![proposer_a.as_str(), proposer_b.as_str()];
+        proposers.sort();
+        assert_eq!(refunded_to, proposers);
         assert_eq!(response.messages.len(), 2);
         for msg in &response.messages {
             match &msg.msg {
@@ -312,5 +454,9 @@ mod tests {
             .expect("expiry set");
         let expected = env.block.time.plus_seconds(request.expiry_duration);
         assert_eq!(stored_expiry, expected);
+        assert_eq!(
+            OPEN_INTEREST_FUNDED.load(deps.as_ref().storage).unwrap(),
+            env.block.time
+        );
     }
 }
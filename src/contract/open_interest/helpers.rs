@@ -6,35 +6,112 @@ use std::collections::{btree_map::Entry, BTreeMap};
 use std::convert::TryFrom;
 
 use crate::{
-    helpers::{minimum_collateral_lock_for_denom, query_staking_rewards, require_owner_or_lender},
+    helpers::{
+        load_config, minimum_collateral_lock_for_denom, query_staking_rewards,
+        require_owner_or_lender,
+    },
     state::{
-        COUNTER_OFFERS, DEFAULT_LIQUIDATION_UNBONDING_SECONDS, LAST_LIQUIDATION_UNBONDING, LENDER,
-        LIQUIDATION_UNBONDING_DURATION, OPEN_INTEREST, OPEN_INTEREST_EXPIRY, OUTSTANDING_DEBT,
+        COUNTER_OFFERS, INTEREST_NONCE, LAST_LIQUIDATION_HEIGHT, LAST_LIQUIDATION_UNBONDING,
+        LENDER, LENDER_PAYOUT, LIQUIDATED_COLLATERAL_DENOM, LIQUIDATION_PAID,
+        LIQUIDATION_UNBONDING_AMOUNT, LOAN_HISTORY, LOAN_REFERENCE, LOAN_SETTLED, MAX_BPS,
+        MAX_LOAN_HISTORY, MAX_LOAN_REFERENCE_LENGTH, NEXT_LOAN_HISTORY_ID, OPEN_INTEREST,
+        OPEN_INTEREST_CREATED, OPEN_INTEREST_EXPIRY, OPEN_INTEREST_FUNDED, OUTSTANDING_DEBT,
+        QUEUED_INTEREST, REPAID_PRINCIPAL, UNCLAIMED_REFUNDS, UNCLAIMED_REFUND_TOTALS,
     },
-    types::OpenInterest,
+    types::{LoanHistoryEntry, LoanOutcome, OpenInterest},
     ContractError,
 };
 
-// TODO refactor all references to this.
-fn uint256_to_uint128(value: Uint256) -> Uint128 {
-    Uint128::try_from(value).expect("value must fit into Uint128")
+/// Stamps `open_interest` with the next nonce so terms signed against an earlier interest no
+/// longer match.
+pub(crate) fn assign_interest_nonce(
+    storage: &mut dyn Storage,
+    open_interest: &mut OpenInterest,
+) -> StdResult<()> {
+    let nonce = INTEREST_NONCE.may_load(storage)?.unwrap_or_default() + 1;
+    INTEREST_NONCE.save(storage, &nonce)?;
+    open_interest.interest_nonce = nonce;
+    Ok(())
+}
+
+/// Rejects loan references longer than `MAX_LOAN_REFERENCE_LENGTH`.
+pub(crate) fn validate_loan_reference(reference: Option<&str>) -> Result<(), ContractError> {
+    if reference.is_some_and(|reference| reference.len() > MAX_LOAN_REFERENCE_LENGTH) {
+        return Err(ContractError::ReferenceTooLong {
+            max: MAX_LOAN_REFERENCE_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+/// Moves the reference off `open_interest` into `LOAN_REFERENCE`, so the stored terms funders
+/// echo never carry it.
+pub(crate) fn store_loan_reference(
+    storage: &mut dyn Storage,
+    open_interest: &mut OpenInterest,
+) -> Result<Option<String>, ContractError> {
+    let reference = open_interest.reference.take();
+    validate_loan_reference(reference.as_deref())?;
+    LOAN_REFERENCE.save(storage, &reference)?;
+    Ok(reference)
 }
 
 pub(crate) fn validate_open_interest(
     deps: &Deps,
     env: &Env,
     open_interest: &OpenInterest,
+) -> Result<(), ContractError> {
+    validate_open_interest_after_payouts(deps, env, open_interest, &[])
+}
+
+/// Like `validate_open_interest`, but checks collateral against the balance left once `outgoing`
+/// coins queued in the same response have been sent.
+fn validate_open_interest_after_payouts(
+    deps: &Deps,
+    env: &Env,
+    open_interest: &OpenInterest,
+    outgoing: &[Coin],
 ) -> Result<(), ContractError> {
     validate_coin(&open_interest.liquidity_coin, "liquidity_coin")?;
     validate_coin(&open_interest.interest_coin, "interest_coin")?;
     validate_coin(&open_interest.collateral, "collateral")?;
 
+    // A shared denom would let the lender's funding count toward the collateral it is meant to secure.
+    if open_interest.collateral.denom == open_interest.liquidity_coin.denom {
+        return Err(ContractError::CollateralLiquidityDenomConflict {});
+    }
+
+    ensure_denoms_allowed(deps, open_interest)?;
+
+    let config = load_config(deps.storage)?;
+    if let Some(minimum) = config.min_liquidity {
+        if open_interest.liquidity_coin.amount < minimum {
+            return Err(ContractError::LiquidityBelowMinimum { minimum });
+        }
+    }
+
     if open_interest.expiry_duration == 0 {
         return Err(ContractError::InvalidExpiryDuration {});
     }
 
+    if let Some(maximum) = config.max_expiry_duration {
+        if open_interest.expiry_duration > maximum {
+            return Err(ContractError::ExpiryDurationTooLong { maximum });
+        }
+    }
+
+    if let Some(deadline) = open_interest.repayment_deadline_duration {
+        if deadline == 0 || deadline >= open_interest.expiry_duration {
+            return Err(ContractError::InvalidRepaymentDeadline {});
+        }
+    }
+
+    if open_interest.late_fee_bps.unwrap_or(0) > MAX_BPS {
+        return Err(ContractError::InvalidConfig {});
+    }
+
     build_repayment_amounts(open_interest)?;
-    ensure_collateral_available(deps, env, open_interest)?;
+    ensure_collateral_available(deps, env, open_interest, outgoing)?;
 
     Ok(())
 }
@@ -44,22 +121,55 @@ fn validate_coin(coin: &Coin, field: &'static str) -> Result<(), ContractError>
         return Err(ContractError::InvalidCoinAmount { field });
     }
 
-    if coin.denom.is_empty() {
+    validate_denom(&coin.denom, field)
+}
+
+/// Rejects denoms that could never match a bank balance. Path denoms such as `ibc/<HASH>` keep
+/// their case, since the hash is uppercase hex.
+pub(crate) fn validate_denom(denom: &str, field: &'static str) -> Result<(), ContractError> {
+    let has_whitespace = denom.chars().any(char::is_whitespace);
+    let has_uppercase = !denom.contains('/') && denom.chars().any(|c| c.is_ascii_uppercase());
+    if denom.is_empty() || has_whitespace || has_uppercase {
         return Err(ContractError::InvalidCoinDenom { field });
     }
 
     Ok(())
 }
 
+fn ensure_denoms_allowed(deps: &Deps, open_interest: &OpenInterest) -> Result<(), ContractError> {
+    let Some(allowed) = load_config(deps.storage)?.allowed_denoms else {
+        return Ok(());
+    };
+
+    for coin in [
+        &open_interest.liquidity_coin,
+        &open_interest.interest_coin,
+        &open_interest.collateral,
+    ] {
+        if !allowed.contains(&coin.denom) {
+            return Err(ContractError::DenomNotAllowed {
+                denom: coin.denom.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn ensure_collateral_available(
     deps: &Deps,
     env: &Env,
     open_interest: &OpenInterest,
+    outgoing: &[Coin],
 ) -> Result<(), ContractError> {
     let denom = open_interest.collateral.denom.clone();
     let requested = open_interest.collateral.amount;
 
-    let available = query_available_balance(deps, env, &denom)?;
+    let leaving = outgoing
+        .iter()
+        .filter(|coin| coin.denom == denom)
+        .fold(Uint256::zero(), |acc, coin| acc.saturating_add(coin.amount));
+    let available = query_available_balance(deps, env, &denom)?.saturating_sub(leaving);
     if available >= requested {
         return Ok(());
     }
@@ -80,8 +190,8 @@ fn ensure_collateral_available(
 
     Err(ContractError::InsufficientBalance {
         denom,
-        available: uint256_to_uint128(effective_balance),
-        requested: uint256_to_uint128(requested),
+        available: effective_balance,
+        requested,
     })
 }
 
@@ -139,6 +249,25 @@ pub(crate) fn build_repayment_amounts(
         .collect()
 }
 
+/// Coins `repay` requires right now: the remaining principal plus interest and any late fee,
+/// grouped by denom.
+pub(crate) fn repayment_due(
+    storage: &dyn Storage,
+    open_interest: &OpenInterest,
+    now: Timestamp,
+) -> Result<Vec<Coin>, ContractError> {
+    let mut outstanding = OpenInterest {
+        liquidity_coin: remaining_principal(storage, open_interest)?,
+        ..open_interest.clone()
+    };
+    outstanding.interest_coin.amount += late_fee(storage, open_interest, now)?;
+
+    Ok(build_repayment_amounts(&outstanding)?
+        .into_iter()
+        .map(|(denom, _, amount)| Coin::new(amount, denom))
+        .collect())
+}
+
 pub(crate) fn validate_liquidity_funding(
     info: &MessageInfo,
     liquidity_coin: &Coin,
@@ -149,7 +278,10 @@ pub(crate) fn validate_liquidity_funding(
         .funds
         .iter()
         .filter(|coin| coin.denom == *denom)
-        .fold(Uint256::zero(), |acc, coin| acc + coin.amount);
+        .try_fold(Uint256::zero(), |acc, coin| acc.checked_add(coin.amount))
+        .map_err(|_| ContractError::EscrowSumOverflow {
+            denom: denom.clone(),
+        })?;
 
     if received != expected {
         return Err(ContractError::OpenInterestFundingMismatch {
@@ -162,51 +294,235 @@ pub(crate) fn validate_liquidity_funding(
     Ok(())
 }
 
-pub(crate) fn refund_counter_offer_escrow(storage: &mut dyn Storage) -> StdResult<Vec<BankMsg>> {
+/// Clears every counter offer and returns the escrow owed back to each proposer.
+pub(crate) fn refund_counter_offer_escrow(
+    storage: &mut dyn Storage,
+) -> StdResult<Vec<(Addr, Coin)>> {
+    let refunds = COUNTER_OFFERS
+        .range(storage, None, None, Order::Ascending)
+        .map(|entry| entry.map(|(addr, offer)| (addr, offer.liquidity_coin)))
+        .collect::<StdResult<Vec<(Addr, Coin)>>>()?;
+
+    COUNTER_OFFERS.clear(storage);
+    OUTSTANDING_DEBT.save(storage, &None)?;
+
+    Ok(refunds)
+}
+
+pub(crate) fn refund_messages(refunds: &[(Addr, Coin)]) -> Vec<BankMsg> {
+    refunds
+        .iter()
+        .map(|(addr, coin)| BankMsg::Send {
+            to_address: addr.to_string(),
+            amount: vec![coin.clone()],
+        })
+        .collect()
+}
+
+/// `refund_N_address`/`refund_N_amount` pairs, bounded by the counter offer capacity.
+pub(crate) fn refund_attributes(refunds: &[(Addr, Coin)]) -> Vec<Attribute> {
+    refunds
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (addr, coin))| {
+            [
+                attr(format!("refund_{index}_address"), addr.as_str()),
+                attr(format!("refund_{index}_amount"), coin.to_string()),
+            ]
+        })
+        .collect()
+}
+
+/// Moves every counter offer escrow into `UNCLAIMED_REFUNDS`, returning how many were credited.
+pub(crate) fn credit_counter_offer_escrow(storage: &mut dyn Storage) -> StdResult<usize> {
     let offers = COUNTER_OFFERS
         .range(storage, None, None, Order::Ascending)
         .collect::<StdResult<Vec<(Addr, OpenInterest)>>>()?;
 
-    let mut refunds = Vec::with_capacity(offers.len());
-
     for (addr, offer) in &offers {
-        refunds.push(BankMsg::Send {
-            to_address: addr.to_string(),
-            amount: vec![offer.liquidity_coin.clone()],
-        });
+        let coin = &offer.liquidity_coin;
+        UNCLAIMED_REFUNDS.update(storage, (addr, coin.denom.as_str()), |current| {
+            current
+                .unwrap_or_default()
+                .checked_add(coin.amount)
+                .map_err(StdError::from)
+        })?;
+        UNCLAIMED_REFUND_TOTALS.update(storage, coin.denom.as_str(), |current| {
+            current
+                .unwrap_or_default()
+                .checked_add(coin.amount)
+                .map_err(StdError::from)
+        })?;
     }
 
     COUNTER_OFFERS.clear(storage);
     OUTSTANDING_DEBT.save(storage, &None)?;
 
-    Ok(refunds)
+    Ok(offers.len())
 }
 
 pub(crate) struct LiquidationState {
     pub(crate) open_interest: OpenInterest,
     pub(crate) lender: Addr,
+    /// Where the lender share of the payout is sent.
+    pub(crate) payout_recipient: Addr,
     pub(crate) collateral_denom: String,
     pub(crate) contract_addr: Addr,
     pub(crate) bonded_denom: String,
+    /// Bonus owed to the caller; zero for owner or lender self-liquidation.
+    pub(crate) liquidation_bonus_bps: u16,
 }
 
+/// Starts the loan: `funded_at` anchors the repayment deadline and `expiry` the liquidation window.
 pub fn set_active_lender(
     storage: &mut dyn Storage,
     lender: Addr,
+    funded_at: Timestamp,
     expiry: Timestamp,
 ) -> StdResult<()> {
     LENDER.save(storage, &Some(lender))?;
     OPEN_INTEREST_EXPIRY.save(storage, &Some(expiry))?;
+    OPEN_INTEREST_FUNDED.save(storage, &funded_at)?;
     Ok(())
 }
 
 pub fn clear_active_lender(storage: &mut dyn Storage) -> StdResult<()> {
     LENDER.save(storage, &None)?;
     OPEN_INTEREST_EXPIRY.save(storage, &None)?;
+    OPEN_INTEREST_FUNDED.remove(storage);
     LAST_LIQUIDATION_UNBONDING.save(storage, &None)?;
+    LIQUIDATION_PAID.remove(storage);
+    LIQUIDATION_UNBONDING_AMOUNT.remove(storage);
+    REPAID_PRINCIPAL.remove(storage);
+    LENDER_PAYOUT.remove(storage);
+    LOAN_REFERENCE.remove(storage);
     Ok(())
 }
 
+/// Appends a settled loan to `LOAN_HISTORY`, evicting the oldest entry once the cap is reached.
+pub(crate) fn record_loan_history(
+    storage: &mut dyn Storage,
+    lender: &Addr,
+    open_interest: &OpenInterest,
+    outcome: LoanOutcome,
+    settled_at: Timestamp,
+    liquidator: Option<&Addr>,
+) -> StdResult<()> {
+    let id = NEXT_LOAN_HISTORY_ID.may_load(storage)?.unwrap_or(0);
+    let entry = LoanHistoryEntry {
+        id,
+        lender: lender.to_string(),
+        liquidity: open_interest.liquidity_coin.clone(),
+        interest: open_interest.interest_coin.clone(),
+        collateral: open_interest.collateral.clone(),
+        outcome,
+        settled_at,
+        liquidator: liquidator.map(Addr::to_string),
+        reference: LOAN_REFERENCE.may_load(storage)?.flatten(),
+    };
+    LOAN_HISTORY.save(storage, id, &entry)?;
+    if id >= MAX_LOAN_HISTORY {
+        LOAN_HISTORY.remove(storage, id - MAX_LOAN_HISTORY);
+    }
+    NEXT_LOAN_HISTORY_ID.save(storage, &(id + 1))
+}
+
+/// Opens the queued interest, if any, once the previous loan has fully settled.
+///
+/// The queued terms are re-validated first; if collateral no longer covers them the queue is
+/// left in place and no interest is opened. `outgoing` holds the settlement payouts sent in the
+/// same response, which coverage must not count on.
+pub(crate) fn activate_queued_interest(
+    deps: &mut DepsMut,
+    env: &Env,
+    outgoing: &[Coin],
+) -> StdResult<Vec<Attribute>> {
+    let Some(mut next) = QUEUED_INTEREST.may_load(deps.storage)?.flatten() else {
+        return Ok(vec![]);
+    };
+
+    if let Err(err) = validate_open_interest_after_payouts(&deps.as_ref(), env, &next, outgoing) {
+        return Ok(vec![attr("queued_interest_activation_failed", err.code())]);
+    }
+    if let Err(err) = store_loan_reference(deps.storage, &mut next) {
+        return Ok(vec![attr("queued_interest_activation_failed", err.code())]);
+    }
+
+    QUEUED_INTEREST.remove(deps.storage);
+    assign_interest_nonce(deps.storage, &mut next)?;
+    OPEN_INTEREST.save(deps.storage, &Some(next.clone()))?;
+    OPEN_INTEREST_CREATED.save(deps.storage, &Some(env.block.time))?;
+    LIQUIDATED_COLLATERAL_DENOM.remove(deps.storage);
+    LOAN_SETTLED.remove(deps.storage);
+    Ok(vec![
+        attr("queued_interest_activated", "true"),
+        attr("open_interest", next.liquidity_coin.to_string()),
+    ])
+}
+
+/// Late fee owed in the interest denom when repaying after the repayment deadline.
+pub(crate) fn late_fee(
+    storage: &dyn Storage,
+    open_interest: &OpenInterest,
+    now: Timestamp,
+) -> StdResult<Uint256> {
+    let (Some(deadline), Some(funded_at)) = (
+        open_interest.repayment_deadline_duration,
+        OPEN_INTEREST_FUNDED.may_load(storage)?,
+    ) else {
+        return Ok(Uint256::zero());
+    };
+
+    if now <= funded_at.plus_seconds(deadline) {
+        return Ok(Uint256::zero());
+    }
+
+    let fee_bps = open_interest.late_fee_bps.unwrap_or(0);
+    Ok(open_interest
+        .interest_coin
+        .amount
+        .multiply_ratio(fee_bps, MAX_BPS))
+}
+
+/// Protocol fee carved out of the repaid `interest` and its recipient. `None` when no fee applies.
+pub(crate) fn protocol_fee(
+    storage: &dyn Storage,
+    interest: &Coin,
+) -> StdResult<Option<(Addr, Coin)>> {
+    let config = load_config(storage)?;
+    let Some(recipient) = config.fee_recipient else {
+        return Ok(None);
+    };
+
+    let amount = interest.amount.multiply_ratio(config.fee_bps, MAX_BPS);
+    if amount.is_zero() {
+        return Ok(None);
+    }
+
+    Ok(Some((recipient, Coin::new(amount, interest.denom.clone()))))
+}
+
+/// Returns the lender's preferred payout address, falling back to the lender itself.
+pub(crate) fn lender_payout_address(storage: &dyn Storage, lender: &Addr) -> StdResult<Addr> {
+    Ok(LENDER_PAYOUT
+        .may_load(storage)?
+        .flatten()
+        .unwrap_or_else(|| lender.clone()))
+}
+
+/// Principal still owed to the lender after any partial repayments.
+pub(crate) fn remaining_principal(
+    storage: &dyn Storage,
+    open_interest: &OpenInterest,
+) -> StdResult<Coin> {
+    let repaid = REPAID_PRINCIPAL.may_load(storage)?.unwrap_or_default();
+    let amount = open_interest.liquidity_coin.amount.checked_sub(repaid)?;
+    Ok(Coin::new(
+        amount,
+        open_interest.liquidity_coin.denom.clone(),
+    ))
+}
+
 pub(crate) struct CollectedFunds {
     pub(crate) available: Uint128,
     pub(crate) rewards_claimed: Uint128,
@@ -218,7 +534,13 @@ pub(crate) fn load_liquidation_state(
     env: &Env,
     info: &MessageInfo,
 ) -> Result<LiquidationState, ContractError> {
-    require_owner_or_lender(deps, info)?;
+    // Third parties may only liquidate when a bonus compensates them for doing so.
+    let configured_bonus_bps = load_config(deps.storage)?.liquidation_bonus_bps;
+    let liquidation_bonus_bps = match require_owner_or_lender(deps, info) {
+        Ok(_) => 0,
+        Err(ContractError::Unauthorized {}) if configured_bonus_bps > 0 => configured_bonus_bps,
+        Err(err) => return Err(err),
+    };
 
     let open_interest = OPEN_INTEREST
         .may_load(deps.storage)?
@@ -238,6 +560,7 @@ pub(crate) fn load_liquidation_state(
         return Err(ContractError::OpenInterestNotExpired {});
     }
 
+    let payout_recipient = lender_payout_address(deps.storage, &lender)?;
     let collateral_denom = open_interest.collateral.denom.clone();
     let contract_addr = env.contract.address.clone();
     let bonded_denom = deps.querier.query_bonded_denom()?;
@@ -245,9 +568,11 @@ pub(crate) fn load_liquidation_state(
     Ok(LiquidationState {
         open_interest,
         lender,
+        payout_recipient,
         collateral_denom,
         contract_addr,
         bonded_denom,
+        liquidation_bonus_bps,
     })
 }
 
@@ -259,10 +584,20 @@ pub(crate) fn get_outstanding_amount(
         return convert_amount(debt.amount, &state.collateral_denom);
     }
 
-    convert_amount(
-        state.open_interest.collateral.amount,
-        &state.collateral_denom,
-    )
+    // Principal already paid down with `RepayPartial` releases its share of the collateral.
+    let principal = remaining_principal(deps.storage, &state.open_interest)?;
+    let claim = state
+        .open_interest
+        .collateral
+        .amount
+        .multiply_ratio(principal.amount, state.open_interest.liquidity_coin.amount);
+    convert_amount(claim, &state.collateral_denom)
+}
+
+pub(crate) fn liquidation_math_error(detail: &str) -> ContractError {
+    ContractError::LiquidationMathError {
+        detail: detail.to_string(),
+    }
 }
 
 fn convert_amount(amount: Uint256, denom: &str) -> Result<Uint128, ContractError> {
@@ -306,7 +641,7 @@ pub(crate) fn collect_funds(
 
         total_available = total_available
             .checked_add(rewards_claimed)
-            .expect("liquidation total available overflow");
+            .map_err(|_| liquidation_math_error("total available overflow"))?;
     }
 
     let available = Uint128::try_from(total_available).map_err(|_| {
@@ -331,10 +666,11 @@ pub(crate) fn collect_funds(
 
 pub(crate) fn payout_message(
     state: &LiquidationState,
+    recipient: &Addr,
     payout_amount: Uint128,
 ) -> Result<CosmosMsg, ContractError> {
     Ok(CosmosMsg::Bank(BankMsg::Send {
-        to_address: state.lender.to_string(),
+        to_address: recipient.to_string(),
         amount: vec![Coin::new(
             payout_amount.u128(),
             state.collateral_denom.clone(),
@@ -351,9 +687,17 @@ pub(crate) fn schedule_undelegations(
         return Ok((Vec::new(), Uint128::zero()));
     }
 
-    let delegations = deps
+    let mut delegations = deps
         .querier
         .query_all_delegations(state.contract_addr.clone())?;
+    // Stable sort, so validators outside the priority list keep the querier order.
+    let priority = load_config(deps.storage)?.undelegation_priority;
+    delegations.sort_by_key(|delegation| {
+        priority
+            .iter()
+            .position(|validator| *validator == delegation.validator)
+            .unwrap_or(priority.len())
+    });
 
     let mut messages = Vec::new();
     let mut remaining_to_undelegate = Uint256::from(remaining);
@@ -371,27 +715,29 @@ pub(crate) fn schedule_undelegations(
 
         let amount = stake_amount.min(remaining_to_undelegate);
         let coin_amount = Uint128::try_from(amount)
-            .expect("undelegation amount cannot exceed remaining undelegation target");
+            .map_err(|_| liquidation_math_error("undelegation amount exceeds Uint128 range"))?;
 
         messages.push(CosmosMsg::Staking(StakingMsg::Undelegate {
             validator: delegation.validator.clone(),
             amount: Coin::new(coin_amount.u128(), state.collateral_denom.clone()),
         }));
 
-        remaining_to_undelegate -= amount;
-        total_undelegated += amount;
+        remaining_to_undelegate = remaining_to_undelegate
+            .checked_sub(amount)
+            .map_err(|_| liquidation_math_error("undelegation target underflow"))?;
+        total_undelegated = total_undelegated
+            .checked_add(amount)
+            .map_err(|_| liquidation_math_error("total undelegated overflow"))?;
     }
 
     let total_undelegated_u128 = Uint128::try_from(total_undelegated)
-        .expect("total undelegated amount cannot exceed remaining undelegation target");
+        .map_err(|_| liquidation_math_error("total undelegated exceeds Uint128 range"))?;
 
     Ok((messages, total_undelegated_u128))
 }
 
 pub(crate) fn liquidation_can_schedule_undelegations(deps: &Deps, env: &Env) -> StdResult<bool> {
-    let duration = LIQUIDATION_UNBONDING_DURATION
-        .may_load(deps.storage)?
-        .unwrap_or(DEFAULT_LIQUIDATION_UNBONDING_SECONDS);
+    let duration = load_config(deps.storage)?.liquidation_unbonding_duration;
     if duration == 0 {
         return Ok(true);
     }
@@ -411,11 +757,32 @@ pub(crate) fn record_liquidation_undelegation_time(deps: &mut DepsMut, env: &Env
     Ok(())
 }
 
+/// Tracks liquidation progress so the lender can see what was paid and what is still unbonding.
+pub(crate) fn record_liquidation_progress(
+    deps: &mut DepsMut,
+    payout_amount: Uint128,
+    undelegated_amount: Uint128,
+) -> StdResult<()> {
+    let paid = LIQUIDATION_PAID
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(Uint256::from(payout_amount))?;
+    LIQUIDATION_PAID.save(deps.storage, &paid)?;
+
+    if !undelegated_amount.is_zero() {
+        LIQUIDATION_UNBONDING_AMOUNT.save(deps.storage, &Uint256::from(undelegated_amount))?;
+    }
+    Ok(())
+}
+
 pub(crate) fn finalize_state(
     state: &LiquidationState,
     deps: &mut DepsMut,
+    env: &Env,
     remaining: Uint128,
 ) -> Result<(), ContractError> {
+    LAST_LIQUIDATION_HEIGHT.save(deps.storage, &env.block.height)?;
+
     if remaining.is_zero() {
         OUTSTANDING_DEBT.save(deps.storage, &None)?;
         OPEN_INTEREST.save(deps.storage, &None)?;
@@ -466,6 +833,8 @@ fn accumulate_repayment_requirement(
 mod tests {
     use super::*;
     use crate::contract::open_interest::test_helpers::{build_open_interest, sample_coin};
+    use crate::helpers::update_config;
+    use crate::state::DEFAULT_LIQUIDATION_UNBONDING_SECONDS;
     use crate::ContractError;
     use cosmwasm_std::{
         coins,
@@ -528,8 +897,8 @@ mod tests {
                 available,
                 requested,
             } if denom == "uatom"
-                && available == Uint128::from(150u128)
-                && requested == Uint128::from(200u128)
+                && available == Uint256::from(150u128)
+                && requested == Uint256::from(200u128)
         ));
     }
 
@@ -557,6 +926,37 @@ mod tests {
             .expect("collateral should cover");
     }
 
+    #[test]
+    fn slashing_haircut_discounts_staked_coverage() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(50, "ucosm"));
+        deps.querier.distribution.set_rewards(
+            "validator",
+            env.contract.address.as_str(),
+            vec![reward_coin(80, "ucosm")],
+        );
+        let delegation = staking_delegation(env.contract.address.clone(), 100);
+        deps.querier
+            .staking
+            .update("ucosm", &[stub_validator()], &[delegation]);
+
+        let open_interest = test_open_interest(sample_coin(226, "ucosm"));
+        validate_open_interest(&deps.as_ref(), &env, &open_interest)
+            .expect("full staked balance covers");
+
+        // With a 5% haircut the 100 staked only counts as 95.
+        update_config(deps.as_mut().storage, |config| {
+            config.slashing_haircut_bps = 500
+        })
+        .expect("haircut stored");
+        let err = validate_open_interest(&deps.as_ref(), &env, &open_interest).unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientBalance { .. }));
+    }
+
     #[test]
     fn rejects_staking_collateral_when_combined_balance_is_insufficient() {
         let mut deps = mock_dependencies();
@@ -587,8 +987,113 @@ mod tests {
                 available,
                 requested,
             } if denom == "ucosm"
-                && available == Uint128::from(170u128)
-                && requested == Uint128::from(200u128)
+                && available == Uint256::from(170u128)
+                && requested == Uint256::from(200u128)
+        ));
+    }
+
+    #[test]
+    fn rejects_denoms_outside_allowlist() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        update_config(deps.as_mut().storage, |config| {
+            config.allowed_denoms = Some(vec!["uusd".to_string(), "uatom".to_string()])
+        })
+        .expect("allowlist stored");
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+
+        let open_interest = test_open_interest(sample_coin(200, "uatom"));
+        let err = validate_open_interest(&deps.as_ref(), &env, &open_interest).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::DenomNotAllowed { denom } if denom == "ujuno"
+        ));
+
+        update_config(deps.as_mut().storage, |config| {
+            config.allowed_denoms = Some(vec![
+                "uusd".to_string(),
+                "ujuno".to_string(),
+                "uatom".to_string(),
+            ])
+        })
+        .expect("allowlist updated");
+        validate_open_interest(&deps.as_ref(), &env, &open_interest).expect("allowed denoms pass");
+    }
+
+    #[test]
+    fn enforces_configured_max_expiry_duration() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+
+        let mut open_interest = test_open_interest(sample_coin(200, "uatom"));
+        open_interest.expiry_duration = 90 * 86_400;
+        validate_open_interest(&deps.as_ref(), &env, &open_interest)
+            .expect("unbounded when no maximum is configured");
+
+        update_config(deps.as_mut().storage, |config| {
+            config.max_expiry_duration = Some(30 * 86_400)
+        })
+        .expect("maximum stored");
+        let err = validate_open_interest(&deps.as_ref(), &env, &open_interest).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ExpiryDurationTooLong { maximum } if maximum == 30 * 86_400
+        ));
+
+        open_interest.expiry_duration = 30 * 86_400;
+        validate_open_interest(&deps.as_ref(), &env, &open_interest)
+            .expect("duration at the maximum is accepted");
+    }
+
+    #[test]
+    fn enforces_configured_min_liquidity() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+
+        let mut open_interest = test_open_interest(sample_coin(200, "uatom"));
+        update_config(deps.as_mut().storage, |config| {
+            config.min_liquidity = Some(Uint256::from(150u128))
+        })
+        .expect("minimum stored");
+        let err = validate_open_interest(&deps.as_ref(), &env, &open_interest).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::LiquidityBelowMinimum { minimum } if minimum == Uint256::from(150u128)
+        ));
+
+        open_interest.liquidity_coin.amount = Uint256::from(150u128);
+        validate_open_interest(&deps.as_ref(), &env, &open_interest)
+            .expect("liquidity at the minimum is accepted");
+    }
+
+    #[test]
+    fn rejects_collateral_in_liquidity_denom() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+
+        let mut open_interest = test_open_interest(sample_coin(200, "uatom"));
+        open_interest.liquidity_coin.denom = "uatom".to_string();
+        let err = validate_open_interest(&deps.as_ref(), &env, &open_interest).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::CollateralLiquidityDenomConflict {}
         ));
     }
 
@@ -617,4 +1122,124 @@ mod tests {
             "undelegation permitted after delay"
         );
     }
+
+    #[test]
+    fn schedule_undelegations_follows_priority_list() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = env.contract.address.clone();
+        let validators = ["val-a", "val-b", "val-c"];
+        let delegations: Vec<FullDelegation> = validators
+            .iter()
+            .map(|validator| {
+                FullDelegation::create(
+                    contract.clone(),
+                    validator.to_string(),
+                    Coin::new(100u128, "ucosm"),
+                    Coin::new(100u128, "ucosm"),
+                    vec![],
+                )
+            })
+            .collect();
+        deps.querier.staking.update("ucosm", &[], &delegations);
+        update_config(deps.as_mut().storage, |config| {
+            config.undelegation_priority = vec!["val-c".to_string()]
+        })
+        .expect("priority stored");
+
+        let state = LiquidationState {
+            open_interest: test_open_interest(sample_coin(300, "ucosm")),
+            lender: Addr::unchecked("lender"),
+            payout_recipient: Addr::unchecked("lender"),
+            collateral_denom: "ucosm".to_string(),
+            contract_addr: contract,
+            bonded_denom: "ucosm".to_string(),
+            liquidation_bonus_bps: 0,
+        };
+        let (messages, total) =
+            schedule_undelegations(&state, &deps.as_ref(), Uint128::new(150)).expect("scheduled");
+
+        assert_eq!(total, Uint128::new(150));
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Staking(StakingMsg::Undelegate {
+                    validator: "val-c".to_string(),
+                    amount: Coin::new(100u128, "ucosm"),
+                }),
+                CosmosMsg::Staking(StakingMsg::Undelegate {
+                    validator: "val-a".to_string(),
+                    amount: Coin::new(50u128, "ucosm"),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn liquidity_funding_rejects_overflowing_sum() {
+        let info = MessageInfo {
+            sender: Addr::unchecked("lender"),
+            funds: vec![
+                Coin::new(Uint256::MAX, "uusd"),
+                Coin::new(Uint256::one(), "uusd"),
+            ],
+        };
+
+        let err = validate_liquidity_funding(&info, &sample_coin(100, "uusd")).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::EscrowSumOverflow { denom } if denom == "uusd"
+        ));
+    }
+
+    #[test]
+    fn validate_denom_rejects_non_canonical_forms() {
+        for denom in ["", " uatom", "uatom ", "UATOM", "uAtom"] {
+            assert!(matches!(
+                validate_denom(denom, "collateral"),
+                Err(ContractError::InvalidCoinDenom {
+                    field: "collateral"
+                })
+            ));
+        }
+
+        for denom in [
+            "uatom",
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        ] {
+            validate_denom(denom, "collateral").expect("canonical denom accepted");
+        }
+    }
+
+    #[test]
+    fn loan_history_evicts_oldest_entries_past_cap() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let lender = Addr::unchecked("lender");
+        let interest = test_open_interest(sample_coin(500, "ucosm"));
+
+        for _ in 0..MAX_LOAN_HISTORY + 2 {
+            record_loan_history(
+                deps.as_mut().storage,
+                &lender,
+                &interest,
+                LoanOutcome::Liquidated,
+                env.block.time,
+                Some(&lender),
+            )
+            .expect("history recorded");
+        }
+
+        let ids: Vec<u64> = LOAN_HISTORY
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .expect("ids load");
+        assert_eq!(ids.len() as u64, MAX_LOAN_HISTORY);
+        assert_eq!(ids[0], 2);
+        assert_eq!(
+            NEXT_LOAN_HISTORY_ID.load(deps.as_ref().storage).unwrap(),
+            MAX_LOAN_HISTORY + 2
+        );
+    }
 }
@@ -1,86 +1,309 @@
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    attr, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    StakingMsg, Uint128, Uint256,
+};
 
-use crate::ContractError;
+use crate::{
+    contract::{staking::undelegate::record_unbonding, withdraw::available_to_withdraw},
+    helpers::{balance_change_attributes, hook_submessage, load_config, require_owner_or_lender},
+    msg::HookMsg,
+    state::{
+        LAST_LIQUIDATION_HEIGHT, LAST_LIQUIDATOR, LAST_SETTLEMENT, LIQUIDATED_COLLATERAL_DENOM,
+        LIQUIDATION_PAID, LOAN_REFERENCE, LOAN_SETTLED, MAX_BPS, OWNER,
+    },
+    types::{LoanOutcome, SettlementRecord},
+    ContractError,
+};
 
 use super::helpers::{
-    collect_funds, finalize_state, get_outstanding_amount, liquidation_can_schedule_undelegations,
-    load_liquidation_state, open_interest_attributes, payout_message, push_nonzero_attr,
-    record_liquidation_undelegation_time, schedule_undelegations, CollectedFunds,
+    activate_queued_interest, collect_funds, finalize_state, get_outstanding_amount,
+    liquidation_can_schedule_undelegations, liquidation_math_error, load_liquidation_state,
+    open_interest_attributes, payout_message, push_nonzero_attr, record_liquidation_progress,
+    record_liquidation_undelegation_time, record_loan_history, schedule_undelegations,
+    CollectedFunds, LiquidationState,
 };
 
+/// Everything a liquidation would do, computed without touching state.
+struct LiquidationPlan {
+    remaining: Uint128,
+    available: Uint128,
+    rewards_claimed: Uint128,
+    payout_amount: Uint128,
+    liquidation_bonus: Uint128,
+    undelegated_amount: Uint128,
+    remaining_after_payout: Uint128,
+    /// Debt neither paid now nor covered by unbonding stake.
+    shortfall: Uint128,
+    messages: Vec<CosmosMsg>,
+}
+
 pub fn liquidate(
     mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     let state = load_liquidation_state(&deps, &env, &info)?;
-    let remaining = get_outstanding_amount(&state, &deps)?;
+    let plan = checked_plan(&deps, &env, &state, &info.sender)?;
+
+    if !plan.undelegated_amount.is_zero() {
+        record_liquidation_undelegation_time(&mut deps, &env)?;
+    }
+    for message in &plan.messages {
+        if let CosmosMsg::Staking(StakingMsg::Undelegate { validator, amount }) = message {
+            record_unbonding(deps.storage, &env, validator, amount.clone())?;
+        }
+    }
+    record_liquidation_progress(&mut deps, plan.payout_amount, plan.undelegated_amount)?;
+    LAST_LIQUIDATOR.save(deps.storage, &Some(info.sender.clone()))?;
+    if plan.remaining_after_payout.is_zero() {
+        // Read before `finalize_state` clears the running total.
+        let paid = LIQUIDATION_PAID.may_load(deps.storage)?.unwrap_or_default();
+        let settlement = SettlementRecord {
+            outcome: "liquidated".to_string(),
+            lender: state.lender.to_string(),
+            liquidator: Some(info.sender.to_string()),
+            repaid: vec![Coin::new(paid, state.collateral_denom.clone())],
+            settled_at: env.block.time,
+            reference: LOAN_REFERENCE.may_load(deps.storage)?.flatten(),
+        };
+        LAST_SETTLEMENT.save(deps.storage, &settlement)?;
+        LOAN_SETTLED.save(deps.storage, &true)?;
+        record_loan_history(
+            deps.storage,
+            &state.lender,
+            &state.open_interest,
+            LoanOutcome::Liquidated,
+            env.block.time,
+            Some(&info.sender),
+        )?;
+    }
+    finalize_state(&state, &mut deps, &env, plan.remaining_after_payout)?;
+    // Activation runs before the surplus is sized, so a newly opened interest keeps its collateral.
+    let activation_attrs = if plan.remaining_after_payout.is_zero() {
+        LIQUIDATED_COLLATERAL_DENOM.save(deps.storage, &state.collateral_denom)?;
+        let payout = Coin::new(plan.payout_amount, state.collateral_denom.clone());
+        activate_queued_interest(&mut deps, &env, &[payout])?
+    } else {
+        vec![]
+    };
+
+    let surplus = if plan.remaining_after_payout.is_zero() {
+        surplus_return(&deps.as_ref(), &env, &state, plan.payout_amount)?
+    } else {
+        None
+    };
+
+    let mut attrs = plan_attributes("liquidate_open_interest", &state, &info.sender, &plan);
+    attrs.extend(activation_attrs);
+    let mut messages = plan.messages;
+    if let Some(surplus) = surplus {
+        attrs.push(attr("surplus_returned", surplus.to_string()));
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: OWNER.load(deps.storage)?.into_string(),
+            amount: vec![surplus],
+        }));
+    }
+    attrs.extend(balance_change_attributes(
+        &deps.as_ref(),
+        &env,
+        &state.collateral_denom,
+        sent_amount(&messages, &state.collateral_denom),
+    )?);
+
+    let hook = hook_submessage(
+        deps.storage,
+        HookMsg::Liquidated {
+            lender: state.lender.to_string(),
+            open_interest: state.open_interest.clone(),
+            payout_amount: plan.payout_amount,
+            outstanding_debt: plan.remaining_after_payout,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_messages(messages)
+        .add_submessages(hook))
+}
+
+/// Total of `denom` that `messages` send out of the vault.
+fn sent_amount(messages: &[CosmosMsg], denom: &str) -> Uint256 {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount),
+            _ => None,
+        })
+        .flatten()
+        .filter(|coin| coin.denom == denom)
+        .fold(Uint256::zero(), |total, coin| total + coin.amount)
+}
+
+/// Liquid collateral left once the loan is fully settled and the payout has been deducted, when
+/// the vault is configured to hand it back to the owner. Without the flag it stays withdrawable.
+fn surplus_return(
+    deps: &Deps,
+    env: &Env,
+    state: &LiquidationState,
+    payout_amount: Uint128,
+) -> Result<Option<Coin>, ContractError> {
+    if !load_config(deps.storage)?.return_liquidation_surplus {
+        return Ok(None);
+    }
+
+    // The payout has not left the vault yet, so it is still part of the withdrawable balance.
+    let surplus = available_to_withdraw(deps, env, &state.collateral_denom)?
+        .saturating_sub(Uint256::from(payout_amount));
+    if surplus.is_zero() {
+        return Ok(None);
+    }
+
+    Ok(Some(Coin::new(surplus, state.collateral_denom.clone())))
+}
+
+/// Reports what `liquidate` would do right now without sending messages or changing state.
+pub fn dry_run_liquidate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    require_owner_or_lender(&deps, &info)?;
+    let state = load_liquidation_state(&deps, &env, &info)?;
+    let plan = checked_plan(&deps, &env, &state, &info.sender)?;
+
+    Ok(Response::new().add_attributes(plan_attributes(
+        "dry_run_liquidate",
+        &state,
+        &info.sender,
+        &plan,
+    )))
+}
+
+/// Plans the liquidation, rejecting it for the same reasons `liquidate` would.
+fn checked_plan(
+    deps: &DepsMut,
+    env: &Env,
+    state: &LiquidationState,
+    liquidator: &Addr,
+) -> Result<LiquidationPlan, ContractError> {
+    if LAST_LIQUIDATION_HEIGHT.may_load(deps.storage)? == Some(env.block.height) {
+        return Err(ContractError::LiquidationAlreadyProcessed {});
+    }
+    let remaining = get_outstanding_amount(state, deps)?;
+    let plan = plan_liquidation(state, &deps.as_ref(), env, liquidator, remaining)?;
+    if !plan.remaining.is_zero() && plan.shortfall == plan.remaining {
+        return Err(ContractError::LiquidationNoCollateral {});
+    }
+    Ok(plan)
+}
 
+fn plan_liquidation(
+    state: &LiquidationState,
+    deps: &Deps,
+    env: &Env,
+    liquidator: &Addr,
+    remaining: Uint128,
+) -> Result<LiquidationPlan, ContractError> {
     let mut messages = Vec::new();
     let CollectedFunds {
         available,
         rewards_claimed,
         reward_claim_messages,
-    } = collect_funds(&state, &deps.as_ref(), &env, remaining)?;
+    } = collect_funds(state, deps, env, remaining)?;
     messages.extend(reward_claim_messages);
     let payout_amount = available.min(remaining);
+    let liquidation_bonus = payout_amount.multiply_ratio(state.liquidation_bonus_bps, MAX_BPS);
+    let lender_payout = payout_amount
+        .checked_sub(liquidation_bonus)
+        .map_err(|_| liquidation_math_error("liquidation bonus exceeds payout"))?;
 
-    if !payout_amount.is_zero() {
-        messages.push(payout_message(&state, payout_amount)?);
+    if !lender_payout.is_zero() {
+        messages.push(payout_message(
+            state,
+            &state.payout_recipient,
+            lender_payout,
+        )?);
+    }
+    if !liquidation_bonus.is_zero() {
+        messages.push(payout_message(state, liquidator, liquidation_bonus)?);
     }
     let remaining_after_payout = remaining
         .checked_sub(payout_amount)
-        .expect("liquidation remaining underflow");
+        .map_err(|_| liquidation_math_error("payout exceeds remaining debt"))?;
 
     if !remaining_after_payout.is_zero() && state.collateral_denom != state.bonded_denom {
-        return Err(ContractError::InsufficientBalance {
+        return Err(ContractError::UnbondedCollateralShortfall {
             denom: state.collateral_denom.clone(),
-            available,
+            liquid: available,
             requested: remaining,
         });
     }
 
-    let mut undelegate_msgs = Vec::new();
     let mut undelegated_amount = Uint128::zero();
-    if liquidation_can_schedule_undelegations(&deps.as_ref(), &env)? {
-        let (msgs, amount) =
-            schedule_undelegations(&state, &deps.as_ref(), remaining_after_payout)?;
-        undelegate_msgs = msgs;
+    // While an earlier unbonding is still maturing, it is expected to cover the rest.
+    let mut shortfall = Uint128::zero();
+    if liquidation_can_schedule_undelegations(deps, env)? {
+        let (undelegate_msgs, amount) =
+            schedule_undelegations(state, deps, remaining_after_payout)?;
+        messages.extend(undelegate_msgs);
         undelegated_amount = amount;
-        if !undelegated_amount.is_zero() {
-            record_liquidation_undelegation_time(&mut deps, &env)?;
-        }
+        shortfall = remaining_after_payout.saturating_sub(undelegated_amount);
     }
-    messages.extend(undelegate_msgs);
 
-    finalize_state(&state, &mut deps, remaining_after_payout)?;
+    Ok(LiquidationPlan {
+        remaining,
+        available,
+        rewards_claimed,
+        payout_amount,
+        liquidation_bonus,
+        undelegated_amount,
+        remaining_after_payout,
+        shortfall,
+        messages,
+    })
+}
 
-    let mut attrs = open_interest_attributes("liquidate_open_interest", &state.open_interest);
+fn plan_attributes(
+    action: &'static str,
+    state: &LiquidationState,
+    liquidator: &Addr,
+    plan: &LiquidationPlan,
+) -> Vec<Attribute> {
+    let mut attrs = open_interest_attributes(action, &state.open_interest);
     attrs.push(attr("lender", state.lender.as_str()));
-    attrs.push(attr("liquidator", info.sender.as_str()));
-    push_nonzero_attr(&mut attrs, "requested_amount", remaining);
-    push_nonzero_attr(&mut attrs, "available_balance", available);
-    push_nonzero_attr(&mut attrs, "payout_amount", payout_amount);
-    push_nonzero_attr(&mut attrs, "rewards_claimed", rewards_claimed);
-    push_nonzero_attr(&mut attrs, "undelegated_amount", undelegated_amount);
-    push_nonzero_attr(&mut attrs, "outstanding_debt", remaining_after_payout);
-
-    let mut response = Response::new().add_attributes(attrs);
-    for msg in messages {
-        response = response.add_message(msg);
-    }
-
-    Ok(response)
+    attrs.push(attr("liquidator", liquidator.as_str()));
+    attrs.push(attr(
+        "collateral_is_bonded",
+        (state.collateral_denom == state.bonded_denom).to_string(),
+    ));
+    // The lender funded in the liquidity denom but liquidation always pays in collateral.
+    attrs.push(attr("payout_denom", state.collateral_denom.as_str()));
+    push_nonzero_attr(&mut attrs, "requested_amount", plan.remaining);
+    push_nonzero_attr(&mut attrs, "available_balance", plan.available);
+    push_nonzero_attr(&mut attrs, "payout_amount", plan.payout_amount);
+    push_nonzero_attr(&mut attrs, "liquidation_bonus", plan.liquidation_bonus);
+    push_nonzero_attr(&mut attrs, "rewards_claimed", plan.rewards_claimed);
+    push_nonzero_attr(&mut attrs, "undelegated_amount", plan.undelegated_amount);
+    push_nonzero_attr(&mut attrs, "outstanding_debt", plan.remaining_after_payout);
+    push_nonzero_attr(&mut attrs, "liquidation_shortfall", plan.shortfall);
+    attrs
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        contract::open_interest::test_helpers::{
-            build_open_interest, sample_coin, setup_active_open_interest,
+        contract::open_interest::{
+            repay_partial,
+            test_helpers::{build_open_interest, sample_coin, setup_active_open_interest},
+        },
+        helpers::update_config,
+        state::{
+            LENDER, LIQUIDATION_UNBONDING_AMOUNT, LOAN_HISTORY, OPEN_INTEREST,
+            OPEN_INTEREST_CREATED, OPEN_INTEREST_EXPIRY, OUTSTANDING_DEBT, QUEUED_INTEREST,
+            UNBONDING_ENTRIES,
         },
-        state::{LENDER, OPEN_INTEREST, OPEN_INTEREST_EXPIRY, OUTSTANDING_DEBT},
+        types::UnbondingEntry,
         ContractError,
     };
     use cosmwasm_std::{
@@ -134,6 +357,219 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dry_run_reports_plan_without_messages_or_state_changes() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("ucosm");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let env = mock_env();
+        let validator = deps.api.addr_make("validator").into_string();
+        deps.querier.staking.update(
+            "ucosm",
+            &[Validator::create(
+                validator.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator,
+                Coin::new(20u128, "ucosm"),
+                Coin::new(20u128, "ucosm"),
+                vec![],
+            )],
+        );
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(4, "ucosm"));
+
+        let snapshot = |storage: &dyn cosmwasm_std::Storage| {
+            storage
+                .range(None, None, cosmwasm_std::Order::Ascending)
+                .collect::<Vec<_>>()
+        };
+        let before = snapshot(deps.as_ref().storage);
+
+        let response =
+            dry_run_liquidate(deps.as_mut(), env, message_info(&lender, &[])).expect("dry run");
+
+        assert!(response.messages.is_empty());
+        assert_eq!(response.attributes[0], attr("action", "dry_run_liquidate"));
+        assert!(response.attributes.contains(&attr("payout_amount", "4")));
+        assert!(response
+            .attributes
+            .contains(&attr("undelegated_amount", "6")));
+        assert!(response.attributes.contains(&attr("outstanding_debt", "6")));
+        assert_eq!(snapshot(deps.as_ref().storage), before);
+    }
+
+    #[test]
+    fn dry_run_rejects_third_parties() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("uatom");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        update_config(deps.as_mut().storage, |config| {
+            config.liquidation_bonus_bps = 500
+        })
+        .expect("bonus stored");
+
+        let keeper = deps.api.addr_make("keeper");
+        let err =
+            dry_run_liquidate(deps.as_mut(), mock_env(), message_info(&keeper, &[])).unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn surplus_collateral_becomes_withdrawable_after_full_liquidation() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("uatom");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(25, "uatom"));
+
+        let response =
+            liquidate(deps.as_mut(), env.clone(), message_info(&lender, &[])).expect("liquidate");
+        assert_eq!(response.messages.len(), 1);
+
+        // The payout of 10 has left the vault; the remaining 15 belongs to the owner.
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(15, "uatom"));
+        let response = crate::contract::execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            crate::msg::ExecuteMsg::WithdrawAll {
+                denom: "uatom".to_string(),
+                recipient: None,
+            },
+        )
+        .expect("owner withdraws surplus");
+
+        assert!(response.attributes.contains(&attr("swept_amount", "15")));
+    }
+
+    #[test]
+    fn non_bonded_payout_is_capped_at_remaining_debt() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("uatom");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(25, "uatom"));
+
+        let response =
+            liquidate(deps.as_mut(), env, message_info(&lender, &[])).expect("liquidate");
+
+        assert_eq!(
+            bank_sends(&response),
+            vec![(lender.into_string(), coins(10, "uatom"))]
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("available_balance", "25")));
+        assert!(response.attributes.contains(&attr("payout_amount", "10")));
+        assert!(response.attributes.contains(&attr("payout_denom", "uatom")));
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "surplus_returned"));
+    }
+
+    #[test]
+    fn partial_repayment_reduces_collateral_seized() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = build_open_interest(
+            sample_coin(100, "uluna"),
+            sample_coin(10, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(40, "uluna"), sample_coin(200, "uatom")],
+        );
+        repay_partial(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            Uint128::new(40),
+        )
+        .expect("partial repayment succeeds");
+
+        let response =
+            liquidate(deps.as_mut(), env, message_info(&lender, &[])).expect("liquidate");
+
+        // 60% of the principal is still owed, so the lender takes 60% of the collateral.
+        assert_eq!(
+            bank_sends(&response),
+            vec![(lender.into_string(), coins(120, "uatom"))]
+        );
+        assert!(response.attributes.contains(&attr("payout_amount", "120")));
+    }
+
+    #[test]
+    fn surplus_collateral_is_returned_to_owner_when_configured() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("uatom");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        update_config(deps.as_mut().storage, |config| {
+            config.return_liquidation_surplus = true
+        })
+        .expect("flag stored");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(25, "uatom"));
+
+        let response =
+            liquidate(deps.as_mut(), env, message_info(&lender, &[])).expect("liquidate");
+
+        assert!(response
+            .attributes
+            .contains(&attr("surplus_returned", "15uatom")));
+        assert!(response.attributes.contains(&attr("balance_before", "25")));
+        assert!(response.attributes.contains(&attr("balance_after", "0")));
+        let messages: Vec<CosmosMsg> = response.messages.into_iter().map(|m| m.msg).collect();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: lender.into_string(),
+                    amount: coins(10, "uatom"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: owner.into_string(),
+                    amount: coins(15, "uatom"),
+                }),
+            ]
+        );
+    }
+
     #[test]
     fn liquidate_pays_lender_and_clears_state() {
         let mut deps = mock_dependencies();
@@ -182,6 +618,8 @@ mod tests {
         assert!(response
             .attributes
             .contains(&attr("payout_amount", amount.to_string())));
+        assert!(response.attributes.contains(&attr("balance_before", "25")));
+        assert!(response.attributes.contains(&attr("balance_after", "0")));
 
         assert_eq!(response.messages.len(), 1);
         match &response.messages[0].msg {
@@ -226,12 +664,12 @@ mod tests {
 
         assert!(matches!(
             err,
-            ContractError::InsufficientBalance {
+            ContractError::UnbondedCollateralShortfall {
                 denom,
-                available,
+                liquid,
                 requested,
             } if denom == collateral_denom
-                && available.is_zero()
+                && liquid.is_zero()
                 && requested == amount
         ));
     }
@@ -298,5 +736,392 @@ mod tests {
             .load(deps.as_ref().storage)
             .expect("lender still stored")
             .is_some());
+        assert_eq!(
+            LIQUIDATION_UNBONDING_AMOUNT
+                .load(deps.as_ref().storage)
+                .expect("unbonding amount recorded"),
+            Uint256::from(remaining_amount)
+        );
+        assert_eq!(
+            UNBONDING_ENTRIES
+                .load(deps.as_ref().storage)
+                .expect("unbonding entry recorded"),
+            vec![UnbondingEntry {
+                validator: validator_addr.to_string(),
+                amount: Coin::new(remaining_amount, collateral_denom),
+                completion_time: env
+                    .block
+                    .time
+                    .plus_seconds(crate::state::DEFAULT_LIQUIDATION_UNBONDING_SECONDS),
+            }]
+        );
+    }
+
+    fn bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.clone(), amount.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn third_party_earns_bonus_on_collateral_denom_payout() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let collateral_denom = "ujuno";
+        let open_interest = new_open_interest(collateral_denom);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        update_config(deps.as_mut().storage, |config| {
+            config.liquidation_bonus_bps = 250
+        })
+        .expect("bonus stored");
+        OUTSTANDING_DEBT
+            .save(
+                deps.as_mut().storage,
+                &Some(Coin::new(200u128, collateral_denom)),
+            )
+            .expect("debt stored");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, collateral_denom));
+
+        let liquidator = deps.api.addr_make("liquidator");
+        let response = liquidate(deps.as_mut(), env, message_info(&liquidator, &[]))
+            .expect("third-party liquidation succeeds");
+
+        assert_eq!(
+            bank_sends(&response),
+            vec![
+                (lender.to_string(), coins(195, collateral_denom)),
+                (liquidator.to_string(), coins(5, collateral_denom)),
+            ]
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("liquidation_bonus", "5")));
+        assert!(response
+            .attributes
+            .contains(&attr("collateral_is_bonded", "false")));
+        assert!(LENDER.load(deps.as_ref().storage).unwrap().is_none());
+        assert_eq!(
+            LAST_LIQUIDATOR.load(deps.as_ref().storage).unwrap(),
+            Some(liquidator.clone())
+        );
+        let settlement = LAST_SETTLEMENT
+            .load(deps.as_ref().storage)
+            .expect("settlement recorded");
+        assert_eq!(settlement.outcome, "liquidated");
+        assert_eq!(settlement.liquidator, Some(liquidator.to_string()));
+        assert_eq!(settlement.repaid, coins(200, collateral_denom));
+        let history = LOAN_HISTORY
+            .load(deps.as_ref().storage, 0)
+            .expect("history recorded");
+        assert_eq!(history.liquidator, Some(liquidator.into_string()));
+        assert_eq!(history.reference, None);
+    }
+
+    #[test]
+    fn third_party_earns_bonus_on_bonded_denom_payout() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let collateral_denom = "uatom";
+        let open_interest = new_open_interest(collateral_denom);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        update_config(deps.as_mut().storage, |config| {
+            config.liquidation_bonus_bps = 500
+        })
+        .expect("bonus stored");
+        OUTSTANDING_DEBT
+            .save(
+                deps.as_mut().storage,
+                &Some(Coin::new(100u128, collateral_denom)),
+            )
+            .expect("debt stored");
+
+        let env = mock_env();
+        deps.querier.staking.update(collateral_denom, &[], &[]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(100, collateral_denom));
+
+        let liquidator = deps.api.addr_make("liquidator");
+        let response = liquidate(deps.as_mut(), env, message_info(&liquidator, &[]))
+            .expect("third-party liquidation succeeds");
+
+        assert_eq!(
+            bank_sends(&response),
+            vec![
+                (lender.to_string(), coins(95, collateral_denom)),
+                (liquidator.to_string(), coins(5, collateral_denom)),
+            ]
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("liquidation_bonus", "5")));
+        assert!(response
+            .attributes
+            .contains(&attr("collateral_is_bonded", "true")));
+    }
+
+    #[test]
+    fn self_liquidation_pays_no_bonus() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let collateral_denom = "ujuno";
+        let open_interest = new_open_interest(collateral_denom);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        update_config(deps.as_mut().storage, |config| {
+            config.liquidation_bonus_bps = 500
+        })
+        .expect("bonus stored");
+        OUTSTANDING_DEBT
+            .save(
+                deps.as_mut().storage,
+                &Some(Coin::new(100u128, collateral_denom)),
+            )
+            .expect("debt stored");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(100, collateral_denom));
+
+        let response = liquidate(deps.as_mut(), env, message_info(&lender, &[]))
+            .expect("lender liquidation succeeds");
+
+        assert_eq!(
+            bank_sends(&response),
+            vec![(lender.to_string(), coins(100, collateral_denom))]
+        );
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "liquidation_bonus"));
+    }
+
+    #[test]
+    fn full_liquidation_activates_queued_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("uatom");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let next = build_open_interest(
+            sample_coin(7, "uluna"),
+            sample_coin(3, "uinterest"),
+            43_200,
+            sample_coin(10, "uatom"),
+        );
+        QUEUED_INTEREST
+            .save(deps.as_mut().storage, &Some(next.clone()))
+            .expect("queue stored");
+
+        let env = mock_env();
+        // The payout of 10 leaves the vault, so the queued terms need another 10 behind them.
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(20, "uatom"));
+
+        let response =
+            liquidate(deps.as_mut(), env.clone(), message_info(&owner, &[])).expect("liquidate");
+
+        assert!(response
+            .attributes
+            .contains(&attr("queued_interest_activated", "true")));
+        assert_eq!(
+            OPEN_INTEREST.load(deps.as_ref().storage).unwrap(),
+            Some(crate::types::OpenInterest {
+                interest_nonce: 1,
+                ..next
+            })
+        );
+        assert_eq!(
+            OPEN_INTEREST_CREATED.load(deps.as_ref().storage).unwrap(),
+            Some(env.block.time)
+        );
+        assert!(LENDER.load(deps.as_ref().storage).unwrap().is_none());
+        assert!(QUEUED_INTEREST
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn queued_interest_stays_queued_when_payout_drains_collateral() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = new_open_interest("uatom");
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let next = build_open_interest(
+            sample_coin(7, "uluna"),
+            sample_coin(3, "uinterest"),
+            43_200,
+            sample_coin(10, "uatom"),
+        );
+        QUEUED_INTEREST
+            .save(deps.as_mut().storage, &Some(next.clone()))
+            .expect("queue stored");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(10, "uatom"));
+
+        let response = liquidate(deps.as_mut(), env, message_info(&owner, &[])).expect("liquidate");
+
+        assert!(response.attributes.contains(&attr(
+            "queued_interest_activation_failed",
+            "insufficient_balance"
+        )));
+        assert_eq!(OPEN_INTEREST.load(deps.as_ref().storage).unwrap(), None);
+        assert_eq!(
+            QUEUED_INTEREST.load(deps.as_ref().storage).unwrap(),
+            Some(next)
+        );
+    }
+
+    #[test]
+    fn rejects_second_liquidation_in_same_block() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let collateral_denom = "uatom";
+        let open_interest = new_open_interest(collateral_denom);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        OUTSTANDING_DEBT
+            .save(
+                deps.as_mut().storage,
+                &Some(Coin::new(200u128, collateral_denom)),
+            )
+            .expect("debt stored");
+
+        let mut env = mock_env();
+        let validator = deps.api.addr_make("validator").into_string();
+        deps.querier.staking.update(
+            collateral_denom,
+            &[Validator::create(
+                validator.clone(),
+                Decimal::zero(),
+                Decimal::zero(),
+                Decimal::zero(),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator,
+                Coin::new(150u128, collateral_denom),
+                Coin::new(150u128, collateral_denom),
+                vec![],
+            )],
+        );
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(50, collateral_denom));
+
+        liquidate(deps.as_mut(), env.clone(), message_info(&lender, &[]))
+            .expect("partial liquidation succeeds");
+        assert_eq!(
+            LAST_LIQUIDATION_HEIGHT.load(deps.as_ref().storage).unwrap(),
+            env.block.height
+        );
+
+        let err = liquidate(deps.as_mut(), env.clone(), message_info(&lender, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::LiquidationAlreadyProcessed {}));
+
+        env.block.height += 1;
+        liquidate(deps.as_mut(), env, message_info(&lender, &[]))
+            .expect("next block may liquidate again");
+    }
+
+    #[test]
+    fn reports_shortfall_and_rejects_when_nothing_covers_debt() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let collateral_denom = "uatom";
+        let open_interest = new_open_interest(collateral_denom);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        OUTSTANDING_DEBT
+            .save(
+                deps.as_mut().storage,
+                &Some(Coin::new(200u128, collateral_denom)),
+            )
+            .expect("debt stored");
+
+        let env = mock_env();
+        deps.querier.staking.update(collateral_denom, &[], &[]);
+        let err = liquidate(deps.as_mut(), env.clone(), message_info(&lender, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::LiquidationNoCollateral {}));
+
+        let validator = deps.api.addr_make("validator").into_string();
+        deps.querier.staking.update(
+            collateral_denom,
+            &[Validator::create(
+                validator.clone(),
+                Decimal::zero(),
+                Decimal::zero(),
+                Decimal::zero(),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator,
+                Coin::new(50u128, collateral_denom),
+                Coin::new(50u128, collateral_denom),
+                vec![],
+            )],
+        );
+
+        let response = liquidate(deps.as_mut(), env, message_info(&lender, &[]))
+            .expect("partial coverage still liquidates");
+        assert!(response
+            .attributes
+            .contains(&attr("undelegated_amount", "50")));
+        assert!(response
+            .attributes
+            .contains(&attr("liquidation_shortfall", "150")));
+    }
+
+    #[test]
+    fn dry_run_applies_liquidation_checks() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let collateral_denom = "uatom";
+        let open_interest = new_open_interest(collateral_denom);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+        OUTSTANDING_DEBT
+            .save(
+                deps.as_mut().storage,
+                &Some(Coin::new(200u128, collateral_denom)),
+            )
+            .expect("debt stored");
+
+        let env = mock_env();
+        deps.querier.staking.update(collateral_denom, &[], &[]);
+        let err =
+            dry_run_liquidate(deps.as_mut(), env.clone(), message_info(&lender, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::LiquidationNoCollateral {}));
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(50, collateral_denom));
+        LAST_LIQUIDATION_HEIGHT
+            .save(deps.as_mut().storage, &env.block.height)
+            .expect("height stored");
+        let err = dry_run_liquidate(deps.as_mut(), env, message_info(&lender, &[])).unwrap_err();
+        assert!(matches!(err, ContractError::LiquidationAlreadyProcessed {}));
     }
 }
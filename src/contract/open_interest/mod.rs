@@ -1,16 +1,28 @@
+mod add_collateral;
 mod close;
 mod execute;
 mod fund;
 mod helpers;
 mod liquidate;
+mod payout;
 mod repay;
+mod repay_partial;
+mod transfer_position;
 
 #[cfg(test)]
 pub mod test_helpers;
 
-pub use close::close;
+pub use add_collateral::add_collateral;
+pub use close::{close, close_to_unclaimed};
 pub use execute::execute;
 pub use fund::fund;
+pub(crate) use helpers::{
+    assign_interest_nonce, build_repayment_amounts, late_fee, lender_payout_address, repayment_due,
+    store_loan_reference, validate_denom, validate_loan_reference, validate_open_interest,
+};
 pub use helpers::{clear_active_lender, set_active_lender};
-pub use liquidate::liquidate;
+pub use liquidate::{dry_run_liquidate, liquidate};
+pub use payout::set_payout_address;
 pub use repay::repay;
+pub use repay_partial::repay_partial;
+pub use transfer_position::transfer_lender_position;
@@ -0,0 +1,123 @@
+use cosmwasm_std::{attr, DepsMut, MessageInfo, Response};
+
+use crate::{
+    state::{LENDER, LENDER_PAYOUT},
+    ContractError,
+};
+
+pub fn set_payout_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let lender = LENDER
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::NoLender {})?;
+
+    if info.sender != lender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let payout_address = deps.api.addr_validate(&address)?;
+    LENDER_PAYOUT.save(deps.storage, &Some(payout_address.clone()))?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "set_payout_address"),
+        attr("lender", lender.as_str()),
+        attr("payout_address", payout_address.as_str()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::open_interest::repay;
+    use crate::contract::open_interest::test_helpers::{
+        build_open_interest, sample_coin, setup, setup_active_open_interest,
+    };
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{BankMsg, CosmosMsg};
+
+    #[test]
+    fn rejects_without_lender() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let cold = deps.api.addr_make("cold").into_string();
+        let err = set_payout_address(deps.as_mut(), message_info(&owner, &[]), cold).unwrap_err();
+
+        assert!(matches!(err, ContractError::NoLender {}));
+    }
+
+    #[test]
+    fn only_lender_can_set_payout_address() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let cold = deps.api.addr_make("cold").into_string();
+        let err = set_payout_address(deps.as_mut(), message_info(&owner, &[]), cold).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = set_payout_address(
+            deps.as_mut(),
+            message_info(&lender, &[]),
+            "not-an-address".to_string(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Std(_)));
+    }
+
+    #[test]
+    fn repay_pays_payout_address_and_clears_it() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let cold = deps.api.addr_make("cold");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(15, "uinterest")],
+        );
+
+        set_payout_address(deps.as_mut(), message_info(&lender, &[]), cold.to_string())
+            .expect("lender sets payout address");
+
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        match &response.messages[0].msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(to_address, cold.as_str());
+                assert_eq!(
+                    amount,
+                    &vec![sample_coin(15, "uinterest"), sample_coin(100, "uusd")]
+                );
+            }
+            msg => panic!("unexpected message: {msg:?}"),
+        }
+        assert!(response
+            .attributes
+            .contains(&attr("payout_address", cold.as_str())));
+        assert!(LENDER_PAYOUT
+            .may_load(deps.as_ref().storage)
+            .expect("payout loads")
+            .is_none());
+    }
+}
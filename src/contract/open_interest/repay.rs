@@ -1,16 +1,36 @@
-use cosmwasm_std::{attr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Uint128};
-use std::convert::TryFrom;
+use cosmwasm_std::{
+    attr, Addr, BankMsg, Coin, DepsMut, DistributionMsg, Env, MessageInfo, Response, StdError,
+    Uint256,
+};
 
 use crate::{
-    helpers::require_owner,
-    state::{LENDER, OPEN_INTEREST, OUTSTANDING_DEBT},
+    helpers::{balance_change_attributes, hook_submessage, query_staking_rewards, require_owner},
+    msg::HookMsg,
+    state::{
+        LAST_SETTLEMENT, LENDER, LOAN_REFERENCE, LOAN_SETTLED, OPEN_INTEREST, OUTSTANDING_DEBT,
+    },
+    types::{LoanOutcome, OpenInterest, SettlementRecord},
     ContractError,
 };
 
-use super::helpers::{build_repayment_amounts, clear_active_lender, open_interest_attributes};
+use super::helpers::{
+    activate_queued_interest, build_repayment_amounts, clear_active_lender, late_fee,
+    lender_payout_address, open_interest_attributes, protocol_fee, record_loan_history,
+    remaining_principal,
+};
 
 pub fn repay(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    require_owner(&deps, &info)?;
+    let (open_interest, lender) = load_active_loan(&deps, &info)?;
+    let principal = remaining_principal(deps.storage, &open_interest)?;
+
+    settle(deps, env, open_interest, lender, principal)
+}
+
+pub(super) fn load_active_loan(
+    deps: &DepsMut,
+    info: &MessageInfo,
+) -> Result<(OpenInterest, Addr), ContractError> {
+    require_owner(deps, info)?;
 
     if let Some(debt) = OUTSTANDING_DEBT.load(deps.storage)? {
         return Err(ContractError::OutstandingDebt { amount: debt });
@@ -24,40 +44,153 @@ pub fn repay(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Con
         .load(deps.storage)?
         .ok_or(ContractError::NoLender {})?;
 
-    let repayment_amounts = build_repayment_amounts(&open_interest)?;
+    Ok((open_interest, lender))
+}
+
+/// Pays the remaining principal plus the interest, less any protocol fee, to the lender and clears
+/// the loan.
+pub(super) fn settle(
+    mut deps: DepsMut,
+    env: Env,
+    open_interest: OpenInterest,
+    lender: Addr,
+    principal: Coin,
+) -> Result<Response, ContractError> {
+    let late_fee = late_fee(deps.storage, &open_interest, env.block.time)?;
+    let mut outstanding = OpenInterest {
+        liquidity_coin: principal,
+        ..open_interest.clone()
+    };
+    outstanding.interest_coin.amount += late_fee;
+    let repayment_amounts = build_repayment_amounts(&outstanding)?;
     let contract_addr = env.contract.address.clone();
 
     let mut repayment_coins = Vec::with_capacity(repayment_amounts.len());
+    let mut reward_claim_messages = Vec::new();
+    let mut rewards_claimed = Uint256::zero();
     for (denom, requested_amount, coin_amount) in repayment_amounts {
         let balance = deps
             .querier
             .query_balance(contract_addr.clone(), denom.clone())?;
-        let available_amount = balance.amount;
+        let mut available_amount = balance.amount;
+
+        // Rewards pay out in the bonded denom, so claiming them first can cover a shortfall.
+        if available_amount < requested_amount
+            && rewards_claimed.is_zero()
+            && denom == deps.querier.query_bonded_denom()?
+        {
+            let rewards = query_staking_rewards(&deps.as_ref(), &env)?;
+            if !rewards.is_zero() {
+                reward_claim_messages = deps
+                    .querier
+                    .query_all_delegations(contract_addr.clone())?
+                    .into_iter()
+                    .map(|delegation| DistributionMsg::WithdrawDelegatorReward {
+                        validator: delegation.validator,
+                    })
+                    .collect();
+                rewards_claimed = rewards;
+                available_amount = available_amount
+                    .checked_add(rewards)
+                    .map_err(StdError::from)?;
+            }
+        }
 
         if available_amount < requested_amount {
             return Err(ContractError::InsufficientBalance {
                 denom: denom.clone(),
-                available: Uint128::try_from(available_amount).expect("balance fits in u128"),
-                requested: Uint128::try_from(requested_amount).expect("request fits in u128"),
+                available: available_amount,
+                requested: requested_amount,
             });
         }
 
         repayment_coins.push(Coin::new(coin_amount, denom));
     }
 
+    let recipient = lender_payout_address(deps.storage, &lender)?;
+    let settlement = SettlementRecord {
+        outcome: "repaid".to_string(),
+        lender: lender.to_string(),
+        liquidator: None,
+        repaid: repayment_coins.clone(),
+        settled_at: env.block.time,
+        reference: LOAN_REFERENCE.may_load(deps.storage)?.flatten(),
+    };
+    LAST_SETTLEMENT.save(deps.storage, &settlement)?;
+    LOAN_SETTLED.save(deps.storage, &true)?;
+    record_loan_history(
+        deps.storage,
+        &lender,
+        &open_interest,
+        LoanOutcome::Repaid,
+        env.block.time,
+        None,
+    )?;
     OPEN_INTEREST.save(deps.storage, &None)?;
     clear_active_lender(deps.storage)?;
+    let activation_attrs = activate_queued_interest(&mut deps, &env, &repayment_coins)?;
     let mut attrs = open_interest_attributes("repay_open_interest", &open_interest);
     attrs.push(attr("lender", lender.as_str()));
+    if !late_fee.is_zero() {
+        let fee = Coin::new(late_fee, open_interest.interest_coin.denom.clone());
+        attrs.push(attr("late_fee", fee.to_string()));
+    }
+    if recipient != lender {
+        attrs.push(attr("payout_address", recipient.as_str()));
+    }
+    attrs.extend(activation_attrs);
+    if !rewards_claimed.is_zero() {
+        attrs.push(attr("rewards_claimed", rewards_claimed.to_string()));
+    }
+    let liquidity_denom = &open_interest.liquidity_coin.denom;
+    let outgoing = repayment_coins
+        .iter()
+        .filter(|coin| coin.denom == *liquidity_denom)
+        .fold(Uint256::zero(), |acc, coin| acc + coin.amount);
+    attrs.extend(balance_change_attributes(
+        &deps.as_ref(),
+        &env,
+        liquidity_denom,
+        outgoing,
+    )?);
+
+    // The fee only ever comes out of interest, so the principal always reaches the lender in full.
+    let fee = protocol_fee(deps.storage, &outstanding.interest_coin)?;
+    if let Some((fee_recipient, fee_coin)) = &fee {
+        if let Some(coin) = repayment_coins
+            .iter_mut()
+            .find(|coin| coin.denom == fee_coin.denom)
+        {
+            coin.amount -= fee_coin.amount;
+        }
+        attrs.push(attr("protocol_fee", fee_coin.to_string()));
+        attrs.push(attr("fee_recipient", fee_recipient.as_str()));
+    }
+
+    let hook = hook_submessage(
+        deps.storage,
+        HookMsg::Repaid {
+            lender: lender.to_string(),
+            open_interest,
+        },
+    )?;
 
-    let response = Response::new()
+    // Messages run in order, so the claimed rewards land before the payout is sent.
+    let mut response = Response::new()
         .add_attributes(attrs)
+        .add_messages(reward_claim_messages)
         .add_message(BankMsg::Send {
-            to_address: lender.to_string(),
+            to_address: recipient.into_string(),
             amount: repayment_coins,
         });
+    if let Some((fee_recipient, fee_coin)) = fee {
+        response = response.add_message(BankMsg::Send {
+            to_address: fee_recipient.into_string(),
+            amount: vec![fee_coin],
+        });
+    }
 
-    Ok(response)
+    Ok(response.add_submessages(hook))
 }
 
 #[cfg(test)]
@@ -67,12 +200,16 @@ mod tests {
         contract::open_interest::test_helpers::{
             build_open_interest, sample_coin, setup, setup_active_open_interest,
         },
-        state::{LENDER, OPEN_INTEREST, OUTSTANDING_DEBT},
+        helpers::update_config,
+        state::{
+            LENDER, LOAN_HISTORY, OPEN_INTEREST, OPEN_INTEREST_FUNDED, OUTSTANDING_DEBT,
+            QUEUED_INTEREST,
+        },
         ContractError,
     };
     use cosmwasm_std::{
         testing::{message_info, mock_dependencies, mock_env},
-        BankMsg,
+        BankMsg, CosmosMsg, DecCoin, Decimal, Decimal256, FullDelegation, Validator,
     };
     use std::collections::BTreeMap;
 
@@ -224,6 +361,9 @@ mod tests {
             sample_coin(200, "uatom"),
         );
         setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+        LOAN_REFERENCE
+            .save(deps.as_mut().storage, &Some("invoice-7".to_string()))
+            .expect("reference stored");
 
         let env = mock_env();
         deps.querier.bank.update_balance(
@@ -245,6 +385,9 @@ mod tests {
             .attributes
             .iter()
             .any(|attr| attr.key == "lender" && attr.value == lender.to_string()));
+        assert!(response.attributes.contains(&attr("balance_denom", "uusd")));
+        assert!(response.attributes.contains(&attr("balance_before", "100")));
+        assert!(response.attributes.contains(&attr("balance_after", "0")));
 
         let send_msg = match &response.messages[0].msg {
             cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
@@ -283,5 +426,391 @@ mod tests {
             .load(deps.as_ref().storage)
             .expect("debt fetched")
             .is_none());
+
+        let settlement = LAST_SETTLEMENT
+            .load(deps.as_ref().storage)
+            .expect("settlement recorded");
+        assert_eq!(settlement.outcome, "repaid");
+        assert_eq!(settlement.lender, lender.to_string());
+        assert_eq!(settlement.liquidator, None);
+        assert_eq!(settlement.settled_at, env.block.time);
+        assert!(LOAN_SETTLED.load(deps.as_ref().storage).unwrap());
+
+        let history = LOAN_HISTORY
+            .load(deps.as_ref().storage, 0)
+            .expect("history recorded");
+        assert_eq!(history.outcome, LoanOutcome::Repaid);
+        assert_eq!(history.liquidity, interest.liquidity_coin);
+        assert_eq!(history.collateral, interest.collateral);
+        assert_eq!(history.liquidator, None);
+        assert_eq!(history.reference, Some("invoice-7".to_string()));
+    }
+
+    #[test]
+    fn repay_activates_queued_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let next = build_open_interest(
+            sample_coin(150, "uusd"),
+            sample_coin(20, "uinterest"),
+            172_800,
+            sample_coin(200, "uatom"),
+        );
+        QUEUED_INTEREST
+            .save(deps.as_mut().storage, &Some(next.clone()))
+            .expect("queue stored");
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![
+                interest.liquidity_coin.clone(),
+                interest.interest_coin.clone(),
+                interest.collateral.clone(),
+            ],
+        );
+
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert!(response
+            .attributes
+            .contains(&attr("queued_interest_activated", "true")));
+        assert!(response
+            .attributes
+            .contains(&attr("open_interest", "150uusd")));
+        assert_eq!(
+            OPEN_INTEREST.load(deps.as_ref().storage).unwrap(),
+            Some(OpenInterest {
+                interest_nonce: 1,
+                ..next
+            })
+        );
+        assert!(LENDER.load(deps.as_ref().storage).unwrap().is_none());
+        assert!(QUEUED_INTEREST
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn repay_moves_queued_interest_reference_into_loan_reference() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let mut next = build_open_interest(
+            sample_coin(150, "uusd"),
+            sample_coin(20, "uinterest"),
+            172_800,
+            sample_coin(200, "uatom"),
+        );
+        next.reference = Some("invoice-8".to_string());
+        QUEUED_INTEREST
+            .save(deps.as_mut().storage, &Some(next))
+            .expect("queue stored");
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![
+                interest.liquidity_coin.clone(),
+                interest.interest_coin.clone(),
+                interest.collateral.clone(),
+            ],
+        );
+
+        repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert_eq!(
+            LOAN_REFERENCE.load(deps.as_ref().storage).unwrap(),
+            Some("invoice-8".to_string())
+        );
+        let opened = OPEN_INTEREST.load(deps.as_ref().storage).unwrap().unwrap();
+        assert_eq!(opened.reference, None);
+    }
+
+    #[test]
+    fn repay_keeps_queue_when_collateral_no_longer_covers_it() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let next = build_open_interest(
+            sample_coin(150, "uusd"),
+            sample_coin(20, "uinterest"),
+            172_800,
+            sample_coin(500, "uatom"),
+        );
+        QUEUED_INTEREST
+            .save(deps.as_mut().storage, &Some(next.clone()))
+            .expect("queue stored");
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![
+                interest.liquidity_coin.clone(),
+                interest.interest_coin.clone(),
+                interest.collateral.clone(),
+            ],
+        );
+
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert!(response.attributes.contains(&attr(
+            "queued_interest_activation_failed",
+            "insufficient_balance"
+        )));
+        assert!(OPEN_INTEREST.load(deps.as_ref().storage).unwrap().is_none());
+        assert_eq!(
+            QUEUED_INTEREST.load(deps.as_ref().storage).unwrap(),
+            Some(next)
+        );
+    }
+
+    #[test]
+    fn repay_after_deadline_adds_late_fee() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let mut interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(20, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        interest.repayment_deadline_duration = Some(3_600);
+        interest.late_fee_bps = Some(2_500);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let mut env = mock_env();
+        OPEN_INTEREST_FUNDED
+            .save(deps.as_mut().storage, &env.block.time)
+            .expect("funding time stored");
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(24, "uinterest")],
+        );
+
+        env.block.time = env.block.time.plus_seconds(3_601);
+        let err = repay(deps.as_mut(), env.clone(), message_info(&owner, &[])).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientBalance { requested, .. }
+                if requested == Uint256::new(25)
+        ));
+
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(25, "uinterest")],
+        );
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert!(response
+            .attributes
+            .contains(&attr("late_fee", "5uinterest")));
+        assert_eq!(
+            response.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
+                to_address: lender.to_string(),
+                amount: vec![sample_coin(25, "uinterest"), sample_coin(100, "uusd")],
+            })
+        );
+        assert!(OPEN_INTEREST_FUNDED
+            .may_load(deps.as_ref().storage)
+            .expect("funding time loads")
+            .is_none());
+    }
+
+    #[test]
+    fn repay_before_deadline_owes_no_late_fee() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let mut interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(20, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        interest.repayment_deadline_duration = Some(3_600);
+        interest.late_fee_bps = Some(2_500);
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let mut env = mock_env();
+        OPEN_INTEREST_FUNDED
+            .save(deps.as_mut().storage, &env.block.time)
+            .expect("funding time stored");
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(20, "uinterest")],
+        );
+
+        env.block.time = env.block.time.plus_seconds(3_600);
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "late_fee"));
+    }
+
+    #[test]
+    fn repay_carves_protocol_fee_from_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let treasury = deps.api.addr_make("treasury");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+        update_config(deps.as_mut().storage, |config| config.fee_bps = 2_000).expect("fee stored");
+        update_config(deps.as_mut().storage, |config| {
+            config.fee_recipient = Some(treasury.clone())
+        })
+        .expect("recipient stored");
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(15, "uinterest")],
+        );
+
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        let messages: Vec<_> = response.messages.iter().map(|m| m.msg.clone()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: lender.to_string(),
+                    amount: vec![sample_coin(12, "uinterest"), sample_coin(100, "uusd")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: treasury.to_string(),
+                    amount: vec![sample_coin(3, "uinterest")],
+                }),
+            ]
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("protocol_fee", "3uinterest")));
+        assert!(response
+            .attributes
+            .contains(&attr("fee_recipient", treasury.as_str())));
+    }
+
+    #[test]
+    fn repay_skips_protocol_fee_without_recipient() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uusd"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+        update_config(deps.as_mut().storage, |config| config.fee_bps = 2_000).expect("fee stored");
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(115, "uusd")],
+        );
+
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert_eq!(response.messages.len(), 1);
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "protocol_fee"));
+    }
+
+    #[test]
+    fn repay_claims_rewards_to_cover_bonded_liquidity() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "ucosm"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(80, "ucosm"), sample_coin(15, "uinterest")],
+        );
+        let validator = Validator::create(
+            "validator".to_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        );
+        let delegation = FullDelegation::create(
+            env.contract.address.clone(),
+            "validator".to_string(),
+            sample_coin(50, "ucosm"),
+            sample_coin(50, "ucosm"),
+            vec![],
+        );
+        deps.querier
+            .staking
+            .update("ucosm", &[validator], &[delegation]);
+        deps.querier.distribution.set_rewards(
+            "validator",
+            env.contract.address.as_str(),
+            vec![DecCoin::new(
+                Decimal256::from_atomics(30u128, 0).unwrap(),
+                "ucosm",
+            )],
+        );
+
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                validator: "validator".to_string(),
+            })
+        );
+        assert!(matches!(
+            &response.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send { to_address, .. }) if to_address == lender.as_str()
+        ));
+        assert!(response.attributes.contains(&attr("rewards_claimed", "30")));
     }
 }
@@ -0,0 +1,225 @@
+use cosmwasm_std::{
+    attr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, StdError, Uint128, Uint256,
+};
+
+use crate::{state::REPAID_PRINCIPAL, ContractError};
+
+use super::helpers::{lender_payout_address, open_interest_attributes, remaining_principal};
+use super::repay::{load_active_loan, settle};
+
+pub fn repay_partial(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let (open_interest, lender) = load_active_loan(&deps, &info)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::InvalidCoinAmount { field: "amount" });
+    }
+
+    let principal = remaining_principal(deps.storage, &open_interest)?;
+    let requested = Uint256::from(amount);
+    if requested > principal.amount {
+        return Err(ContractError::RepaymentExceedsDebt {
+            denom: principal.denom,
+            remaining: principal.amount,
+        });
+    }
+
+    // Interest is only owed once the principal is fully repaid.
+    if requested == principal.amount {
+        return settle(deps, env, open_interest, lender, principal);
+    }
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), principal.denom.clone())?;
+    if balance.amount < requested {
+        return Err(ContractError::InsufficientBalance {
+            denom: principal.denom,
+            available: balance.amount,
+            requested,
+        });
+    }
+
+    let repaid = REPAID_PRINCIPAL
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(requested)
+        .map_err(StdError::from)?;
+    REPAID_PRINCIPAL.save(deps.storage, &repaid)?;
+
+    let recipient = lender_payout_address(deps.storage, &lender)?;
+    let remaining = principal.amount - requested;
+    let mut attrs = open_interest_attributes("repay_partial", &open_interest);
+    attrs.push(attr("lender", lender.as_str()));
+    if recipient != lender {
+        attrs.push(attr("payout_address", recipient.as_str()));
+    }
+    attrs.push(attr("amount", amount.to_string()));
+    attrs.push(attr("remaining_principal", remaining.to_string()));
+
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_message(BankMsg::Send {
+            to_address: recipient.into_string(),
+            amount: vec![Coin::new(amount, principal.denom)],
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        contract::open_interest::test_helpers::{
+            build_open_interest, sample_coin, setup_active_open_interest,
+        },
+        state::{LENDER, OPEN_INTEREST},
+    };
+    use cosmwasm_std::{
+        testing::{message_info, mock_dependencies, mock_env},
+        CosmosMsg,
+    };
+
+    fn sent_coins(response: &Response) -> (String, Vec<Coin>) {
+        match &response.messages[0].msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                (to_address.clone(), amount.clone())
+            }
+            msg => panic!("unexpected message: {msg:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_amount_exceeding_remaining_debt() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+        REPAID_PRINCIPAL
+            .save(deps.as_mut().storage, &Uint256::from(60u128))
+            .expect("progress stored");
+
+        let err = repay_partial(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            Uint128::new(41),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::RepaymentExceedsDebt { remaining, .. }
+                if remaining == Uint256::from(40u128)
+        ));
+    }
+
+    #[test]
+    fn rejects_zero_amount() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let err = repay_partial(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            Uint128::zero(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InvalidCoinAmount { field: "amount" }
+        ));
+    }
+
+    #[test]
+    fn partial_repayments_forward_principal_then_settle() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(15, "uinterest")],
+        );
+
+        let response = repay_partial(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            Uint128::new(30),
+        )
+        .expect("partial repayment succeeds");
+
+        assert_eq!(
+            sent_coins(&response),
+            (lender.to_string(), vec![sample_coin(30, "uusd")])
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("remaining_principal", "70")));
+        assert_eq!(
+            REPAID_PRINCIPAL.load(deps.as_ref().storage).unwrap(),
+            Uint256::from(30u128)
+        );
+
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(70, "uusd"), sample_coin(15, "uinterest")],
+        );
+
+        let response = repay_partial(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            Uint128::new(70),
+        )
+        .expect("final repayment settles");
+
+        assert_eq!(
+            sent_coins(&response),
+            (
+                lender.to_string(),
+                vec![sample_coin(15, "uinterest"), sample_coin(70, "uusd")]
+            )
+        );
+        assert!(OPEN_INTEREST
+            .load(deps.as_ref().storage)
+            .expect("interest fetched")
+            .is_none());
+        assert!(LENDER
+            .load(deps.as_ref().storage)
+            .expect("lender fetched")
+            .is_none());
+        assert!(REPAID_PRINCIPAL
+            .may_load(deps.as_ref().storage)
+            .expect("progress fetched")
+            .is_none());
+    }
+}
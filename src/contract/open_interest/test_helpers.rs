@@ -51,5 +51,6 @@ pub fn build_open_interest(
         interest_coin,
         expiry_duration,
         collateral,
+        ..Default::default()
     }
 }
@@ -0,0 +1,137 @@
+use cosmwasm_std::{attr, DepsMut, MessageInfo, Response};
+
+use crate::{
+    state::{LENDER, LENDER_PAYOUT},
+    ContractError,
+};
+
+pub fn transfer_lender_position(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_lender: String,
+) -> Result<Response, ContractError> {
+    let lender = LENDER
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::NoLender {})?;
+
+    if info.sender != lender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_lender = deps.api.addr_validate(&new_lender)?;
+    if new_lender == lender {
+        return Err(ContractError::LenderUnchanged {});
+    }
+
+    LENDER.save(deps.storage, &Some(new_lender.clone()))?;
+    // A payout address belongs to the previous lender and must not keep receiving funds.
+    LENDER_PAYOUT.remove(deps.storage);
+
+    Ok(Response::new().add_attributes([
+        attr("action", "transfer_lender_position"),
+        attr("previous_lender", lender.as_str()),
+        attr("new_lender", new_lender.as_str()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::open_interest::test_helpers::{
+        build_open_interest, sample_coin, setup, setup_active_open_interest,
+    };
+    use crate::contract::open_interest::{repay, set_payout_address};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{BankMsg, CosmosMsg};
+
+    #[test]
+    fn rejects_without_lender() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let buyer = deps.api.addr_make("buyer").into_string();
+        let err =
+            transfer_lender_position(deps.as_mut(), message_info(&owner, &[]), buyer).unwrap_err();
+
+        assert!(matches!(err, ContractError::NoLender {}));
+    }
+
+    #[test]
+    fn only_lender_can_transfer_to_a_new_address() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let buyer = deps.api.addr_make("buyer").into_string();
+        let err =
+            transfer_lender_position(deps.as_mut(), message_info(&owner, &[]), buyer).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = transfer_lender_position(
+            deps.as_mut(),
+            message_info(&lender, &[]),
+            lender.to_string(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::LenderUnchanged {}));
+    }
+
+    #[test]
+    fn repayment_goes_to_new_lender() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let buyer = deps.api.addr_make("buyer");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let cold = deps.api.addr_make("cold").into_string();
+        set_payout_address(deps.as_mut(), message_info(&lender, &[]), cold)
+            .expect("lender sets payout address");
+
+        let response =
+            transfer_lender_position(deps.as_mut(), message_info(&lender, &[]), buyer.to_string())
+                .expect("transfer succeeds");
+
+        assert_eq!(
+            response.attributes,
+            vec![
+                attr("action", "transfer_lender_position"),
+                attr("previous_lender", lender.as_str()),
+                attr("new_lender", buyer.as_str()),
+            ]
+        );
+        assert_eq!(
+            LENDER.load(deps.as_ref().storage).unwrap(),
+            Some(buyer.clone())
+        );
+
+        let env = mock_env();
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![sample_coin(100, "uusd"), sample_coin(15, "uinterest")],
+        );
+        let response = repay(deps.as_mut(), env, message_info(&owner, &[])).expect("repay works");
+
+        match &response.messages[0].msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => {
+                assert_eq!(to_address, buyer.as_str());
+            }
+            msg => panic!("unexpected message: {msg:?}"),
+        }
+    }
+}
@@ -1,23 +1,110 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Deps, Env, Order, QueryResponse, StdResult};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, Deps, Env, Order, QueryResponse, StdError, StdResult, Uint256,
+};
 
+use crate::contract::open_interest::{
+    late_fee, lender_payout_address, repayment_due, validate_open_interest,
+};
+use crate::contract::staking::undelegate::unbonding_seconds;
+use crate::contract::withdraw::available_to_withdraw;
+use crate::error::{ContractError, ERROR_CATALOG};
+use crate::helpers::{load_config, minimum_collateral_lock_for_denom, query_staked_balance};
 use crate::msg::QueryMsg;
-use crate::state::{COUNTER_OFFERS, LENDER, OPEN_INTEREST, OWNER};
-use crate::types::{CounterOffer, InfoResponse};
+use crate::state::{
+    COUNTER_OFFERS, INTEREST_NONCE, LAST_HOOK_FAILED, LAST_LIQUIDATION_UNBONDING, LAST_LIQUIDATOR,
+    LAST_SETTLEMENT, LENDER, LIQUIDATED_COLLATERAL_DENOM, LIQUIDATION_PAID,
+    LIQUIDATION_UNBONDING_AMOUNT, LOAN_HISTORY, LOAN_REFERENCE, LOAN_SETTLED, MAX_COUNTER_OFFERS,
+    MAX_LOAN_HISTORY, OPEN_INTEREST, OPEN_INTEREST_EXPIRY, OPERATOR, OUTSTANDING_DEBT, OWNER,
+    QUEUED_INTEREST, TERM_SNAPSHOTS, UNBONDING_ENTRIES, UNCLAIMED_REFUND_TOTALS, VOTES,
+};
+use crate::types::{
+    CanOpenResponse, ConfigResponse, CounterOffer, DelegationInfo, ErrorCatalogEntry,
+    EstimatedYieldResponse, HealthResponse, HookStatusResponse, InfoResponse,
+    InvariantCheckResponse, LenderResponse, LiquidationSettlementResponse, LoanHistoryEntry,
+    LoanState, NetPositionResponse, OpenInterest, OwnershipResponse, ReservedDenom, UnbondingEntry,
+};
+use cw_storage_plus::Bound;
+
+const DEFAULT_LOAN_HISTORY_LIMIT: u64 = 30;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
     match msg {
-        QueryMsg::Info => query_info(deps),
+        QueryMsg::Info => query_info(deps, env),
+        QueryMsg::TermSnapshot { id } => query_term_snapshot(deps, id),
+        QueryMsg::Config {} => query_config(deps),
+        QueryMsg::Vote { proposal_id } => query_vote(deps, proposal_id),
+        QueryMsg::InvariantCheck {} => query_invariant_check(deps),
+        QueryMsg::EffectiveInterest {} => query_effective_interest(deps, env),
+        QueryMsg::LiquidationSettlement {} => query_liquidation_settlement(deps),
+        QueryMsg::Delegations {} => query_delegations(deps, env),
+        QueryMsg::Unbonding {} => query_unbonding(deps, env),
+        QueryMsg::NetPosition {} => query_net_position(deps, env),
+        QueryMsg::PayoutAddress {} => query_payout_address(deps),
+        QueryMsg::RepaymentDue {} => query_repayment_due(deps, env),
+        QueryMsg::CanOpen { open_interest } => query_can_open(deps, env, *open_interest),
+        QueryMsg::CounterOffer { proposer } => query_counter_offer(deps, proposer),
+        QueryMsg::CounterOfferRanking { limit } => query_counter_offer_ranking(deps, limit),
+        QueryMsg::Health {} => query_health(deps, env),
+        QueryMsg::ErrorCatalog {} => query_error_catalog(),
+        QueryMsg::QueuedInterest {} => query_queued_interest(deps),
+        QueryMsg::Balances {} => query_balances(deps, env),
+        QueryMsg::Hook {} => query_hook(deps),
+        QueryMsg::EstimatedYield {} => query_estimated_yield(),
+        QueryMsg::Withdrawable { denom } => query_withdrawable(deps, env, denom),
+        QueryMsg::CollateralLock { denom } => query_collateral_lock(deps, env, denom),
+        QueryMsg::ReservedDenoms {} => query_reserved_denoms(deps, env),
+        QueryMsg::Ownership {} => query_ownership(deps),
+        QueryMsg::ReclaimableCollateral {} => query_reclaimable_collateral(deps, env),
+        QueryMsg::LastSettlement {} => to_json_binary(&LAST_SETTLEMENT.may_load(deps.storage)?),
+        QueryMsg::LoanHistory { start_after, limit } => {
+            query_loan_history(deps, start_after, limit)
+        }
+        QueryMsg::Lender {} => query_lender(deps),
+        QueryMsg::LoanState {} => query_loan_state(deps),
     }
 }
 
-fn query_info(deps: Deps) -> StdResult<QueryResponse> {
+fn query_info(deps: Deps, env: Env) -> StdResult<QueryResponse> {
     let owner = OWNER.load(deps.storage)?;
     let lender = LENDER.load(deps.storage)?;
     let open_interest = OPEN_INTEREST.load(deps.storage)?;
-    let mut collected_offers: Vec<CounterOffer> = COUNTER_OFFERS
+    let collected_offers = ranked_counter_offers(deps)?;
+    let counter_offers = if collected_offers.is_empty() {
+        None
+    } else {
+        Some(collected_offers)
+    };
+
+    let expiry = match lender {
+        Some(_) => OPEN_INTEREST_EXPIRY.may_load(deps.storage)?.flatten(),
+        None => None,
+    };
+
+    let response = InfoResponse {
+        message: "wasm_vault".to_string(),
+        owner: owner.into_string(),
+        lender: lender.map(|addr| addr.into_string()),
+        open_interest,
+        counter_offers,
+        expiry,
+        liquidatable: expiry.is_some_and(|expiry| expiry <= env.block.time),
+        reference: LOAN_REFERENCE.may_load(deps.storage)?.flatten(),
+        interest_nonce: INTEREST_NONCE.may_load(deps.storage)?.unwrap_or_default(),
+        last_liquidator: LAST_LIQUIDATOR
+            .may_load(deps.storage)?
+            .flatten()
+            .map(Addr::into_string),
+    };
+
+    to_json_binary(&response)
+}
+
+/// Counter offers ordered best first: largest liquidity amount, then proposer address.
+fn ranked_counter_offers(deps: Deps) -> StdResult<Vec<CounterOffer>> {
+    let mut offers: Vec<CounterOffer> = COUNTER_OFFERS
         .range(deps.storage, None, None, Order::Ascending)
         .map(|entry| {
             let (addr, open_interest) = entry?;
@@ -27,37 +114,538 @@ fn query_info(deps: Deps) -> StdResult<QueryResponse> {
             })
         })
         .collect::<StdResult<_>>()?;
-    collected_offers.sort_by(|a, b| {
+    offers.sort_by(|a, b| {
         b.open_interest
             .liquidity_coin
             .amount
             .cmp(&a.open_interest.liquidity_coin.amount)
             .then_with(|| a.proposer.cmp(&b.proposer))
     });
-    let counter_offers = if collected_offers.is_empty() {
-        None
+    Ok(offers)
+}
+
+fn query_loan_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueryResponse> {
+    let limit = limit
+        .map_or(DEFAULT_LOAN_HISTORY_LIMIT, u64::from)
+        .min(MAX_LOAN_HISTORY) as usize;
+    let entries: Vec<LoanHistoryEntry> = LOAN_HISTORY
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(_, entry)| entry))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&entries)
+}
+
+fn query_counter_offer_ranking(deps: Deps, limit: Option<u32>) -> StdResult<QueryResponse> {
+    let limit = limit
+        .unwrap_or(MAX_COUNTER_OFFERS as u32)
+        .min(MAX_COUNTER_OFFERS as u32) as usize;
+    let ranking: Vec<(String, OpenInterest)> = ranked_counter_offers(deps)?
+        .into_iter()
+        .take(limit)
+        .map(|offer| (offer.proposer, offer.open_interest))
+        .collect();
+    to_json_binary(&ranking)
+}
+
+fn query_config(deps: Deps) -> StdResult<QueryResponse> {
+    let config = load_config(deps.storage)?;
+    let operator = OPERATOR.may_load(deps.storage)?.map(Addr::into_string);
+
+    to_json_binary(&ConfigResponse {
+        liquidation_unbonding_duration: config.liquidation_unbonding_duration,
+        allowed_denoms: config.allowed_denoms,
+        max_counter_offers: config.max_counter_offers,
+        liquidation_bonus_bps: config.liquidation_bonus_bps,
+        unbonding_seconds: config.unbonding_seconds,
+        max_expiry_duration: config.max_expiry_duration,
+        min_liquidity: config.min_liquidity,
+        max_per_validator: config.max_per_validator,
+        hook_contract: config.hook_contract.map(Addr::into_string),
+        operator,
+        slashing_haircut_bps: config.slashing_haircut_bps,
+        return_liquidation_surplus: config.return_liquidation_surplus,
+        undelegation_priority: config.undelegation_priority,
+        fee_bps: config.fee_bps,
+        fee_recipient: config.fee_recipient.map(Addr::into_string),
+    })
+}
+
+fn query_vote(deps: Deps, proposal_id: u64) -> StdResult<QueryResponse> {
+    let vote = VOTES.may_load(deps.storage, proposal_id)?;
+    to_json_binary(&vote)
+}
+
+fn query_invariant_check(deps: Deps) -> StdResult<QueryResponse> {
+    let violations = collect_invariant_violations(deps)?;
+
+    to_json_binary(&InvariantCheckResponse {
+        healthy: violations.is_empty(),
+        violations,
+    })
+}
+
+fn collect_invariant_violations(deps: Deps) -> StdResult<Vec<String>> {
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    let lender = LENDER.may_load(deps.storage)?.flatten();
+    let expiry = OPEN_INTEREST_EXPIRY.may_load(deps.storage)?.flatten();
+    let debt = OUTSTANDING_DEBT.may_load(deps.storage)?.flatten();
+    let offers = COUNTER_OFFERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut violations = Vec::new();
+
+    if lender.is_some() && open_interest.is_none() {
+        violations.push("lender is set without an open interest".to_string());
+    }
+
+    if lender.is_some() != expiry.is_some() {
+        violations.push(format!(
+            "expiry must be set iff a lender is set (lender set: {}, expiry set: {})",
+            lender.is_some(),
+            expiry.is_some()
+        ));
+    }
+
+    if lender.is_some() && !offers.is_empty() {
+        violations.push(format!(
+            "{} counter offers remain after a lender was set",
+            offers.len()
+        ));
+    }
+
+    if open_interest.is_none() && !offers.is_empty() {
+        violations.push(format!(
+            "{} counter offers exist without an open interest",
+            offers.len()
+        ));
+    }
+
+    if lender.is_none() {
+        let mut escrow = Uint256::zero();
+        for (addr, offer) in &offers {
+            let matches_debt = debt
+                .as_ref()
+                .is_some_and(|debt| debt.denom == offer.liquidity_coin.denom);
+            if !matches_debt {
+                violations.push(format!(
+                    "counter offer escrow from {addr} is not tracked as outstanding debt"
+                ));
+            }
+            escrow = escrow.checked_add(offer.liquidity_coin.amount)?;
+        }
+
+        let tracked = debt.as_ref().map(|debt| debt.amount).unwrap_or_default();
+        if escrow != tracked {
+            violations.push(format!(
+                "counter offer escrow {escrow} does not match outstanding debt {tracked}"
+            ));
+        }
+    }
+
+    Ok(violations)
+}
+
+fn query_effective_interest(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    // No prepayment or discount is tracked, so the nominal interest plus any late fee is owed.
+    let lender = LENDER.may_load(deps.storage)?.flatten();
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    let owed: Vec<Coin> = match (lender, open_interest) {
+        (Some(_), Some(open_interest)) => {
+            let late_fee = late_fee(deps.storage, &open_interest, env.block.time)?;
+            let mut interest = open_interest.interest_coin;
+            interest.amount = interest.amount.checked_add(late_fee)?;
+            if interest.amount.is_zero() {
+                vec![]
+            } else {
+                vec![interest]
+            }
+        }
+        _ => vec![],
+    };
+
+    to_json_binary(&owed)
+}
+
+fn query_liquidation_settlement(deps: Deps) -> StdResult<QueryResponse> {
+    let immediate_paid = LIQUIDATION_PAID.may_load(deps.storage)?.unwrap_or_default();
+    let unbonding_amount = LIQUIDATION_UNBONDING_AMOUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+
+    let unbonding_seconds = unbonding_seconds(deps.storage)?;
+    let estimated_completion = match LAST_LIQUIDATION_UNBONDING.may_load(deps.storage)?.flatten() {
+        Some(started) if !unbonding_amount.is_zero() && unbonding_seconds > 0 => {
+            Some(started.plus_seconds(unbonding_seconds))
+        }
+        _ => None,
+    };
+
+    to_json_binary(&LiquidationSettlementResponse {
+        immediate_paid,
+        unbonding_amount,
+        estimated_completion,
+    })
+}
+
+fn query_delegations(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    let delegations = deps
+        .querier
+        .query_all_delegations(env.contract.address.clone())?
+        .into_iter()
+        .map(|delegation| {
+            let accumulated_rewards = deps
+                .querier
+                .query_delegation(env.contract.address.clone(), delegation.validator.clone())?
+                .map(|full| full.accumulated_rewards)
+                .unwrap_or_default();
+
+            Ok(DelegationInfo {
+                validator: delegation.validator,
+                staked: delegation.amount,
+                accumulated_rewards,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&delegations)
+}
+
+fn query_unbonding(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    let entries: Vec<UnbondingEntry> = UNBONDING_ENTRIES
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| entry.completion_time > env.block.time)
+        .collect();
+
+    to_json_binary(&entries)
+}
+
+fn query_ownership(deps: Deps) -> StdResult<QueryResponse> {
+    to_json_binary(&OwnershipResponse {
+        owner: OWNER.load(deps.storage)?.into_string(),
+        pending_owner: None,
+    })
+}
+
+fn query_lender(deps: Deps) -> StdResult<QueryResponse> {
+    let Some(lender) = LENDER.may_load(deps.storage)?.flatten() else {
+        return to_json_binary(&LenderResponse {
+            lender: None,
+            funded_amount: None,
+        });
+    };
+    let funded_amount = OPEN_INTEREST
+        .may_load(deps.storage)?
+        .flatten()
+        .map(|open_interest| open_interest.liquidity_coin);
+
+    to_json_binary(&LenderResponse {
+        lender: Some(lender.into_string()),
+        funded_amount,
+    })
+}
+
+fn query_loan_state(deps: Deps) -> StdResult<QueryResponse> {
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    let lender = LENDER.may_load(deps.storage)?.flatten();
+
+    let state = match (open_interest, lender) {
+        (Some(_), Some(_)) => LoanState::Funded,
+        (Some(_), None) => LoanState::Pending,
+        // `LAST_SETTLEMENT` outlives the loan it describes; only report it until the vault reopens.
+        (None, _) if LOAN_SETTLED.may_load(deps.storage)?.unwrap_or(false) => LoanState::Settled,
+        (None, _) => LoanState::Empty,
+    };
+
+    to_json_binary(&state)
+}
+
+fn query_reclaimable_collateral(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    // Collateral pledged to a pending or funded interest is not reclaimable.
+    if let Some(open_interest) = OPEN_INTEREST.may_load(deps.storage)?.flatten() {
+        return to_json_binary(&Coin::new(0u128, open_interest.collateral.denom));
+    }
+
+    let Some(denom) = LIQUIDATED_COLLATERAL_DENOM.may_load(deps.storage)? else {
+        return to_json_binary(&Coin::new(0u128, deps.querier.query_bonded_denom()?));
+    };
+    let amount = available_to_withdraw(&deps, &env, &denom)?;
+
+    to_json_binary(&Coin::new(amount, denom))
+}
+
+fn query_hook(deps: Deps) -> StdResult<QueryResponse> {
+    to_json_binary(&HookStatusResponse {
+        hook_contract: load_config(deps.storage)?
+            .hook_contract
+            .map(Addr::into_string),
+        last_hook_failed: LAST_HOOK_FAILED.may_load(deps.storage)?.unwrap_or(false),
+    })
+}
+
+/// The staking query interface exposes no APR, so there is no rate to project rewards from.
+fn query_estimated_yield() -> StdResult<QueryResponse> {
+    to_json_binary(&EstimatedYieldResponse {
+        annual_rewards: None,
+    })
+}
+
+fn query_withdrawable(deps: Deps, env: Env, denom: String) -> StdResult<QueryResponse> {
+    to_json_binary(&available_to_withdraw(&deps, &env, &denom)?)
+}
+
+fn query_collateral_lock(deps: Deps, env: Env, denom: String) -> StdResult<QueryResponse> {
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    to_json_binary(&minimum_collateral_lock_for_denom(
+        &deps,
+        &env,
+        &denom,
+        open_interest.as_ref(),
+    )?)
+}
+
+fn query_reserved_denoms(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    let mut reserved = Vec::new();
+
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    if let Some(debt) = OUTSTANDING_DEBT.may_load(deps.storage)?.flatten() {
+        // Until a lender is set, the tracked debt is escrow held for counter offers.
+        let lender = LENDER.may_load(deps.storage)?.flatten();
+        let reason = if open_interest.is_some() && lender.is_none() {
+            "counter_offer_escrow"
+        } else {
+            "outstanding_debt"
+        };
+        reserved.push(ReservedDenom {
+            denom: debt.denom,
+            reason: reason.to_string(),
+            amount: debt.amount,
+        });
+    }
+
+    if let Some(interest) = &open_interest {
+        let denom = &interest.collateral.denom;
+        reserved.push(ReservedDenom {
+            denom: denom.clone(),
+            reason: "collateral_lock".to_string(),
+            amount: minimum_collateral_lock_for_denom(&deps, &env, denom, open_interest.as_ref())?,
+        });
+    }
+
+    for entry in UNCLAIMED_REFUND_TOTALS.range(deps.storage, None, None, Order::Ascending) {
+        let (denom, amount) = entry?;
+        reserved.push(ReservedDenom {
+            denom,
+            reason: "unclaimed_refunds".to_string(),
+            amount,
+        });
+    }
+
+    reserved.retain(|reservation| !reservation.amount.is_zero());
+    to_json_binary(&reserved)
+}
+
+fn query_balances(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    to_json_binary(&known_balances(deps, &env)?)
+}
+
+/// Non-zero balances of every denom the vault knows about.
+///
+/// There is no all-balances query, so this covers the bonded denom, the open and queued interest
+/// terms, outstanding debt, and the denom allowlist.
+fn known_balances(deps: Deps, env: &Env) -> StdResult<Vec<Coin>> {
+    let mut denoms = vec![deps.querier.query_bonded_denom()?];
+    for interest in [
+        OPEN_INTEREST.may_load(deps.storage)?.flatten(),
+        QUEUED_INTEREST.may_load(deps.storage)?.flatten(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        denoms.push(interest.liquidity_coin.denom);
+        denoms.push(interest.interest_coin.denom);
+        denoms.push(interest.collateral.denom);
+    }
+    if let Some(debt) = OUTSTANDING_DEBT.may_load(deps.storage)?.flatten() {
+        denoms.push(debt.denom);
+    }
+    denoms.extend(
+        load_config(deps.storage)?
+            .allowed_denoms
+            .unwrap_or_default(),
+    );
+
+    let mut balances: Vec<Coin> = Vec::new();
+    for denom in denoms {
+        if balances.iter().any(|coin| coin.denom == denom) {
+            continue;
+        }
+        let balance = deps
+            .querier
+            .query_balance(env.contract.address.clone(), denom)?;
+        if !balance.amount.is_zero() {
+            balances.push(balance);
+        }
+    }
+
+    Ok(balances)
+}
+
+fn query_net_position(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    let bonded_denom = deps.querier.query_bonded_denom()?;
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    let reserved_debt = OUTSTANDING_DEBT.may_load(deps.storage)?.flatten();
+
+    let liquid = known_balances(deps, &env)?;
+
+    let staked = Coin::new(
+        query_staked_balance(&deps, &env, &bonded_denom)?,
+        bonded_denom,
+    );
+    let pending_rewards = deps
+        .querier
+        .query_delegation_total_rewards(env.contract.address.clone())?
+        .total
+        .into_iter()
+        .map(|reward| Coin::new(reward.amount.to_uint_floor(), reward.denom))
+        .filter(|reward| !reward.amount.is_zero())
+        .collect();
+
+    let collateral_locked = match &open_interest {
+        Some(interest) => minimum_collateral_lock_for_denom(
+            &deps,
+            &env,
+            &interest.collateral.denom,
+            open_interest.as_ref(),
+        )?,
+        None => Uint256::zero(),
+    };
+
+    to_json_binary(&NetPositionResponse {
+        liquid,
+        staked,
+        pending_rewards,
+        reserved_debt,
+        collateral_locked,
+    })
+}
+
+fn query_payout_address(deps: Deps) -> StdResult<QueryResponse> {
+    let payout_address = match LENDER.may_load(deps.storage)?.flatten() {
+        Some(lender) => Some(lender_payout_address(deps.storage, &lender)?.into_string()),
+        None => None,
+    };
+
+    to_json_binary(&payout_address)
+}
+
+fn query_repayment_due(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    let (Some(_), Some(open_interest)) = (
+        LENDER.may_load(deps.storage)?.flatten(),
+        OPEN_INTEREST.may_load(deps.storage)?.flatten(),
+    ) else {
+        return to_json_binary(&Vec::<Coin>::new());
+    };
+
+    let due = repayment_due(deps.storage, &open_interest, env.block.time)
+        .map_err(|err| StdError::msg(err.to_string()))?;
+    to_json_binary(&due)
+}
+
+fn query_can_open(deps: Deps, env: Env, open_interest: OpenInterest) -> StdResult<QueryResponse> {
+    let result = if OPEN_INTEREST.may_load(deps.storage)?.flatten().is_some() {
+        Err(ContractError::OpenInterestAlreadyExists {})
     } else {
-        Some(collected_offers)
+        validate_open_interest(&deps, &env, &open_interest)
     };
 
-    let response = InfoResponse {
-        message: "wasm_vault".to_string(),
-        owner: owner.into_string(),
-        lender: lender.map(|addr| addr.into_string()),
-        open_interest,
-        counter_offers,
+    to_json_binary(&CanOpenResponse {
+        ok: result.is_ok(),
+        reason: result.err().map(|err| err.to_string()),
+    })
+}
+
+fn query_counter_offer(deps: Deps, proposer: String) -> StdResult<QueryResponse> {
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let offer = COUNTER_OFFERS.may_load(deps.storage, &proposer)?;
+
+    to_json_binary(&offer)
+}
+
+fn query_health(deps: Deps, env: Env) -> StdResult<QueryResponse> {
+    let Some(open_interest) = OPEN_INTEREST.may_load(deps.storage)?.flatten() else {
+        return to_json_binary(&HealthResponse {
+            collateral_available: Uint256::zero(),
+            required_lock: Uint256::zero(),
+            is_covered: true,
+        });
     };
 
-    to_json_binary(&response)
+    let denom = &open_interest.collateral.denom;
+    let collateral_available = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.clone())?
+        .amount;
+    let required_lock =
+        minimum_collateral_lock_for_denom(&deps, &env, denom, Some(&open_interest))?;
+
+    to_json_binary(&HealthResponse {
+        collateral_available,
+        required_lock,
+        is_covered: collateral_available >= required_lock,
+    })
+}
+
+fn query_error_catalog() -> StdResult<QueryResponse> {
+    let catalog: Vec<ErrorCatalogEntry> = ERROR_CATALOG
+        .iter()
+        .map(|(code, description)| ErrorCatalogEntry {
+            code: code.to_string(),
+            description: description.to_string(),
+        })
+        .collect();
+
+    to_json_binary(&catalog)
+}
+
+fn query_queued_interest(deps: Deps) -> StdResult<QueryResponse> {
+    let queued = QUEUED_INTEREST.may_load(deps.storage)?.flatten();
+    to_json_binary(&queued)
+}
+
+fn query_term_snapshot(deps: Deps, id: u64) -> StdResult<QueryResponse> {
+    let snapshot = TERM_SNAPSHOTS.may_load(deps.storage, id)?;
+    to_json_binary(&snapshot)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::OpenInterest;
+    use crate::contract::open_interest::test_helpers::{
+        build_open_interest, sample_coin, setup_active_open_interest,
+    };
+    use crate::helpers::update_config;
+    use crate::state::{
+        DEFAULT_LIQUIDATION_UNBONDING_SECONDS, LENDER_PAYOUT, OPEN_INTEREST_FUNDED,
+    };
+    use crate::types::{LoanOutcome, OpenInterest, SettlementRecord, TermSnapshot};
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_env},
-        Coin,
+        coins, from_json,
+        testing::{message_info, mock_dependencies, mock_env},
+        Coin, DecCoin, Decimal, Decimal256, FullDelegation, Timestamp, Validator,
     };
 
     #[test]
@@ -72,12 +660,16 @@ mod tests {
         LENDER
             .save(deps.as_mut().storage, &Some(lender.clone()))
             .expect("lender saved");
+        OPEN_INTEREST_EXPIRY
+            .save(deps.as_mut().storage, &Some(Timestamp::from_seconds(500)))
+            .expect("expiry saved");
 
         let open_interest = OpenInterest {
             liquidity_coin: Coin::new(100u128, "uusd"),
             interest_coin: Coin::new(5u128, "uusd"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(200u128, "ujuno"),
+            ..Default::default()
         };
 
         OPEN_INTEREST
@@ -96,10 +688,18 @@ mod tests {
         assert_eq!(info.owner, owner.into_string());
         assert_eq!(info.lender, Some(lender.into_string()));
         assert_eq!(info.open_interest, Some(open_interest.clone()));
+        assert_eq!(info.expiry, Some(Timestamp::from_seconds(500)));
+        assert!(info.liquidatable);
         let offers = info.counter_offers.expect("counter offers present");
         assert_eq!(offers.len(), 1);
         assert_eq!(offers[0].proposer, proposer.into_string());
         assert_eq!(offers[0].open_interest, open_interest);
+
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(499);
+        let response = query(deps.as_ref(), env, QueryMsg::Info).expect("query succeeds");
+        let info: InfoResponse = cosmwasm_std::from_json(response).expect("valid json");
+        assert!(!info.liquidatable);
     }
 
     #[test]
@@ -127,6 +727,17 @@ mod tests {
         assert_eq!(info.lender, None);
         assert_eq!(info.open_interest, None);
         assert!(info.counter_offers.is_none());
+        assert_eq!(info.expiry, None);
+        assert!(!info.liquidatable);
+        assert_eq!(info.last_liquidator, None);
+
+        let liquidator = deps.api.addr_make("liquidator");
+        LAST_LIQUIDATOR
+            .save(deps.as_mut().storage, &Some(liquidator.clone()))
+            .expect("liquidator saved");
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::Info).expect("query succeeds");
+        let info: InfoResponse = cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(info.last_liquidator, Some(liquidator.into_string()));
     }
 
     #[test]
@@ -146,6 +757,7 @@ mod tests {
             interest_coin: Coin::new(50u128, "ujuno"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(2_000u128, "uatom"),
+            ..Default::default()
         };
 
         OPEN_INTEREST
@@ -176,14 +788,1219 @@ mod tests {
         let proposer_order: Vec<_> = offers.iter().map(|o| o.proposer.clone()).collect();
         assert_eq!(
             proposer_order,
+            vec![best.to_string(), medium.to_string(), worst.into_string()]
+        );
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::CounterOfferRanking { limit: Some(2) },
+        )
+        .expect("query succeeds");
+        let ranking: Vec<(String, OpenInterest)> =
+            cosmwasm_std::from_json(response).expect("valid json");
+        let ranked: Vec<_> = ranking
+            .iter()
+            .map(|(proposer, offer)| (proposer.clone(), offer.liquidity_coin.amount))
+            .collect();
+        assert_eq!(
+            ranked,
             vec![
-                best.into_string(),
-                medium.into_string(),
-                worst.into_string()
+                (best.into_string(), Uint256::from(950u128)),
+                (medium.into_string(), Uint256::from(900u128)),
             ]
         );
     }
 
+    #[test]
+    fn query_term_snapshot_returns_stored_entry() {
+        let mut deps = mock_dependencies();
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(100u128, "uusd"),
+            interest_coin: Coin::new(5u128, "uusd"),
+            expiry_duration: 86_400u64,
+            collateral: Coin::new(200u128, "ujuno"),
+            ..Default::default()
+        };
+        let snapshot = TermSnapshot {
+            id: 3,
+            taken_at: Timestamp::from_seconds(42),
+            taken_by: deps.api.addr_make("owner").into_string(),
+            open_interest,
+            lender: None,
+            expiry: None,
+            outstanding_debt: None,
+            balances: vec![Coin::new(200u128, "ujuno")],
+        };
+        TERM_SNAPSHOTS
+            .save(deps.as_mut().storage, 3, &snapshot)
+            .expect("snapshot saved");
+
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::TermSnapshot { id: 3 })
+            .expect("query succeeds");
+        let stored: Option<TermSnapshot> = cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(stored, Some(snapshot));
+
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::TermSnapshot { id: 4 })
+            .expect("query succeeds");
+        let missing: Option<TermSnapshot> = cosmwasm_std::from_json(response).expect("valid json");
+        assert!(missing.is_none());
+    }
+
+    #[test]
+    fn query_config_returns_allowlist_and_defaults() {
+        let mut deps = mock_dependencies();
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::Config {}).expect("query succeeds");
+        let config: ConfigResponse = cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(
+            config,
+            ConfigResponse {
+                liquidation_unbonding_duration: DEFAULT_LIQUIDATION_UNBONDING_SECONDS,
+                allowed_denoms: None,
+                max_counter_offers: MAX_COUNTER_OFFERS,
+                liquidation_bonus_bps: 0,
+                unbonding_seconds: None,
+                max_expiry_duration: None,
+                min_liquidity: None,
+                max_per_validator: None,
+                hook_contract: None,
+                operator: None,
+                slashing_haircut_bps: 0,
+                return_liquidation_surplus: false,
+                undelegation_priority: vec![],
+                fee_bps: 0,
+                fee_recipient: None,
+            }
+        );
+
+        update_config(deps.as_mut().storage, |config| {
+            config.liquidation_unbonding_duration = 3_600
+        })
+        .expect("duration saved");
+        update_config(deps.as_mut().storage, |config| {
+            config.allowed_denoms = Some(vec!["uatom".to_string()])
+        })
+        .expect("allowlist saved");
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::Config {}).expect("query succeeds");
+        let config: ConfigResponse = cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(config.liquidation_unbonding_duration, 3_600);
+        assert_eq!(config.allowed_denoms, Some(vec!["uatom".to_string()]));
+    }
+
+    #[test]
+    fn invariant_check_reports_healthy_state() {
+        let mut deps = mock_dependencies();
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(1_000u128, "uusd"),
+            interest_coin: Coin::new(50u128, "ujuno"),
+            expiry_duration: 86_400u64,
+            collateral: Coin::new(2_000u128, "uatom"),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest.clone()))
+            .expect("open interest saved");
+        LENDER
+            .save(deps.as_mut().storage, &None)
+            .expect("lender cleared");
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = open_interest.clone();
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        COUNTER_OFFERS
+            .save(deps.as_mut().storage, &proposer, &offer)
+            .expect("offer saved");
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(Coin::new(900u128, "uusd")))
+            .expect("debt saved");
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::InvariantCheck {}).expect("query succeeds");
+        let check: InvariantCheckResponse = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert!(check.healthy);
+        assert!(check.violations.is_empty());
+    }
+
+    #[test]
+    fn invariant_check_reports_corrupted_state() {
+        let mut deps = mock_dependencies();
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(1_000u128, "uusd"),
+            interest_coin: Coin::new(50u128, "ujuno"),
+            expiry_duration: 86_400u64,
+            collateral: Coin::new(2_000u128, "uatom"),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest.clone()))
+            .expect("open interest saved");
+        LENDER
+            .save(deps.as_mut().storage, &None)
+            .expect("lender cleared");
+        OPEN_INTEREST_EXPIRY
+            .save(deps.as_mut().storage, &Some(Timestamp::from_seconds(10)))
+            .expect("stray expiry saved");
+
+        let proposer = deps.api.addr_make("proposer");
+        let mut offer = open_interest;
+        offer.liquidity_coin.amount = Uint256::from(900u128);
+        COUNTER_OFFERS
+            .save(deps.as_mut().storage, &proposer, &offer)
+            .expect("offer saved");
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(Coin::new(500u128, "uusd")))
+            .expect("corrupted debt saved");
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::InvariantCheck {}).expect("query succeeds");
+        let check: InvariantCheckResponse = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert!(!check.healthy);
+        assert_eq!(check.violations.len(), 2, "{:?}", check.violations);
+        assert!(check.violations[0].contains("expiry"));
+        assert!(check.violations[1].contains("does not match outstanding debt"));
+    }
+
+    #[test]
+    fn effective_interest_returns_nominal_interest_for_active_loan() {
+        let mut deps = mock_dependencies();
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(100u128, "uusd"),
+            interest_coin: Coin::new(7u128, "ujuno"),
+            expiry_duration: 86_400,
+            collateral: Coin::new(200u128, "uatom"),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest))
+            .unwrap();
+        LENDER.save(deps.as_mut().storage, &None).unwrap();
+
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::EffectiveInterest {})
+            .expect("query succeeds");
+        let owed: Vec<Coin> = cosmwasm_std::from_json(response).expect("valid json");
+        assert!(owed.is_empty());
+
+        let lender = deps.api.addr_make("lender");
+        LENDER.save(deps.as_mut().storage, &Some(lender)).unwrap();
+
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::EffectiveInterest {})
+            .expect("query succeeds");
+        let owed: Vec<Coin> = cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(owed, vec![Coin::new(7u128, "ujuno")]);
+    }
+
+    #[test]
+    fn effective_interest_includes_late_fee_after_deadline() {
+        let mut deps = mock_dependencies();
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(100u128, "uusd"),
+            interest_coin: Coin::new(20u128, "ujuno"),
+            expiry_duration: 86_400,
+            collateral: Coin::new(200u128, "uatom"),
+            repayment_deadline_duration: Some(3_600),
+            late_fee_bps: Some(2_500),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest))
+            .unwrap();
+        let lender = deps.api.addr_make("lender");
+        LENDER.save(deps.as_mut().storage, &Some(lender)).unwrap();
+        let mut env = mock_env();
+        OPEN_INTEREST_FUNDED
+            .save(deps.as_mut().storage, &env.block.time)
+            .unwrap();
+
+        let owed = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, env: &Env| -> Vec<Coin> {
+            let response = query(deps.as_ref(), env.clone(), QueryMsg::EffectiveInterest {})
+                .expect("query succeeds");
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+        assert_eq!(owed(&deps, &env), vec![Coin::new(20u128, "ujuno")]);
+
+        env.block.time = env.block.time.plus_seconds(3_601);
+        assert_eq!(owed(&deps, &env), vec![Coin::new(25u128, "ujuno")]);
+    }
+
+    #[test]
+    fn liquidation_settlement_estimates_unbonding_completion() {
+        let mut deps = mock_dependencies();
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::LiquidationSettlement {},
+        )
+        .expect("query succeeds");
+        let settlement: LiquidationSettlementResponse =
+            cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(settlement.immediate_paid, Uint256::zero());
+        assert_eq!(settlement.estimated_completion, None);
+
+        LIQUIDATION_PAID
+            .save(deps.as_mut().storage, &Uint256::from(40u128))
+            .unwrap();
+        LIQUIDATION_UNBONDING_AMOUNT
+            .save(deps.as_mut().storage, &Uint256::from(60u128))
+            .unwrap();
+        update_config(deps.as_mut().storage, |config| {
+            config.liquidation_unbonding_duration = 86_400
+        })
+        .unwrap();
+        LAST_LIQUIDATION_UNBONDING
+            .save(deps.as_mut().storage, &Some(Timestamp::from_seconds(1_000)))
+            .unwrap();
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::LiquidationSettlement {},
+        )
+        .expect("query succeeds");
+        let settlement: LiquidationSettlementResponse =
+            cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(
+            settlement,
+            LiquidationSettlementResponse {
+                immediate_paid: Uint256::from(40u128),
+                unbonding_amount: Uint256::from(60u128),
+                estimated_completion: Some(Timestamp::from_seconds(87_400)),
+            }
+        );
+    }
+
+    #[test]
+    fn delegations_include_per_validator_rewards() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let validator = deps.api.addr_make("validator").into_string();
+
+        deps.querier.staking.update(
+            "ucosm",
+            &[Validator::create(
+                validator.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator.clone(),
+                Coin::new(500u128, "ucosm"),
+                Coin::new(500u128, "ucosm"),
+                vec![Coin::new(7u128, "ucosm")],
+            )],
+        );
+
+        let response = query(deps.as_ref(), env, QueryMsg::Delegations {}).expect("query succeeds");
+        let delegations: Vec<DelegationInfo> =
+            cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            delegations,
+            vec![DelegationInfo {
+                validator,
+                staked: Coin::new(500u128, "ucosm"),
+                accumulated_rewards: vec![Coin::new(7u128, "ucosm")],
+            }]
+        );
+    }
+
+    #[test]
+    fn unbonding_lists_undelegations_until_they_mature() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER.save(deps.as_mut().storage, &owner).unwrap();
+        update_config(deps.as_mut().storage, |config| {
+            config.unbonding_seconds = Some(1_000)
+        })
+        .unwrap();
+        let mut env = mock_env();
+        let validator = deps.api.addr_make("validator").into_string();
+
+        deps.querier.staking.update(
+            "ucosm",
+            &[Validator::create(
+                validator.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator.clone(),
+                Coin::new(500u128, "ucosm"),
+                Coin::new(500u128, "ucosm"),
+                vec![],
+            )],
+        );
+        let unbonding = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, env: &Env| {
+            let response =
+                query(deps.as_ref(), env.clone(), QueryMsg::Unbonding {}).expect("query succeeds");
+            cosmwasm_std::from_json::<Vec<UnbondingEntry>>(response).expect("valid json")
+        };
+        assert!(unbonding(&deps, &env).is_empty());
+
+        crate::contract::staking::undelegate::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            validator.clone(),
+            cosmwasm_std::Uint128::new(200),
+        )
+        .expect("undelegation sent");
+
+        assert_eq!(
+            unbonding(&deps, &env),
+            vec![UnbondingEntry {
+                validator,
+                amount: Coin::new(200u128, "ucosm"),
+                completion_time: env.block.time.plus_seconds(1_000),
+            }]
+        );
+
+        env.block.time = env.block.time.plus_seconds(1_000);
+        assert!(unbonding(&deps, &env).is_empty());
+    }
+
+    #[test]
+    fn net_position_aggregates_staking_and_active_loan() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let validator = deps.api.addr_make("validator").into_string();
+
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(500, "ucosm"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        deps.querier.staking.update(
+            "ucosm",
+            &[Validator::create(
+                validator.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator.clone(),
+                Coin::new(300u128, "ucosm"),
+                Coin::new(300u128, "ucosm"),
+                vec![],
+            )],
+        );
+        deps.querier.distribution.set_rewards(
+            validator,
+            env.contract.address.as_str(),
+            vec![DecCoin::new(
+                Decimal256::from_atomics(50u128, 0).unwrap(),
+                "ucosm",
+            )],
+        );
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![
+                Coin::new(1_000u128, "ucosm"),
+                Coin::new(15u128, "uinterest"),
+            ],
+        );
+
+        let response = query(deps.as_ref(), env, QueryMsg::NetPosition {}).expect("query succeeds");
+        let position: NetPositionResponse = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            position,
+            NetPositionResponse {
+                liquid: vec![
+                    Coin::new(1_000u128, "ucosm"),
+                    Coin::new(15u128, "uinterest"),
+                ],
+                staked: Coin::new(300u128, "ucosm"),
+                pending_rewards: vec![Coin::new(50u128, "ucosm")],
+                reserved_debt: None,
+                collateral_locked: Uint256::from(150u128),
+            }
+        );
+    }
+
+    #[test]
+    fn payout_address_resolves_to_override_then_lender() {
+        let mut deps = mock_dependencies();
+        let payout = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> Option<String> {
+            let response =
+                query(deps.as_ref(), mock_env(), QueryMsg::PayoutAddress {}).expect("query works");
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+
+        LENDER.save(deps.as_mut().storage, &None).unwrap();
+        assert_eq!(payout(&deps), None);
+
+        let lender = deps.api.addr_make("lender");
+        LENDER
+            .save(deps.as_mut().storage, &Some(lender.clone()))
+            .unwrap();
+        assert_eq!(payout(&deps), Some(lender.to_string()));
+
+        let cold = deps.api.addr_make("cold");
+        LENDER_PAYOUT
+            .save(deps.as_mut().storage, &Some(cold.clone()))
+            .unwrap();
+        assert_eq!(payout(&deps), Some(cold.to_string()));
+    }
+
+    #[test]
+    fn counter_offer_returns_single_proposer_offer() {
+        let mut deps = mock_dependencies();
+        let proposer = deps.api.addr_make("proposer");
+        let offer = OpenInterest {
+            liquidity_coin: Coin::new(900u128, "uusd"),
+            interest_coin: Coin::new(50u128, "ujuno"),
+            expiry_duration: 86_400,
+            collateral: Coin::new(2_000u128, "uatom"),
+            ..Default::default()
+        };
+        COUNTER_OFFERS
+            .save(deps.as_mut().storage, &proposer, &offer)
+            .unwrap();
+
+        let lookup = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, proposer: String| {
+            let response = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::CounterOffer { proposer },
+            )
+            .expect("query works");
+            cosmwasm_std::from_json::<Option<OpenInterest>>(response).expect("valid json")
+        };
+
+        assert_eq!(lookup(&deps, proposer.to_string()), Some(offer));
+        assert_eq!(lookup(&deps, deps.api.addr_make("other").to_string()), None);
+
+        let err = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::CounterOffer {
+                proposer: "not-an-address".to_string(),
+            },
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn health_is_trivially_covered_without_open_interest() {
+        let mut deps = mock_dependencies();
+        OPEN_INTEREST.save(deps.as_mut().storage, &None).unwrap();
+
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::Health {}).expect("query works");
+        let health: HealthResponse = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            health,
+            HealthResponse {
+                collateral_available: Uint256::zero(),
+                required_lock: Uint256::zero(),
+                is_covered: true,
+            }
+        );
+    }
+
+    #[test]
+    fn health_counts_staked_collateral_towards_the_lock() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let validator = deps.api.addr_make("validator").into_string();
+
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(500, "ucosm"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+        deps.querier.staking.update(
+            "ucosm",
+            &[Validator::create(
+                validator.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator,
+                Coin::new(300u128, "ucosm"),
+                Coin::new(300u128, "ucosm"),
+                vec![],
+            )],
+        );
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![Coin::new(150u128, "ucosm")],
+        );
+
+        let health = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> HealthResponse {
+            let response = query(deps.as_ref(), mock_env(), QueryMsg::Health {}).unwrap();
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+
+        let uncovered = health(&deps);
+        assert_eq!(uncovered.collateral_available, Uint256::from(150u128));
+        assert_eq!(uncovered.required_lock, Uint256::from(200u128));
+        assert!(!uncovered.is_covered);
+
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![Coin::new(200u128, "ucosm")],
+        );
+        assert!(health(&deps).is_covered);
+    }
+
+    #[test]
+    fn queued_interest_reflects_queue() {
+        let mut deps = mock_dependencies();
+        let queued = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> Option<OpenInterest> {
+            let response =
+                query(deps.as_ref(), mock_env(), QueryMsg::QueuedInterest {}).expect("query works");
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+
+        assert_eq!(queued(&deps), None);
+
+        let next = build_open_interest(
+            sample_coin(150, "uusd"),
+            sample_coin(20, "uinterest"),
+            172_800,
+            sample_coin(200, "uatom"),
+        );
+        QUEUED_INTEREST
+            .save(deps.as_mut().storage, &Some(next.clone()))
+            .unwrap();
+        assert_eq!(queued(&deps), Some(next));
+    }
+
+    #[test]
+    fn balances_cover_every_denom_in_vault_state() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        deps.querier.staking.update("ucosm", &[], &[]);
+        OPEN_INTEREST
+            .save(
+                deps.as_mut().storage,
+                &Some(OpenInterest {
+                    liquidity_coin: Coin::new(100u128, "uusd"),
+                    interest_coin: Coin::new(5u128, "uinterest"),
+                    expiry_duration: 86_400,
+                    collateral: Coin::new(200u128, "uatom"),
+                    ..Default::default()
+                }),
+            )
+            .expect("open interest stored");
+        update_config(deps.as_mut().storage, |config| {
+            config.allowed_denoms = Some(vec!["uusd".to_string(), "ujuno".to_string()])
+        })
+        .expect("allowlist stored");
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![
+                Coin::new(10u128, "ucosm"),
+                Coin::new(200u128, "uatom"),
+                Coin::new(3u128, "ujuno"),
+                Coin::new(9u128, "uunknown"),
+            ],
+        );
+
+        let response = query(deps.as_ref(), env, QueryMsg::Balances {}).expect("query succeeds");
+        let balances: Vec<Coin> = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            balances,
+            vec![
+                Coin::new(10u128, "ucosm"),
+                Coin::new(200u128, "uatom"),
+                Coin::new(3u128, "ujuno"),
+            ]
+        );
+    }
+
+    #[test]
+    fn hook_reports_last_notification_outcome() {
+        let mut deps = mock_dependencies();
+        let hook_status = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> HookStatusResponse {
+            let response =
+                query(deps.as_ref(), mock_env(), QueryMsg::Hook {}).expect("query succeeds");
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+
+        assert_eq!(
+            hook_status(&deps),
+            HookStatusResponse {
+                hook_contract: None,
+                last_hook_failed: false,
+            }
+        );
+
+        let hook = deps.api.addr_make("hook");
+        update_config(deps.as_mut().storage, |config| {
+            config.hook_contract = Some(hook.clone())
+        })
+        .expect("hook stored");
+        LAST_HOOK_FAILED
+            .save(deps.as_mut().storage, &true)
+            .expect("outcome stored");
+
+        assert_eq!(
+            hook_status(&deps),
+            HookStatusResponse {
+                hook_contract: Some(hook.into_string()),
+                last_hook_failed: true,
+            }
+        );
+    }
+
+    #[test]
+    fn withdrawable_excludes_outstanding_debt() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(Coin::new(40u128, "uusd")))
+            .expect("debt stored");
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &None)
+            .expect("open interest cleared");
+        deps.querier.bank.update_balance(
+            env.contract.address.as_str(),
+            vec![Coin::new(100u128, "uusd"), Coin::new(7u128, "ujuno")],
+        );
+
+        let withdrawable = |denom: &str| -> Uint256 {
+            let response = query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Withdrawable {
+                    denom: denom.to_string(),
+                },
+            )
+            .expect("query succeeds");
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+
+        assert_eq!(withdrawable("uusd"), Uint256::from(60u128));
+        assert_eq!(withdrawable("ujuno"), Uint256::from(7u128));
+    }
+
+    #[test]
+    fn reserved_denoms_lists_debt_and_collateral() {
+        let mut deps = mock_dependencies();
+        deps.querier.staking.update("ucosm", &[], &[]);
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(Coin::new(90u128, "uusd")))
+            .expect("debt stored");
+        OPEN_INTEREST
+            .save(
+                deps.as_mut().storage,
+                &Some(OpenInterest {
+                    liquidity_coin: Coin::new(100u128, "uusd"),
+                    interest_coin: Coin::new(5u128, "ujuno"),
+                    expiry_duration: 86_400,
+                    collateral: Coin::new(200u128, "uatom"),
+                    ..Default::default()
+                }),
+            )
+            .expect("open interest stored");
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::ReservedDenoms {}).expect("query works");
+        let reserved: Vec<ReservedDenom> = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            reserved,
+            vec![
+                ReservedDenom {
+                    denom: "uusd".to_string(),
+                    reason: "counter_offer_escrow".to_string(),
+                    amount: Uint256::from(90u128),
+                },
+                ReservedDenom {
+                    denom: "uatom".to_string(),
+                    reason: "collateral_lock".to_string(),
+                    amount: Uint256::from(200u128),
+                },
+            ]
+        );
+
+        let lender = deps.api.addr_make("lender");
+        LENDER
+            .save(deps.as_mut().storage, &Some(lender))
+            .expect("lender stored");
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::ReservedDenoms {}).expect("query works");
+        let reserved: Vec<ReservedDenom> = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            reserved[0],
+            ReservedDenom {
+                denom: "uusd".to_string(),
+                reason: "outstanding_debt".to_string(),
+                amount: Uint256::from(90u128),
+            }
+        );
+    }
+
+    #[test]
+    fn ownership_reports_owner() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER
+            .save(deps.as_mut().storage, &owner)
+            .expect("owner stored");
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::Ownership {}).expect("query works");
+        let ownership: OwnershipResponse = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(
+            ownership,
+            OwnershipResponse {
+                owner: owner.into_string(),
+                pending_owner: None,
+            }
+        );
+    }
+
+    #[test]
+    fn lender_reports_funded_liquidity() {
+        use crate::contract::open_interest::test_helpers::{
+            build_open_interest, sample_coin, setup_active_open_interest,
+        };
+
+        let mut deps = mock_dependencies();
+        LENDER
+            .save(deps.as_mut().storage, &None)
+            .expect("lender cleared");
+
+        let lender_of = |deps: Deps| -> LenderResponse {
+            cosmwasm_std::from_json(
+                query(deps, mock_env(), QueryMsg::Lender {}).expect("query works"),
+            )
+            .expect("valid json")
+        };
+        assert_eq!(
+            lender_of(deps.as_ref()),
+            LenderResponse {
+                lender: None,
+                funded_amount: None,
+            }
+        );
+
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        assert_eq!(
+            lender_of(deps.as_ref()),
+            LenderResponse {
+                lender: Some(lender.into_string()),
+                funded_amount: Some(sample_coin(100, "uusd")),
+            }
+        );
+    }
+
+    #[test]
+    fn loan_state_tracks_loan_lifecycle() {
+        use crate::contract::open_interest::test_helpers::{
+            build_open_interest, sample_coin, setup_active_open_interest,
+        };
+
+        let mut deps = mock_dependencies();
+        let loan_state = |deps: Deps| -> LoanState {
+            cosmwasm_std::from_json(
+                query(deps, mock_env(), QueryMsg::LoanState {}).expect("query works"),
+            )
+            .expect("valid json")
+        };
+        assert_eq!(loan_state(deps.as_ref()), LoanState::Empty);
+
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+        assert_eq!(loan_state(deps.as_ref()), LoanState::Funded);
+
+        LENDER
+            .save(deps.as_mut().storage, &None)
+            .expect("lender cleared");
+        assert_eq!(loan_state(deps.as_ref()), LoanState::Pending);
+
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &None)
+            .expect("open interest cleared");
+        LAST_SETTLEMENT
+            .save(
+                deps.as_mut().storage,
+                &SettlementRecord {
+                    outcome: "repaid".to_string(),
+                    lender: lender.into_string(),
+                    liquidator: None,
+                    repaid: coins(115, "uusd"),
+                    settled_at: Timestamp::from_seconds(42),
+                    reference: None,
+                },
+            )
+            .expect("settlement saved");
+        LOAN_SETTLED
+            .save(deps.as_mut().storage, &true)
+            .expect("settled flag saved");
+        assert_eq!(loan_state(deps.as_ref()), LoanState::Settled);
+
+        // Reopening and closing leaves nothing settled, though the last settlement is still kept.
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+        crate::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            crate::msg::ExecuteMsg::OpenInterest(interest),
+        )
+        .expect("interest reopened");
+        assert_eq!(loan_state(deps.as_ref()), LoanState::Pending);
+
+        crate::contract::execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            crate::msg::ExecuteMsg::CloseOpenInterest {},
+        )
+        .expect("interest closed");
+        assert_eq!(loan_state(deps.as_ref()), LoanState::Empty);
+        assert!(LAST_SETTLEMENT
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn reclaimable_collateral_reports_surplus_after_liquidation() {
+        use crate::contract::open_interest::test_helpers::{
+            build_open_interest, sample_coin, setup_active_open_interest,
+        };
+
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let interest = build_open_interest(
+            sample_coin(5, "uusd"),
+            sample_coin(2, "ujuno"),
+            86_400,
+            sample_coin(10, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &interest);
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(25, "uatom"));
+        let reclaimable = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> Coin {
+            let response = query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ReclaimableCollateral {},
+            )
+            .expect("query works");
+            cosmwasm_std::from_json(response).expect("valid json")
+        };
+
+        assert_eq!(reclaimable(&deps), Coin::new(0u128, "uatom"));
+
+        crate::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&lender, &[]),
+            crate::msg::ExecuteMsg::LiquidateOpenInterest {},
+        )
+        .expect("liquidation settles the loan");
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(15, "uatom"));
+
+        assert_eq!(reclaimable(&deps), Coin::new(15u128, "uatom"));
+    }
+
+    #[test]
+    fn reclaimable_collateral_is_zero_without_a_liquidation() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        OPEN_INTEREST.save(deps.as_mut().storage, &None).unwrap();
+        deps.querier.staking.update("ucosm", &[], &[]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(25, "ucosm"));
+
+        let response =
+            query(deps.as_ref(), env, QueryMsg::ReclaimableCollateral {}).expect("query works");
+        let reclaimable: Coin = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(reclaimable, Coin::new(0u128, "ucosm"));
+    }
+
+    #[test]
+    fn reclaimable_collateral_is_zero_while_interest_is_pending() {
+        use crate::contract::open_interest::test_helpers::{build_open_interest, sample_coin};
+
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        LIQUIDATED_COLLATERAL_DENOM
+            .save(deps.as_mut().storage, &"uatom".to_string())
+            .unwrap();
+        let interest = build_open_interest(
+            sample_coin(5, "uusd"),
+            sample_coin(2, "ujuno"),
+            86_400,
+            sample_coin(10, "uatom"),
+        );
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(interest))
+            .unwrap();
+        LENDER.save(deps.as_mut().storage, &None).unwrap();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(25, "uatom"));
+
+        let response =
+            query(deps.as_ref(), env, QueryMsg::ReclaimableCollateral {}).expect("query works");
+        let reclaimable: Coin = cosmwasm_std::from_json(response).expect("valid json");
+
+        assert_eq!(reclaimable, Coin::new(0u128, "uatom"));
+    }
+
+    #[test]
+    fn last_settlement_is_none_until_a_loan_settles() {
+        let mut deps = mock_dependencies();
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::LastSettlement {}).expect("query succeeds");
+        let settlement: Option<SettlementRecord> =
+            cosmwasm_std::from_json(response).expect("valid json");
+        assert!(settlement.is_none());
+
+        let record = SettlementRecord {
+            outcome: "repaid".to_string(),
+            lender: deps.api.addr_make("lender").into_string(),
+            liquidator: None,
+            repaid: coins(115, "uusd"),
+            settled_at: Timestamp::from_seconds(42),
+            reference: None,
+        };
+        LAST_SETTLEMENT
+            .save(deps.as_mut().storage, &record)
+            .expect("settlement saved");
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::LastSettlement {}).expect("query succeeds");
+        let settlement: Option<SettlementRecord> =
+            cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(settlement, Some(record));
+    }
+
+    #[test]
+    fn repayment_due_groups_shared_denoms() {
+        let mut deps = mock_dependencies();
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::RepaymentDue {}).expect("query succeeds");
+        let due: Vec<Coin> = cosmwasm_std::from_json(response).expect("valid json");
+        assert!(due.is_empty());
+
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(100u128, "uusd"),
+            interest_coin: Coin::new(5u128, "uusd"),
+            expiry_duration: 86_400u64,
+            collateral: Coin::new(200u128, "ujuno"),
+            ..Default::default()
+        };
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let response =
+            query(deps.as_ref(), mock_env(), QueryMsg::RepaymentDue {}).expect("query succeeds");
+        let due: Vec<Coin> = cosmwasm_std::from_json(response).expect("valid json");
+        assert_eq!(due, coins(105, "uusd"));
+    }
+
+    #[test]
+    fn can_open_reports_why_terms_would_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        OPEN_INTEREST.save(deps.as_mut().storage, &None).unwrap();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(200, "uatom"));
+        let terms = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+
+        let can_open = |deps: Deps, open_interest: OpenInterest| -> CanOpenResponse {
+            from_json(
+                query(
+                    deps,
+                    env.clone(),
+                    QueryMsg::CanOpen {
+                        open_interest: Box::new(open_interest),
+                    },
+                )
+                .expect("query succeeds"),
+            )
+            .expect("valid json")
+        };
+
+        assert_eq!(
+            can_open(deps.as_ref(), terms.clone()),
+            CanOpenResponse {
+                ok: true,
+                reason: None,
+            }
+        );
+
+        let too_much = OpenInterest {
+            collateral: sample_coin(500, "uatom"),
+            ..terms.clone()
+        };
+        let response = can_open(deps.as_ref(), too_much);
+        assert!(!response.ok);
+        assert!(response.reason.is_some());
+
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(terms.clone()))
+            .unwrap();
+        assert_eq!(
+            can_open(deps.as_ref(), terms).reason,
+            Some(ContractError::OpenInterestAlreadyExists {}.to_string())
+        );
+    }
+
+    #[test]
+    fn collateral_lock_only_counts_the_collateral_denom() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let lock = |deps: Deps, denom: &str| -> Uint256 {
+            from_json(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::CollateralLock {
+                        denom: denom.to_string(),
+                    },
+                )
+                .expect("query succeeds"),
+            )
+            .expect("valid json")
+        };
+
+        assert_eq!(lock(deps.as_ref(), "uatom"), Uint256::zero());
+
+        deps.querier.staking.update("ucosm", &[], &[]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, "uatom"));
+        let interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(15, "uinterest"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(interest))
+            .unwrap();
+
+        assert_eq!(lock(deps.as_ref(), "uatom"), Uint256::from(200u128));
+        assert_eq!(lock(deps.as_ref(), "uusd"), Uint256::zero());
+    }
+
+    #[test]
+    fn loan_history_pages_by_id() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let lender = deps.api.addr_make("lender");
+
+        for id in 2..=101 {
+            let entry = LoanHistoryEntry {
+                id,
+                lender: lender.to_string(),
+                liquidity: sample_coin(100, "uusd"),
+                interest: sample_coin(15, "uinterest"),
+                collateral: sample_coin(500, "ucosm"),
+                outcome: LoanOutcome::Repaid,
+                settled_at: env.block.time,
+                liquidator: None,
+                reference: None,
+            };
+            LOAN_HISTORY
+                .save(deps.as_mut().storage, id, &entry)
+                .expect("history stored");
+        }
+
+        let page: Vec<LoanHistoryEntry> = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::LoanHistory {
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .expect("query succeeds"),
+        )
+        .expect("valid json");
+        assert_eq!(
+            page.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+
+        let page: Vec<LoanHistoryEntry> = from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::LoanHistory {
+                    start_after: Some(100),
+                    limit: None,
+                },
+            )
+            .expect("query succeeds"),
+        )
+        .expect("valid json");
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, 101);
+        assert_eq!(page[0].lender, lender.to_string());
+    }
+
     #[test]
     fn query_info_fails_without_owner() {
         let deps = mock_dependencies();
@@ -0,0 +1,98 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{from_json, DepsMut, Env, Reply, Response, SubMsgResult};
+
+use crate::contract::staking;
+use crate::error::ContractError;
+use crate::state::LAST_HOOK_FAILED;
+
+/// Reply id for `StakingMsg::Delegate` submessages. The payload carries the validator address.
+pub const DELEGATE_REPLY_ID: u64 = 1;
+/// Reply id for hook notifications, which must never revert the operation that sent them.
+pub const HOOK_REPLY_ID: u64 = 2;
+/// Reply id for the last reward claim of a `ClaimSplit`. The payload carries the split terms.
+pub const CLAIM_SPLIT_REPLY_ID: u64 = 3;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        DELEGATE_REPLY_ID => match msg.result {
+            SubMsgResult::Err(reason) => Err(ContractError::DelegationFailed {
+                validator: from_json(&msg.payload)?,
+                reason,
+            }),
+            SubMsgResult::Ok(_) => Ok(Response::new()),
+        },
+        HOOK_REPLY_ID => {
+            LAST_HOOK_FAILED.save(deps.storage, &msg.result.is_err())?;
+            match msg.result {
+                SubMsgResult::Err(reason) => {
+                    Ok(Response::new().add_attribute("hook_failed", reason))
+                }
+                SubMsgResult::Ok(_) => Ok(Response::new()),
+            }
+        }
+        CLAIM_SPLIT_REPLY_ID => staking::claim_split::split_claimed(deps, env, &msg.payload),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::to_json_binary;
+
+    fn failed_reply(id: u64, validator: &str) -> Reply {
+        Reply {
+            id,
+            payload: to_json_binary(validator).expect("payload encodes"),
+            gas_used: 0,
+            result: SubMsgResult::Err("validator is jailed".to_string()),
+        }
+    }
+
+    #[test]
+    fn failed_delegation_surfaces_validator_and_reason() {
+        let mut deps = mock_dependencies();
+        let validator = deps.api.addr_make("validator").into_string();
+
+        let err = reply(
+            deps.as_mut(),
+            mock_env(),
+            failed_reply(DELEGATE_REPLY_ID, &validator),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::DelegationFailed { validator: failed, reason }
+                if failed == validator && reason == "validator is jailed"
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_reply_id() {
+        let mut deps = mock_dependencies();
+
+        let err = reply(deps.as_mut(), mock_env(), failed_reply(99, "validator")).unwrap_err();
+
+        assert!(matches!(err, ContractError::UnknownReplyId { id: 99 }));
+    }
+
+    #[test]
+    fn hook_failures_are_swallowed() {
+        let mut deps = mock_dependencies();
+
+        let response = reply(
+            deps.as_mut(),
+            mock_env(),
+            failed_reply(HOOK_REPLY_ID, "hook"),
+        )
+        .expect("hook failure does not revert");
+
+        assert_eq!(response.attributes[0].key, "hook_failed");
+        assert_eq!(response.attributes[0].value, "validator is jailed");
+        assert!(LAST_HOOK_FAILED.load(deps.as_ref().storage).unwrap());
+    }
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, StdResult};
+use std::collections::BTreeSet;
+
+use crate::{
+    helpers::require_owner_or_lender,
+    state::{
+        LENDER, NEXT_TERM_SNAPSHOT_ID, OPEN_INTEREST, OPEN_INTEREST_EXPIRY, OUTSTANDING_DEBT,
+        TERM_SNAPSHOTS,
+    },
+    types::TermSnapshot,
+    ContractError,
+};
+
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    require_owner_or_lender(&deps, &info)?;
+
+    let open_interest = OPEN_INTEREST
+        .load(deps.storage)?
+        .ok_or(ContractError::NoOpenInterest {})?;
+
+    let denoms: BTreeSet<&str> = [
+        open_interest.liquidity_coin.denom.as_str(),
+        open_interest.interest_coin.denom.as_str(),
+        open_interest.collateral.denom.as_str(),
+    ]
+    .into_iter()
+    .collect();
+    let balances = denoms
+        .into_iter()
+        .map(|denom| {
+            deps.querier
+                .query_balance(env.contract.address.clone(), denom.to_string())
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let id = NEXT_TERM_SNAPSHOT_ID.may_load(deps.storage)?.unwrap_or(0);
+    let snapshot = TermSnapshot {
+        id,
+        taken_at: env.block.time,
+        taken_by: info.sender.to_string(),
+        open_interest,
+        lender: LENDER
+            .may_load(deps.storage)?
+            .flatten()
+            .map(|addr| addr.into_string()),
+        expiry: OPEN_INTEREST_EXPIRY.may_load(deps.storage)?.flatten(),
+        outstanding_debt: OUTSTANDING_DEBT.may_load(deps.storage)?.flatten(),
+        balances,
+    };
+
+    TERM_SNAPSHOTS.save(deps.storage, id, &snapshot)?;
+    NEXT_TERM_SNAPSHOT_ID.save(deps.storage, &(id + 1))?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "snapshot_terms"),
+        attr("snapshot_id", id.to_string()),
+        attr("taken_by", info.sender.as_str()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::open_interest::test_helpers::{
+        build_open_interest, sample_coin, setup, setup_active_open_interest,
+    };
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{coins, Coin, Timestamp};
+
+    #[test]
+    fn rejects_unrelated_senders() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let intruder = deps.api.addr_make("intruder");
+        let err = execute(deps.as_mut(), mock_env(), message_info(&intruder, &[])).unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn requires_active_open_interest() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup(deps.as_mut().storage, &owner);
+
+        let err = execute(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap_err();
+
+        assert!(matches!(err, ContractError::NoOpenInterest {}));
+    }
+
+    #[test]
+    fn lender_records_sequential_snapshots() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let lender = deps.api.addr_make("lender");
+        let open_interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "uusd"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        setup_active_open_interest(deps.as_mut().storage, &owner, &lender, &open_interest);
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(250, "uatom"));
+
+        let response = execute(deps.as_mut(), env.clone(), message_info(&lender, &[]))
+            .expect("snapshot succeeds");
+        assert!(response.messages.is_empty());
+        assert!(response
+            .attributes
+            .contains(&attr("snapshot_id", "0".to_string())));
+
+        execute(deps.as_mut(), env.clone(), message_info(&owner, &[]))
+            .expect("second snapshot succeeds");
+
+        let first = TERM_SNAPSHOTS
+            .load(deps.as_ref().storage, 0)
+            .expect("first snapshot stored");
+        assert_eq!(first.id, 0);
+        assert_eq!(first.taken_at, env.block.time);
+        assert_eq!(first.taken_by, lender.to_string());
+        assert_eq!(first.open_interest, open_interest);
+        assert_eq!(first.lender, Some(lender.to_string()));
+        assert_eq!(first.expiry, Some(Timestamp::from_seconds(0)));
+        assert_eq!(first.outstanding_debt, None);
+        assert_eq!(
+            first.balances,
+            vec![Coin::new(250u128, "uatom"), Coin::new(0u128, "uusd")]
+        );
+
+        let second = TERM_SNAPSHOTS
+            .load(deps.as_ref().storage, 1)
+            .expect("second snapshot stored");
+        assert_eq!(second.taken_by, owner.to_string());
+        assert_eq!(
+            NEXT_TERM_SNAPSHOT_ID.load(deps.as_ref().storage).unwrap(),
+            2
+        );
+    }
+}
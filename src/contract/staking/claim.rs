@@ -1,9 +1,9 @@
 use cosmwasm_std::{DepsMut, DistributionMsg, Env, MessageInfo, Response};
 
-use crate::{helpers::require_owner, ContractError};
+use crate::{helpers::require_owner_or_operator, ContractError};
 
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    require_owner(&deps, &info)?;
+    require_owner_or_operator(&deps, &info)?;
 
     let delegations = deps
         .querier
@@ -0,0 +1,295 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, from_json, to_json_binary, BankMsg, Binary, Coin, DepsMut, DistributionMsg, Env,
+    MessageInfo, Response, StakingMsg, SubMsg, Uint256,
+};
+
+use crate::{
+    contract::reply::CLAIM_SPLIT_REPLY_ID, helpers::require_owner, state::MAX_BPS, ContractError,
+};
+
+use super::delegate::{ensure_collateralized, ensure_within_validator_cap};
+
+/// Carried from the claim to its reply so the split only covers what was just claimed.
+#[cw_serde]
+struct ClaimSplitPayload {
+    validator: String,
+    restake_bps: u16,
+    recipient: String,
+    balance_before: Uint256,
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    validator: String,
+    restake_bps: u16,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let owner = require_owner(&deps, &info)?;
+
+    if restake_bps > MAX_BPS {
+        return Err(ContractError::InvalidRestakeBps { bps: restake_bps });
+    }
+
+    let validator_addr = deps.api.addr_validate(&validator)?.into_string();
+    if deps
+        .querier
+        .query_validator(validator_addr.clone())?
+        .is_none()
+    {
+        return Err(ContractError::ValidatorNotFound {
+            validator: validator_addr,
+        });
+    }
+
+    let recipient = match recipient {
+        Some(addr) => deps.api.addr_validate(&addr)?,
+        None => owner,
+    };
+
+    let denom = deps.querier.query_bonded_denom()?;
+    if restake_bps > 0 {
+        ensure_collateralized(&deps.as_ref(), &env, &denom)?;
+    }
+
+    let delegations = deps
+        .querier
+        .query_all_delegations(env.contract.address.clone())?;
+    let Some((last, rest)) = delegations.split_last() else {
+        return Err(ContractError::NoDelegations {});
+    };
+
+    let balance_before = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom)?
+        .amount;
+    let payload = to_json_binary(&ClaimSplitPayload {
+        validator: validator_addr.clone(),
+        restake_bps,
+        recipient: recipient.into_string(),
+        balance_before,
+    })?;
+
+    let claims = rest
+        .iter()
+        .map(|delegation| DistributionMsg::WithdrawDelegatorReward {
+            validator: delegation.validator.clone(),
+        });
+    // Only the final claim replies, once every reward has landed in the vault.
+    let last_claim = SubMsg::reply_on_success(
+        DistributionMsg::WithdrawDelegatorReward {
+            validator: last.validator.clone(),
+        },
+        CLAIM_SPLIT_REPLY_ID,
+    )
+    .with_payload(payload);
+
+    Ok(Response::new()
+        .add_messages(claims)
+        .add_submessage(last_claim)
+        .add_attributes([
+            attr("action", "claim_split"),
+            attr("validator", validator_addr),
+            attr("restake_bps", restake_bps.to_string()),
+            attr("validator_count", delegations.len().to_string()),
+        ]))
+}
+
+/// Splits the rewards claimed by [`execute`] between restaking and the recipient.
+pub(crate) fn split_claimed(
+    deps: DepsMut,
+    env: Env,
+    payload: &Binary,
+) -> Result<Response, ContractError> {
+    let payload: ClaimSplitPayload = from_json(payload)?;
+    let denom = deps.querier.query_bonded_denom()?;
+    let balance_after = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.clone())?
+        .amount;
+
+    let claimed = balance_after.saturating_sub(payload.balance_before);
+    let restaked = claimed.multiply_ratio(payload.restake_bps, MAX_BPS);
+    let withdrawn = claimed - restaked;
+
+    let mut response = Response::new().add_attributes([
+        attr("action", "claim_split_settled"),
+        attr("restaked", restaked.to_string()),
+        attr("withdrawn", withdrawn.to_string()),
+    ]);
+
+    if !restaked.is_zero() {
+        ensure_within_validator_cap(&deps.as_ref(), &env, &payload.validator, restaked)?;
+        response = response.add_message(StakingMsg::Delegate {
+            validator: payload.validator,
+            amount: Coin::new(restaked, denom.clone()),
+        });
+    }
+    if !withdrawn.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: payload.recipient,
+            amount: vec![Coin::new(withdrawn, denom)],
+        });
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::update_config;
+    use crate::state::OWNER;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{coins, CosmosMsg, Decimal, FullDelegation, ReplyOn, Uint128, Validator};
+
+    #[test]
+    fn rejects_restake_share_above_full() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER
+            .save(deps.as_mut().storage, &owner)
+            .expect("owner stored");
+
+        let validator = deps.api.addr_make("validator").into_string();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            validator,
+            10_001,
+            None,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InvalidRestakeBps { bps: 10_001 }
+        ));
+    }
+
+    #[test]
+    fn replies_after_the_last_claim_and_splits_the_difference() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER
+            .save(deps.as_mut().storage, &owner)
+            .expect("owner stored");
+
+        let env = mock_env();
+        let validator = Validator::create(
+            deps.api.addr_make("validator").into_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        );
+        let delegation = FullDelegation::create(
+            env.contract.address.clone(),
+            validator.address.clone(),
+            Coin::new(500u128, "ucosm"),
+            Coin::new(500u128, "ucosm"),
+            vec![],
+        );
+        deps.querier
+            .staking
+            .update("ucosm", &[validator.clone()], &[delegation]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(100, "ucosm"));
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            validator.address.clone(),
+            2_500,
+            None,
+        )
+        .expect("claim split succeeds");
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].id, CLAIM_SPLIT_REPLY_ID);
+        assert_eq!(response.messages[0].reply_on, ReplyOn::Success);
+
+        // Rewards of 41 land before the reply; only they are split.
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(141, "ucosm"));
+        let response = split_claimed(deps.as_mut(), env, &response.messages[0].payload)
+            .expect("split succeeds");
+
+        assert!(response.attributes.contains(&attr("restaked", "10")));
+        assert!(response.attributes.contains(&attr("withdrawn", "31")));
+        let messages: Vec<CosmosMsg> = response.messages.into_iter().map(|m| m.msg).collect();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Staking(StakingMsg::Delegate {
+                    validator: validator.address,
+                    amount: Coin::new(10u128, "ucosm"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: owner.into_string(),
+                    amount: vec![Coin::new(31u128, "ucosm")],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn restake_respects_validator_cap() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        OWNER
+            .save(deps.as_mut().storage, &owner)
+            .expect("owner stored");
+        update_config(deps.as_mut().storage, |config| {
+            config.max_per_validator = Some(Uint128::new(505))
+        })
+        .expect("cap stored");
+
+        let env = mock_env();
+        let validator = Validator::create(
+            deps.api.addr_make("validator").into_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        );
+        let delegation = FullDelegation::create(
+            env.contract.address.clone(),
+            validator.address.clone(),
+            Coin::new(500u128, "ucosm"),
+            Coin::new(500u128, "ucosm"),
+            vec![],
+        );
+        deps.querier
+            .staking
+            .update("ucosm", &[validator.clone()], &[delegation]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(100, "ucosm"));
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            validator.address.clone(),
+            10_000,
+            None,
+        )
+        .expect("claim split succeeds");
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(110, "ucosm"));
+        let err = split_claimed(deps.as_mut(), env, &response.messages[0].payload).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::ValidatorDelegationCapExceeded { validator: v, .. }
+                if v == validator.address
+        ));
+    }
+}
@@ -1,10 +1,14 @@
 use cosmwasm_std::{
-    attr, Coin, Deps, DepsMut, Env, MessageInfo, Response, StakingMsg, Uint128, Uint256,
+    attr, to_json_binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StakingMsg,
+    StdError, SubMsg, Uint128, Uint256,
 };
-use std::convert::TryFrom;
 
 use crate::{
-    helpers::require_owner,
+    contract::reply::DELEGATE_REPLY_ID,
+    helpers::{
+        load_config, minimum_collateral_lock_for_denom, require_owner_or_operator,
+        unclaimed_refunds_for_denom,
+    },
     state::{LENDER, OPEN_INTEREST, OUTSTANDING_DEBT},
     ContractError,
 };
@@ -15,8 +19,9 @@ pub fn execute(
     info: MessageInfo,
     validator: String,
     amount: Uint128,
+    max_commission: Option<Decimal>,
 ) -> Result<Response, ContractError> {
-    require_owner(&deps, &info)?;
+    require_owner_or_operator(&deps, &info)?;
 
     if amount.is_zero() {
         return Err(ContractError::InvalidDelegationAmount {});
@@ -26,7 +31,8 @@ pub fn execute(
     let denom = deps.querier.query_bonded_denom()?;
     let requested = Uint256::from(amount);
 
-    let reserved_debt = reserved_debt_for_denom(&deps.as_ref(), &denom)?;
+    ensure_collateralized(&deps.as_ref(), &env, &denom)?;
+    let reserved_debt = reserved_debt_for_denom(&deps.as_ref(), &env, &denom)?;
 
     let balance = deps
         .querier
@@ -36,28 +42,42 @@ pub fn execute(
     if available_after_reserved < requested {
         return Err(ContractError::InsufficientBalance {
             denom: denom.clone(),
-            available: Uint128::try_from(available_after_reserved).expect("available fits in u128"),
-            requested: Uint128::try_from(requested).expect("requested fits in u128"),
+            available: available_after_reserved,
+            requested,
         });
     }
 
-    if deps
-        .querier
-        .query_validator(validator_addr.clone())?
-        .is_none()
-    {
+    let Some(validator_info) = deps.querier.query_validator(validator_addr.clone())? else {
         return Err(ContractError::ValidatorNotFound {
             validator: validator_addr,
         });
+    };
+    if let Some(maximum) = max_commission {
+        if validator_info.commission > maximum {
+            return Err(ContractError::ValidatorCommissionTooHigh {
+                validator: validator_addr,
+                commission: validator_info.commission,
+                maximum,
+            });
+        }
     }
 
+    ensure_within_validator_cap(&deps.as_ref(), &env, &validator_addr, requested)?;
+
     let delegate_coin = Coin::new(requested, denom.clone());
 
-    Ok(Response::new()
-        .add_message(StakingMsg::Delegate {
+    // A failed delegation is reported through the reply handler with the validator attached.
+    let delegate_msg = SubMsg::reply_on_error(
+        StakingMsg::Delegate {
             validator: validator_addr.clone(),
             amount: delegate_coin.clone(),
-        })
+        },
+        DELEGATE_REPLY_ID,
+    )
+    .with_payload(to_json_binary(&validator_addr)?);
+
+    Ok(Response::new()
+        .add_submessage(delegate_msg)
         .add_attributes([
             attr("action", "delegate"),
             attr("validator", validator_addr),
@@ -66,15 +86,114 @@ pub fn execute(
         ]))
 }
 
+/// Blocks delegating collateral while an active loan's staked coverage plus liquid balance is
+/// already short of the collateral requirement, e.g. after a slash.
+pub(crate) fn ensure_collateralized(
+    deps: &Deps,
+    env: &Env,
+    denom: &str,
+) -> Result<(), ContractError> {
+    if LENDER.may_load(deps.storage)?.flatten().is_none() {
+        return Ok(());
+    }
+
+    let open_interest = OPEN_INTEREST.may_load(deps.storage)?.flatten();
+    let required_lock =
+        minimum_collateral_lock_for_denom(deps, env, denom, open_interest.as_ref())?;
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.to_string())?
+        .amount;
+
+    if balance < required_lock {
+        return Err(ContractError::CannotDelegateWhileUndercollateralized {
+            denom: denom.to_string(),
+            shortfall: required_lock - balance,
+        });
+    }
+
+    Ok(())
+}
+
+/// Rejects delegating `additional` to `validator` when the vault's stake with it would pass the
+/// configured `max_per_validator`.
+pub(crate) fn ensure_within_validator_cap(
+    deps: &Deps,
+    env: &Env,
+    validator: &str,
+    additional: Uint256,
+) -> Result<(), ContractError> {
+    let Some(cap) = load_config(deps.storage)?.max_per_validator else {
+        return Ok(());
+    };
+
+    let delegated = deps
+        .querier
+        .query_delegation(env.contract.address.clone(), validator.to_string())?
+        .map(|delegation| delegation.amount.amount)
+        .unwrap_or_default();
+    let total = delegated.checked_add(additional).map_err(StdError::from)?;
+
+    if total > Uint256::from(cap) {
+        return Err(ContractError::ValidatorDelegationCapExceeded {
+            validator: validator.to_string(),
+            cap,
+        });
+    }
+
+    Ok(())
+}
+
+pub(crate) fn reserved_debt_for_denom(
+    deps: &Deps,
+    env: &Env,
+    denom: &str,
+) -> Result<Uint256, ContractError> {
+    let unclaimed_refunds = unclaimed_refunds_for_denom(deps, denom)?;
+
+    if let Some(debt) = OUTSTANDING_DEBT.load(deps.storage)? {
+        if debt.denom == denom {
+            let has_open_interest = OPEN_INTEREST.load(deps.storage)?.is_some();
+            let lender_exists = LENDER.load(deps.storage)?.is_some();
+
+            if has_open_interest && !lender_exists {
+                // Reserve the outstanding debt only for counter-offer escrow (open interest without lender).
+                return Ok(debt
+                    .amount
+                    .checked_add(unclaimed_refunds)
+                    .map_err(StdError::from)?);
+            }
+
+            return Err(ContractError::OutstandingDebt { amount: debt });
+        }
+    }
+
+    // A funded loan keeps its collateral liquid unless staked funds already cover it.
+    let collateral_lock = match LENDER.may_load(deps.storage)?.flatten() {
+        Some(_) => minimum_collateral_lock_for_denom(
+            deps,
+            env,
+            denom,
+            OPEN_INTEREST.may_load(deps.storage)?.flatten().as_ref(),
+        )?,
+        None => Uint256::zero(),
+    };
+
+    Ok(unclaimed_refunds
+        .checked_add(collateral_lock)
+        .map_err(StdError::from)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        helpers::update_config,
         state::{LENDER, OPEN_INTEREST, OUTSTANDING_DEBT, OWNER},
         types::OpenInterest,
     };
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-    use cosmwasm_std::{coins, Addr, Coin, Decimal, Storage, Uint128, Validator};
+    use cosmwasm_std::{coins, Addr, Coin, Decimal, FullDelegation, Storage, Uint128, Validator};
 
     fn setup_owner_and_zero_debt(storage: &mut dyn Storage, owner: &Addr) {
         OWNER.save(storage, owner).expect("owner stored");
@@ -96,7 +215,7 @@ mod tests {
         let info = message_info(&deps.api.addr_make("intruder"), &[]);
         let amount = Uint128::new(10);
         let validator = deps.api.addr_make("validator").into_string();
-        let err = execute(deps.as_mut(), mock_env(), info, validator, amount).unwrap_err();
+        let err = execute(deps.as_mut(), mock_env(), info, validator, amount, None).unwrap_err();
 
         assert!(matches!(err, ContractError::Unauthorized {}));
     }
@@ -109,7 +228,15 @@ mod tests {
 
         let info = message_info(&owner, &[]);
         let validator = deps.api.addr_make("validator").into_string();
-        let err = execute(deps.as_mut(), mock_env(), info, validator, Uint128::zero()).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            validator,
+            Uint128::zero(),
+            None,
+        )
+        .unwrap_err();
 
         assert!(matches!(err, ContractError::InvalidDelegationAmount {}));
     }
@@ -129,7 +256,7 @@ mod tests {
         let amount = Uint128::new(100);
 
         let validator = deps.api.addr_make("validator").into_string();
-        let err = execute(deps.as_mut(), mock_env(), info, validator, amount).unwrap_err();
+        let err = execute(deps.as_mut(), mock_env(), info, validator, amount, None).unwrap_err();
 
         assert!(matches!(err, ContractError::InsufficientBalance { .. }));
     }
@@ -148,7 +275,7 @@ mod tests {
 
         let info = message_info(&owner, &[]);
         let validator = deps.api.addr_make("validator").into_string();
-        let err = execute(deps.as_mut(), env, info, validator, Uint128::new(50)).unwrap_err();
+        let err = execute(deps.as_mut(), env, info, validator, Uint128::new(50), None).unwrap_err();
 
         assert!(matches!(err, ContractError::ValidatorNotFound { .. }));
     }
@@ -167,8 +294,15 @@ mod tests {
 
         let info = message_info(&owner, &[]);
         let validator = deps.api.addr_make("validator").into_string();
-        let err =
-            execute(deps.as_mut(), mock_env(), info, validator, Uint128::new(50)).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            validator,
+            Uint128::new(50),
+            None,
+        )
+        .unwrap_err();
 
         assert!(matches!(
             err,
@@ -208,8 +342,15 @@ mod tests {
         let info = message_info(&owner, &[]);
         let amount = Uint128::new(200);
 
-        let response =
-            execute(deps.as_mut(), env, info, validator_addr.clone(), amount).expect("succeeds");
+        let response = execute(
+            deps.as_mut(),
+            env,
+            info,
+            validator_addr.clone(),
+            amount,
+            None,
+        )
+        .expect("succeeds");
 
         assert_eq!(response.messages.len(), 1);
     }
@@ -226,6 +367,7 @@ mod tests {
             interest_coin: Coin::new(20u128, "ujuno"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(200u128, "uatom"),
+            ..Default::default()
         };
 
         OPEN_INTEREST
@@ -255,8 +397,15 @@ mod tests {
         let info = message_info(&owner, &[]);
         let amount = Uint128::new(200);
 
-        let response =
-            execute(deps.as_mut(), env, info, validator_addr.clone(), amount).expect("succeeds");
+        let response = execute(
+            deps.as_mut(),
+            env,
+            info,
+            validator_addr.clone(),
+            amount,
+            None,
+        )
+        .expect("succeeds");
 
         assert_eq!(response.messages.len(), 1);
     }
@@ -273,6 +422,7 @@ mod tests {
             interest_coin: Coin::new(20u128, "ujuno"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(200u128, "uatom"),
+            ..Default::default()
         };
 
         OPEN_INTEREST
@@ -302,14 +452,14 @@ mod tests {
         let info = message_info(&owner, &[]);
         let amount = Uint128::new(100);
 
-        let err = execute(deps.as_mut(), env, info, validator_addr, amount).unwrap_err();
+        let err = execute(deps.as_mut(), env, info, validator_addr, amount, None).unwrap_err();
 
         assert!(matches!(
             err,
             ContractError::InsufficientBalance { denom, available, requested }
                 if denom == "ucosm"
-                    && available == Uint128::from(50u128)
-                    && requested == Uint128::from(100u128)
+                    && available == Uint256::from(50u128)
+                    && requested == Uint256::from(100u128)
         ));
     }
 
@@ -340,8 +490,15 @@ mod tests {
         let info = message_info(&owner, &[]);
         let amount = Uint128::new(150);
 
-        let response = execute(deps.as_mut(), env, info, validator_addr.clone(), amount)
-            .expect("delegation succeeds");
+        let response = execute(
+            deps.as_mut(),
+            env,
+            info,
+            validator_addr.clone(),
+            amount,
+            None,
+        )
+        .expect("delegation succeeds");
 
         assert_eq!(response.messages.len(), 1);
         let msg = response.messages[0].clone().msg;
@@ -356,22 +513,242 @@ mod tests {
             _ => panic!("unexpected message"),
         }
     }
-}
 
-fn reserved_debt_for_denom(deps: &Deps, denom: &str) -> Result<Uint256, ContractError> {
-    if let Some(debt) = OUTSTANDING_DEBT.load(deps.storage)? {
-        if debt.denom == denom {
-            let has_open_interest = OPEN_INTEREST.load(deps.storage)?.is_some();
-            let lender_exists = LENDER.load(deps.storage)?.is_some();
+    #[test]
+    fn reserves_uncovered_collateral_while_lender_is_set() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
 
-            if has_open_interest && !lender_exists {
-                // Reserve the outstanding debt only for counter-offer escrow (open interest without lender).
-                return Ok(debt.amount);
-            }
+        let env = mock_env();
+        let validator = Validator::create(
+            deps.api.addr_make("validator").into_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        );
+        deps.querier.staking.update(
+            "ucosm",
+            &[validator.clone()],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator.address.clone(),
+                Coin::new(100u128, "ucosm"),
+                Coin::new(100u128, "ucosm"),
+                vec![],
+            )],
+        );
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(500, "ucosm"));
 
-            return Err(ContractError::OutstandingDebt { amount: debt });
-        }
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(100u128, "uusd"),
+            interest_coin: Coin::new(5u128, "uusd"),
+            expiry_duration: 86_400,
+            collateral: Coin::new(500u128, "ucosm"),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest))
+            .expect("open interest stored");
+        let lender = deps.api.addr_make("lender");
+        LENDER
+            .save(deps.as_mut().storage, &Some(lender))
+            .expect("lender stored");
+
+        // 500 collateral minus 100 already staked leaves 400 locked out of the 500 balance.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            validator.address.clone(),
+            Uint128::new(101),
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientBalance { available, .. } if available == Uint256::new(100)
+        ));
+
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            validator.address,
+            Uint128::new(100),
+            None,
+        )
+        .expect("uncommitted balance can be delegated");
+    }
+
+    #[test]
+    fn blocks_delegation_after_slash_leaves_collateral_short() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let validator = Validator::create(
+            deps.api.addr_make("validator").into_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        );
+        // 200 was delegated but a slash left only 100 bonded.
+        deps.querier.staking.update(
+            "ucosm",
+            &[validator.clone()],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator.address.clone(),
+                Coin::new(100u128, "ucosm"),
+                Coin::new(100u128, "ucosm"),
+                vec![],
+            )],
+        );
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, "ucosm"));
+
+        let open_interest = OpenInterest {
+            liquidity_coin: Coin::new(100u128, "uusd"),
+            interest_coin: Coin::new(5u128, "uusd"),
+            expiry_duration: 86_400,
+            collateral: Coin::new(500u128, "ucosm"),
+            ..Default::default()
+        };
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest))
+            .expect("open interest stored");
+        let lender = deps.api.addr_make("lender");
+        LENDER
+            .save(deps.as_mut().storage, &Some(lender))
+            .expect("lender stored");
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            validator.address,
+            Uint128::new(10),
+            None,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::CannotDelegateWhileUndercollateralized { shortfall, .. }
+                if shortfall == Uint256::from(100u128)
+        ));
+    }
+
+    #[test]
+    fn rejects_validator_above_max_commission() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let denom = "ucosm";
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, denom));
+        let validator_addr = deps.api.addr_make("validator").into_string();
+        deps.querier.staking.update(
+            denom,
+            &[Validator::create(
+                validator_addr.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[],
+        );
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            validator_addr.clone(),
+            Uint128::new(100),
+            Some(Decimal::percent(4)),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ValidatorCommissionTooHigh { commission, maximum, .. }
+                if commission == Decimal::percent(5) && maximum == Decimal::percent(4)
+        ));
+
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            validator_addr,
+            Uint128::new(100),
+            Some(Decimal::percent(5)),
+        )
+        .expect("commission at the maximum is accepted");
     }
 
-    Ok(Uint256::zero())
+    #[test]
+    fn rejects_stake_above_per_validator_cap() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        update_config(deps.as_mut().storage, |config| {
+            config.max_per_validator = Some(Uint128::new(150))
+        })
+        .expect("cap stored");
+
+        let env = mock_env();
+        let denom = "ucosm";
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, denom));
+        let validator_addr = deps.api.addr_make("validator").into_string();
+        deps.querier.staking.update(
+            denom,
+            &[Validator::create(
+                validator_addr.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator_addr.clone(),
+                Coin::new(100u128, denom),
+                Coin::new(100u128, denom),
+                vec![],
+            )],
+        );
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            validator_addr.clone(),
+            Uint128::new(60),
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ValidatorDelegationCapExceeded { validator, cap }
+                if validator == validator_addr && cap == Uint128::new(150)
+        ));
+
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            validator_addr,
+            Uint128::new(50),
+            None,
+        )
+        .expect("stake at the cap is accepted");
+    }
 }
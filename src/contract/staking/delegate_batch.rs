@@ -0,0 +1,283 @@
+use cosmwasm_std::{attr, Coin, DepsMut, Env, MessageInfo, Response, StakingMsg, Uint128, Uint256};
+use std::collections::BTreeMap;
+
+use crate::{helpers::require_owner_or_operator, ContractError};
+
+use super::delegate::{
+    ensure_collateralized, ensure_within_validator_cap, reserved_debt_for_denom,
+};
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegations: Vec<(String, Uint128)>,
+) -> Result<Response, ContractError> {
+    require_owner_or_operator(&deps, &info)?;
+
+    if delegations.is_empty() || delegations.iter().any(|(_, amount)| amount.is_zero()) {
+        return Err(ContractError::InvalidDelegationAmount {});
+    }
+
+    let mut validated = Vec::with_capacity(delegations.len());
+    // Entries may repeat a validator, so the cap is checked against the running total.
+    let mut requested_by_validator: BTreeMap<String, Uint256> = BTreeMap::new();
+    let mut total = Uint256::zero();
+    for (validator, amount) in delegations {
+        let validator_addr = deps.api.addr_validate(&validator)?.into_string();
+        if deps
+            .querier
+            .query_validator(validator_addr.clone())?
+            .is_none()
+        {
+            return Err(ContractError::ValidatorNotFound {
+                validator: validator_addr,
+            });
+        }
+
+        let requested = requested_by_validator
+            .entry(validator_addr.clone())
+            .or_default();
+        *requested += Uint256::from(amount);
+        ensure_within_validator_cap(&deps.as_ref(), &env, &validator_addr, *requested)?;
+
+        total += Uint256::from(amount);
+        validated.push((validator_addr, amount));
+    }
+
+    let denom = deps.querier.query_bonded_denom()?;
+    ensure_collateralized(&deps.as_ref(), &env, &denom)?;
+    let reserved_debt = reserved_debt_for_denom(&deps.as_ref(), &env, &denom)?;
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.clone())?;
+    let available_after_reserved = balance.amount.saturating_sub(reserved_debt);
+
+    if available_after_reserved < total {
+        return Err(ContractError::InsufficientBalance {
+            denom: denom.clone(),
+            available: available_after_reserved,
+            requested: total,
+        });
+    }
+
+    let messages = validated
+        .iter()
+        .map(|(validator, amount)| StakingMsg::Delegate {
+            validator: validator.clone(),
+            amount: Coin::new(*amount, denom.clone()),
+        });
+
+    Ok(Response::new().add_messages(messages).add_attributes([
+        attr("action", "delegate_batch"),
+        attr("denom", denom),
+        attr("delegations", validated.len().to_string()),
+        attr("total_delegated", total.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::update_config;
+    use crate::state::{LENDER, OPEN_INTEREST, OUTSTANDING_DEBT, OWNER};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+    use cosmwasm_std::{coins, Addr, CosmosMsg, Decimal, Storage, Validator};
+
+    fn setup_owner_and_zero_debt(storage: &mut dyn Storage, owner: &Addr) {
+        OWNER.save(storage, owner).expect("owner stored");
+        OUTSTANDING_DEBT
+            .save(storage, &None)
+            .expect("zero debt stored");
+        LENDER.save(storage, &None).expect("lender cleared");
+        OPEN_INTEREST
+            .save(storage, &None)
+            .expect("open interest cleared");
+    }
+
+    fn validator(api: &MockApi, label: &str) -> Validator {
+        Validator::create(
+            api.addr_make(label).into_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        )
+    }
+
+    #[test]
+    fn fails_for_unauthorized_sender() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let info = message_info(&deps.api.addr_make("intruder"), &[]);
+        let delegations = vec![(
+            deps.api.addr_make("validator").into_string(),
+            Uint128::new(10),
+        )];
+        let err = execute(deps.as_mut(), mock_env(), info, delegations).unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn fails_when_any_entry_is_zero() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let delegations = vec![
+            (
+                deps.api.addr_make("validator").into_string(),
+                Uint128::new(10),
+            ),
+            (
+                deps.api.addr_make("validator-two").into_string(),
+                Uint128::zero(),
+            ),
+        ];
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            delegations,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::InvalidDelegationAmount {}));
+    }
+
+    #[test]
+    fn reports_first_missing_validator() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let known = validator(&deps.api, "validator");
+        deps.querier.staking.update("ucosm", &[known.clone()], &[]);
+
+        let first_missing = deps.api.addr_make("missing-one").into_string();
+        let delegations = vec![
+            (known.address.clone(), Uint128::new(10)),
+            (first_missing.clone(), Uint128::new(10)),
+            (
+                deps.api.addr_make("missing-two").into_string(),
+                Uint128::new(10),
+            ),
+        ];
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            delegations,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::ValidatorNotFound { validator } if validator == first_missing
+        ));
+    }
+
+    #[test]
+    fn checks_total_against_available_balance() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        deps.querier
+            .staking
+            .update("ucosm", &[first.clone(), second.clone()], &[]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(150, "ucosm"));
+
+        let delegations = vec![
+            (first.address, Uint128::new(100)),
+            (second.address, Uint128::new(100)),
+        ];
+        let err = execute(deps.as_mut(), env, message_info(&owner, &[]), delegations).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InsufficientBalance { available, requested, .. }
+                if available == Uint256::new(150) && requested == Uint256::new(200)
+        ));
+    }
+
+    #[test]
+    fn creates_one_delegate_message_per_entry() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        deps.querier
+            .staking
+            .update("ucosm", &[first.clone(), second.clone()], &[]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, "ucosm"));
+
+        let delegations = vec![
+            (first.address.clone(), Uint128::new(100)),
+            (second.address.clone(), Uint128::new(175)),
+        ];
+        let response = execute(deps.as_mut(), env, message_info(&owner, &[]), delegations)
+            .expect("batch delegation succeeds");
+
+        let messages: Vec<CosmosMsg> = response.messages.into_iter().map(|m| m.msg).collect();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Staking(StakingMsg::Delegate {
+                    validator: first.address,
+                    amount: Coin::new(100u128, "ucosm"),
+                }),
+                CosmosMsg::Staking(StakingMsg::Delegate {
+                    validator: second.address,
+                    amount: Coin::new(175u128, "ucosm"),
+                }),
+            ]
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("total_delegated", "275")));
+    }
+
+    #[test]
+    fn checks_repeated_validator_against_cap() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        update_config(deps.as_mut().storage, |config| {
+            config.max_per_validator = Some(Uint128::new(150))
+        })
+        .expect("cap stored");
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        deps.querier.staking.update("ucosm", &[first.clone()], &[]);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, "ucosm"));
+
+        let delegations = vec![
+            (first.address.clone(), Uint128::new(100)),
+            (first.address.clone(), Uint128::new(100)),
+        ];
+        let err = execute(deps.as_mut(), env, message_info(&owner, &[]), delegations).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::ValidatorDelegationCapExceeded { validator, .. }
+                if validator == first.address
+        ));
+    }
+}
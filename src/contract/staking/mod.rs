@@ -1,4 +1,7 @@
 pub mod claim;
+pub mod claim_split;
 pub mod delegate;
+pub mod delegate_batch;
 pub mod redelegate;
+pub mod redelegate_batch;
 pub mod undelegate;
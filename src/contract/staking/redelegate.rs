@@ -1,11 +1,13 @@
 use cosmwasm_std::{attr, Coin, DepsMut, Env, MessageInfo, Response, StakingMsg, Uint128, Uint256};
 
 use crate::{
-    helpers::require_owner,
+    helpers::require_owner_or_operator,
     state::{LENDER, OUTSTANDING_DEBT},
     ContractError,
 };
 
+use super::delegate::ensure_within_validator_cap;
+
 pub fn execute(
     deps: DepsMut,
     env: Env,
@@ -14,7 +16,7 @@ pub fn execute(
     dst_validator: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    require_owner(&deps, &info)?;
+    require_owner_or_operator(&deps, &info)?;
 
     if amount.is_zero() {
         return Err(ContractError::InvalidRedelegationAmount {});
@@ -24,7 +26,9 @@ pub fn execute(
     let dst_addr = deps.api.addr_validate(&dst_validator)?.into_string();
 
     if src_addr == dst_addr {
-        return Err(ContractError::RedelegateToSameValidator {});
+        return Err(ContractError::RedelegateToSameValidator {
+            validator: src_addr,
+        });
     }
 
     let denom = deps.querier.query_bonded_denom()?;
@@ -61,6 +65,7 @@ pub fn execute(
             validator: dst_addr.clone(),
         });
     }
+    ensure_within_validator_cap(&deps.as_ref(), &env, &dst_addr, requested)?;
 
     let redelegate_coin = Coin::new(requested, denom.clone());
 
@@ -82,6 +87,7 @@ pub fn execute(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::helpers::update_config;
     use crate::state::{LENDER, OUTSTANDING_DEBT, OWNER};
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
     use cosmwasm_std::{Addr, Coin, Decimal, FullDelegation, Storage, Uint128, Uint256, Validator};
@@ -327,7 +333,10 @@ mod tests {
         )
         .unwrap_err();
 
-        assert!(matches!(err, ContractError::RedelegateToSameValidator {}));
+        assert!(matches!(
+            err,
+            ContractError::RedelegateToSameValidator { .. }
+        ));
     }
 
     #[test]
@@ -523,4 +532,69 @@ mod tests {
             _ => panic!("unexpected message"),
         }
     }
+
+    #[test]
+    fn fails_when_destination_would_pass_cap() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        update_config(deps.as_mut().storage, |config| {
+            config.max_per_validator = Some(Uint128::new(100))
+        })
+        .expect("cap stored");
+
+        let env = mock_env();
+        let contract_addr = env.contract.address.clone();
+        let src_validator_addr = deps.api.addr_make("validator").into_string();
+        let dst_validator_addr = deps.api.addr_make("validator-two").into_string();
+        let validators = [&src_validator_addr, &dst_validator_addr].map(|address| {
+            Validator::create(
+                address.clone(),
+                Decimal::percent(5),
+                Decimal::percent(10),
+                Decimal::percent(1),
+            )
+        });
+        let delegations = [
+            (&src_validator_addr, 300u128),
+            (&dst_validator_addr, 80u128),
+        ]
+        .map(|(validator, amount)| {
+            FullDelegation::create(
+                contract_addr.clone(),
+                validator.clone(),
+                Coin::new(amount, "ucosm"),
+                Coin::new(amount, "ucosm"),
+                vec![],
+            )
+        });
+        deps.querier
+            .staking
+            .update("ucosm", &validators, &delegations);
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            src_validator_addr.clone(),
+            dst_validator_addr.clone(),
+            Uint128::new(21),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ValidatorDelegationCapExceeded { validator, .. }
+                if validator == dst_validator_addr
+        ));
+
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            src_validator_addr,
+            dst_validator_addr,
+            Uint128::new(20),
+        )
+        .expect("redelegation up to the cap succeeds");
+    }
 }
@@ -0,0 +1,385 @@
+use cosmwasm_std::{attr, Coin, DepsMut, Env, MessageInfo, Response, StakingMsg, Uint128, Uint256};
+use std::collections::BTreeMap;
+
+use crate::{
+    helpers::require_owner_or_operator,
+    state::{LENDER, OUTSTANDING_DEBT},
+    ContractError,
+};
+
+use super::delegate::ensure_within_validator_cap;
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    moves: Vec<(String, String, Uint128)>,
+) -> Result<Response, ContractError> {
+    require_owner_or_operator(&deps, &info)?;
+
+    if moves.is_empty() || moves.iter().any(|(_, _, amount)| amount.is_zero()) {
+        return Err(ContractError::InvalidRedelegationAmount {});
+    }
+
+    let denom = deps.querier.query_bonded_denom()?;
+    let lender_present = matches!(LENDER.may_load(deps.storage)?, Some(Some(_)));
+
+    if lender_present {
+        match OUTSTANDING_DEBT.load(deps.storage)? {
+            Some(debt) if debt.denom == denom => {
+                return Err(ContractError::OutstandingDebt { amount: debt });
+            }
+            _ => {}
+        }
+    }
+
+    let mut validated = Vec::with_capacity(moves.len());
+    // Several moves may draw from the same source, so stake is checked against the running total.
+    let mut requested_by_src: BTreeMap<String, Uint256> = BTreeMap::new();
+    let mut incoming_by_dst: BTreeMap<String, Uint256> = BTreeMap::new();
+    let mut total = Uint256::zero();
+    for (src_validator, dst_validator, amount) in moves {
+        let src_addr = deps.api.addr_validate(&src_validator)?.into_string();
+        let dst_addr = deps.api.addr_validate(&dst_validator)?.into_string();
+
+        if src_addr == dst_addr {
+            return Err(ContractError::RedelegateToSameValidator {
+                validator: src_addr,
+            });
+        }
+
+        let delegation = deps
+            .querier
+            .query_delegation(env.contract.address.clone(), src_addr.clone())?
+            .ok_or_else(|| ContractError::DelegationNotFound {
+                validator: src_addr.clone(),
+            })?;
+
+        let requested = requested_by_src.entry(src_addr.clone()).or_default();
+        *requested += Uint256::from(amount);
+
+        if delegation.amount.amount < *requested {
+            return Err(ContractError::InsufficientDelegatedBalance {
+                validator: src_addr,
+                delegated: delegation.amount.amount,
+                requested: *requested,
+            });
+        }
+
+        // Stake received through a recent redelegation cannot move again until it matures.
+        if delegation.can_redelegate.amount < *requested {
+            return Err(ContractError::RedelegationCooldown {
+                validator: src_addr,
+                redelegatable: delegation.can_redelegate.amount,
+                requested: *requested,
+            });
+        }
+
+        if deps.querier.query_validator(dst_addr.clone())?.is_none() {
+            return Err(ContractError::ValidatorNotFound {
+                validator: dst_addr,
+            });
+        }
+
+        let incoming = incoming_by_dst.entry(dst_addr.clone()).or_default();
+        *incoming += Uint256::from(amount);
+        ensure_within_validator_cap(&deps.as_ref(), &env, &dst_addr, *incoming)?;
+
+        total += Uint256::from(amount);
+        validated.push((src_addr, dst_addr, amount));
+    }
+
+    let messages = validated
+        .iter()
+        .map(|(src, dst, amount)| StakingMsg::Redelegate {
+            src_validator: src.clone(),
+            dst_validator: dst.clone(),
+            amount: Coin::new(*amount, denom.clone()),
+        });
+
+    Ok(Response::new().add_messages(messages).add_attributes([
+        attr("action", "redelegate_batch"),
+        attr("denom", denom),
+        attr("redelegations", validated.len().to_string()),
+        attr("total_redelegated", total.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::update_config;
+    use crate::state::OWNER;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+    use cosmwasm_std::{Addr, CosmosMsg, Decimal, FullDelegation, Storage, Validator};
+
+    fn setup_owner_and_zero_debt(storage: &mut dyn Storage, owner: &Addr) {
+        OWNER.save(storage, owner).expect("owner stored");
+        LENDER.save(storage, &None).expect("lender cleared");
+        OUTSTANDING_DEBT
+            .save(storage, &None)
+            .expect("zero debt stored");
+    }
+
+    fn validator(api: &MockApi, label: &str) -> Validator {
+        Validator::create(
+            api.addr_make(label).into_string(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        )
+    }
+
+    fn delegation(
+        delegator: &Addr,
+        validator: &Validator,
+        amount: u128,
+        can: u128,
+    ) -> FullDelegation {
+        FullDelegation::create(
+            delegator.clone(),
+            validator.address.clone(),
+            Coin::new(amount, "ucosm"),
+            Coin::new(can, "ucosm"),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn fails_for_unauthorized_sender() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let info = message_info(&deps.api.addr_make("intruder"), &[]);
+        let moves = vec![(
+            deps.api.addr_make("validator").into_string(),
+            deps.api.addr_make("validator-two").into_string(),
+            Uint128::new(10),
+        )];
+        let err = execute(deps.as_mut(), mock_env(), info, moves).unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn rejects_same_validator_pair() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        deps.querier.staking.update(
+            "ucosm",
+            &[first.clone(), second.clone()],
+            &[delegation(&env.contract.address, &first, 100, 100)],
+        );
+
+        let moves = vec![
+            (
+                first.address.clone(),
+                second.address.clone(),
+                Uint128::new(10),
+            ),
+            (
+                first.address.clone(),
+                first.address.clone(),
+                Uint128::new(10),
+            ),
+        ];
+        let err = execute(deps.as_mut(), env, message_info(&owner, &[]), moves).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::RedelegateToSameValidator { validator } if validator == first.address
+        ));
+    }
+
+    #[test]
+    fn checks_combined_moves_against_source_stake() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        let third = validator(&deps.api, "validator-three");
+        deps.querier.staking.update(
+            "ucosm",
+            &[first.clone(), second.clone(), third.clone()],
+            &[delegation(&env.contract.address, &first, 100, 100)],
+        );
+
+        let moves = vec![
+            (
+                first.address.clone(),
+                second.address.clone(),
+                Uint128::new(60),
+            ),
+            (
+                first.address.clone(),
+                third.address.clone(),
+                Uint128::new(60),
+            ),
+        ];
+        let err = execute(deps.as_mut(), env, message_info(&owner, &[]), moves).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InsufficientDelegatedBalance { delegated, requested, .. }
+                if delegated == Uint256::from(100u128) && requested == Uint256::from(120u128)
+        ));
+    }
+
+    #[test]
+    fn rejects_stake_still_cooling_down() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        deps.querier.staking.update(
+            "ucosm",
+            &[first.clone(), second.clone()],
+            &[delegation(&env.contract.address, &first, 100, 40)],
+        );
+
+        let moves = vec![(first.address.clone(), second.address, Uint128::new(50))];
+        let err = execute(deps.as_mut(), env, message_info(&owner, &[]), moves).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::RedelegationCooldown { redelegatable, .. }
+                if redelegatable == Uint256::from(40u128)
+        ));
+    }
+
+    #[test]
+    fn rejects_batch_when_lender_has_outstanding_debt() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        let lender = deps.api.addr_make("lender");
+        LENDER
+            .save(deps.as_mut().storage, &Some(lender))
+            .expect("lender stored");
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(Coin::new(10u128, "ucosm")))
+            .expect("debt stored");
+
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        deps.querier
+            .staking
+            .update("ucosm", &[first.clone(), second.clone()], &[]);
+
+        let moves = vec![(first.address, second.address, Uint128::new(5))];
+        let err = execute(deps.as_mut(), mock_env(), message_info(&owner, &[]), moves).unwrap_err();
+
+        assert!(matches!(err, ContractError::OutstandingDebt { .. }));
+    }
+
+    #[test]
+    fn emits_one_redelegate_message_per_move() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        let third = validator(&deps.api, "validator-three");
+        deps.querier.staking.update(
+            "ucosm",
+            &[first.clone(), second.clone(), third.clone()],
+            &[
+                delegation(&env.contract.address, &first, 100, 100),
+                delegation(&env.contract.address, &second, 50, 50),
+            ],
+        );
+
+        let moves = vec![
+            (
+                first.address.clone(),
+                third.address.clone(),
+                Uint128::new(70),
+            ),
+            (
+                second.address.clone(),
+                third.address.clone(),
+                Uint128::new(50),
+            ),
+        ];
+        let response = execute(deps.as_mut(), env, message_info(&owner, &[]), moves)
+            .expect("batch redelegation succeeds");
+
+        let messages: Vec<CosmosMsg> = response.messages.into_iter().map(|m| m.msg).collect();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Staking(StakingMsg::Redelegate {
+                    src_validator: first.address,
+                    dst_validator: third.address.clone(),
+                    amount: Coin::new(70u128, "ucosm"),
+                }),
+                CosmosMsg::Staking(StakingMsg::Redelegate {
+                    src_validator: second.address,
+                    dst_validator: third.address,
+                    amount: Coin::new(50u128, "ucosm"),
+                }),
+            ]
+        );
+        assert!(response.attributes.contains(&attr("redelegations", "2")));
+    }
+
+    #[test]
+    fn checks_combined_moves_against_destination_cap() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        update_config(deps.as_mut().storage, |config| {
+            config.max_per_validator = Some(Uint128::new(100))
+        })
+        .expect("cap stored");
+
+        let env = mock_env();
+        let first = validator(&deps.api, "validator");
+        let second = validator(&deps.api, "validator-two");
+        let third = validator(&deps.api, "validator-three");
+        deps.querier.staking.update(
+            "ucosm",
+            &[first.clone(), second.clone(), third.clone()],
+            &[
+                delegation(&env.contract.address, &first, 100, 100),
+                delegation(&env.contract.address, &second, 100, 100),
+                delegation(&env.contract.address, &third, 40, 40),
+            ],
+        );
+
+        // Each move fits on its own, but together they push the destination past the cap.
+        let moves = vec![
+            (
+                first.address.clone(),
+                third.address.clone(),
+                Uint128::new(40),
+            ),
+            (
+                second.address.clone(),
+                third.address.clone(),
+                Uint128::new(40),
+            ),
+        ];
+        let err = execute(deps.as_mut(), env, message_info(&owner, &[]), moves).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::ValidatorDelegationCapExceeded { validator, cap }
+                if validator == third.address && cap == Uint128::new(100)
+        ));
+    }
+}
@@ -1,6 +1,14 @@
-use cosmwasm_std::{attr, Coin, DepsMut, Env, MessageInfo, Response, StakingMsg, Uint128, Uint256};
-
-use crate::{helpers::require_owner, ContractError};
+use cosmwasm_std::{
+    attr, Coin, DepsMut, DistributionMsg, Env, MessageInfo, Response, StakingMsg, StdResult,
+    Storage, Uint128, Uint256,
+};
+
+use crate::{
+    helpers::{load_config, require_owner_or_operator},
+    state::UNBONDING_ENTRIES,
+    types::UnbondingEntry,
+    ContractError,
+};
 
 pub fn execute(
     deps: DepsMut,
@@ -9,7 +17,7 @@ pub fn execute(
     validator: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    require_owner(&deps, &info)?;
+    require_owner_or_operator(&deps, &info)?;
 
     if amount.is_zero() {
         return Err(ContractError::InvalidUndelegationAmount {});
@@ -35,8 +43,22 @@ pub fn execute(
     }
 
     let undelegate_coin = Coin::new(requested, denom.clone());
+    record_unbonding(deps.storage, &env, &validator_addr, undelegate_coin.clone())?;
+    let pending_rewards = delegation
+        .accumulated_rewards
+        .iter()
+        .filter(|coin| coin.denom == denom)
+        .fold(Uint256::zero(), |total, coin| total + coin.amount);
+
+    // Pending rewards are claimed explicitly first so they are never lost with the unbonding stake.
+    let mut response = Response::new();
+    if !pending_rewards.is_zero() {
+        response = response.add_message(DistributionMsg::WithdrawDelegatorReward {
+            validator: validator_addr.clone(),
+        });
+    }
 
-    Ok(Response::new()
+    Ok(response
         .add_message(StakingMsg::Undelegate {
             validator: validator_addr.clone(),
             amount: undelegate_coin,
@@ -46,9 +68,37 @@ pub fn execute(
             attr("validator", validator_addr),
             attr("denom", denom),
             attr("amount", amount.to_string()),
+            attr("rewards_claimed_hint", pending_rewards.to_string()),
         ]))
 }
 
+/// Chain unbonding period as configured, falling back to the liquidation delay. Staking params
+/// are not queryable from contracts.
+pub(crate) fn unbonding_seconds(storage: &dyn Storage) -> StdResult<u64> {
+    let config = load_config(storage)?;
+    Ok(config
+        .unbonding_seconds
+        .unwrap_or(config.liquidation_unbonding_duration))
+}
+
+/// Records an undelegation of `amount` from `validator`, dropping entries that have matured.
+pub(crate) fn record_unbonding(
+    storage: &mut dyn Storage,
+    env: &Env,
+    validator: &str,
+    amount: Coin,
+) -> StdResult<()> {
+    let completion_time = env.block.time.plus_seconds(unbonding_seconds(storage)?);
+    let mut entries = UNBONDING_ENTRIES.may_load(storage)?.unwrap_or_default();
+    entries.retain(|entry| entry.completion_time > env.block.time);
+    entries.push(UnbondingEntry {
+        validator: validator.to_string(),
+        amount,
+        completion_time,
+    });
+    UNBONDING_ENTRIES.save(storage, &entries)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,4 +304,50 @@ mod tests {
         execute(deps.as_mut(), env, info, validator_addr, Uint128::new(200))
             .expect("undelegate succeeds even with debt");
     }
+
+    #[test]
+    fn claims_rewards_before_undelegating() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+
+        let env = mock_env();
+        let validator_addr = deps.api.addr_make("validator").into_string();
+        let delegation = FullDelegation::create(
+            env.contract.address.clone(),
+            validator_addr.clone(),
+            Coin::new(300u128, "ucosm"),
+            Coin::new(300u128, "ucosm"),
+            vec![Coin::new(42u128, "ucosm"), Coin::new(7u128, "uother")],
+        );
+        let validator_obj = Validator::create(
+            validator_addr.clone(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        );
+        deps.querier
+            .staking
+            .update("ucosm", &[validator_obj], &[delegation]);
+
+        let response = execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            validator_addr.clone(),
+            Uint128::new(100),
+        )
+        .expect("undelegate succeeds");
+
+        assert_eq!(
+            response.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                validator: validator_addr,
+            })
+        );
+        assert_eq!(response.messages.len(), 2);
+        assert!(response
+            .attributes
+            .contains(&attr("rewards_claimed_hint", "42")));
+    }
 }
@@ -1,11 +1,16 @@
 use cosmwasm_std::{attr, DepsMut, MessageInfo, Response};
 
-use crate::{helpers::require_owner, state::OWNER, ContractError};
+use crate::{
+    helpers::require_owner,
+    state::{OPERATOR, OWNER},
+    ContractError,
+};
 
 pub fn execute(
     deps: DepsMut,
     info: MessageInfo,
     new_owner: String,
+    validate_receiver: bool,
 ) -> Result<Response, ContractError> {
     let current_owner = require_owner(&deps, &info)?;
 
@@ -15,7 +20,21 @@ pub fn execute(
         return Err(ContractError::OwnershipUnchanged {});
     }
 
+    // Handing the vault to an address that cannot execute messages would brick it.
+    if validate_receiver
+        && deps
+            .querier
+            .query_wasm_contract_info(validated_new_owner.as_str())
+            .is_err()
+    {
+        return Err(ContractError::IncompatibleOwnerContract {
+            address: validated_new_owner.into_string(),
+        });
+    }
+
     OWNER.save(deps.storage, &validated_new_owner)?;
+    // The operator was chosen by the previous owner and must not outlive the transfer.
+    OPERATOR.remove(deps.storage);
 
     Ok(Response::new().add_attributes([
         attr("action", "transfer_ownership"),
@@ -42,6 +61,7 @@ mod tests {
             deps.as_mut(),
             message_info(&intruder, &[]),
             "new_owner".to_string(),
+            false,
         )
         .unwrap_err();
 
@@ -56,7 +76,13 @@ mod tests {
             .save(deps.as_mut().storage, &owner)
             .expect("owner stored");
 
-        let err = execute(deps.as_mut(), message_info(&owner, &[]), owner.to_string()).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            owner.to_string(),
+            false,
+        )
+        .unwrap_err();
 
         assert!(matches!(err, ContractError::OwnershipUnchanged {}));
     }
@@ -69,11 +95,16 @@ mod tests {
         OWNER
             .save(deps.as_mut().storage, &owner)
             .expect("owner stored");
+        let operator = deps.api.addr_make("operator");
+        OPERATOR
+            .save(deps.as_mut().storage, &operator)
+            .expect("operator stored");
 
         let response = execute(
             deps.as_mut(),
             message_info(&owner, &[]),
             new_owner.to_string(),
+            false,
         )
         .expect("transfer succeeds");
 
@@ -81,6 +112,7 @@ mod tests {
             .load(deps.as_ref().storage)
             .expect("owner should be updated");
         assert_eq!(saved, new_owner);
+        assert!(!OPERATOR.exists(deps.as_ref().storage));
 
         assert_eq!(response.attributes.len(), 3);
         assert!(response
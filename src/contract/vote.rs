@@ -1,18 +1,27 @@
 use cosmwasm_std::{
-    attr, DepsMut, Env, GovMsg, MessageInfo, Response, VoteOption, WeightedVoteOption,
+    attr, Attribute, Decimal, DepsMut, Env, GovMsg, MessageInfo, Response, StdResult, Storage,
+    VoteOption, WeightedVoteOption,
 };
 
-use crate::{helpers::require_owner, ContractError};
+use std::collections::BTreeSet;
+
+use crate::{helpers::require_owner, state::VOTES, types::VoteRecord, ContractError};
 
 pub fn execute_vote(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     proposal_id: u64,
     option: VoteOption,
 ) -> Result<Response, ContractError> {
     require_owner(&deps, &info)?;
 
+    let recorded = vec![WeightedVoteOption {
+        option: option.clone(),
+        weight: Decimal::one(),
+    }];
+    let overwrite_attr = record_vote(deps.storage, &env, proposal_id, recorded)?;
+
     Ok(Response::new()
         .add_message(GovMsg::Vote {
             proposal_id,
@@ -22,12 +31,13 @@ pub fn execute_vote(
             attr("action", "vote"),
             attr("proposal_id", proposal_id.to_string()),
             attr("vote_type", "standard"),
-        ]))
+        ])
+        .add_attributes(overwrite_attr))
 }
 
 pub fn execute_weighted_vote(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     proposal_id: u64,
     options: Vec<WeightedVoteOption>,
@@ -35,6 +45,7 @@ pub fn execute_weighted_vote(
     require_owner(&deps, &info)?;
 
     let option_count = options.len().to_string();
+    let overwrite_attr = record_vote(deps.storage, &env, proposal_id, options.clone())?;
 
     Ok(Response::new()
         .add_message(GovMsg::VoteWeighted {
@@ -46,7 +57,72 @@ pub fn execute_weighted_vote(
             attr("proposal_id", proposal_id.to_string()),
             attr("vote_type", "weighted"),
             attr("option_count", option_count),
-        ]))
+        ])
+        .add_attributes(overwrite_attr))
+}
+
+pub fn execute_vote_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    votes: Vec<(u64, VoteOption)>,
+) -> Result<Response, ContractError> {
+    require_owner(&deps, &info)?;
+
+    if votes.is_empty() {
+        return Err(ContractError::EmptyVoteBatch {});
+    }
+
+    let mut seen = BTreeSet::new();
+    if let Some((proposal_id, _)) = votes.iter().find(|(id, _)| !seen.insert(*id)) {
+        return Err(ContractError::DuplicateProposalVote {
+            proposal_id: *proposal_id,
+        });
+    }
+
+    let votes_cast = votes.len().to_string();
+    let mut response = Response::new();
+    for (proposal_id, option) in votes {
+        let recorded = vec![WeightedVoteOption {
+            option: option.clone(),
+            weight: Decimal::one(),
+        }];
+        let overwrite_attr = record_vote(deps.storage, &env, proposal_id, recorded)?;
+
+        response = response
+            .add_message(GovMsg::Vote {
+                proposal_id,
+                option,
+            })
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attributes(overwrite_attr);
+    }
+
+    Ok(response.add_attributes([
+        attr("action", "vote_batch"),
+        attr("vote_type", "standard"),
+        attr("votes_cast", votes_cast),
+    ]))
+}
+
+/// Stores the submitted vote and reports whether an earlier vote was overwritten.
+fn record_vote(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: u64,
+    options: Vec<WeightedVoteOption>,
+) -> StdResult<Option<Attribute>> {
+    let previous = VOTES.may_load(storage, proposal_id)?;
+    VOTES.save(
+        storage,
+        proposal_id,
+        &VoteRecord {
+            options,
+            height: env.block.height,
+        },
+    )?;
+
+    Ok(previous.map(|_| attr("previous_vote_overwritten", "true")))
 }
 
 #[cfg(test)]
@@ -54,7 +130,7 @@ mod tests {
     use super::*;
     use crate::state::OWNER;
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-    use cosmwasm_std::{Addr, Decimal, Storage};
+    use cosmwasm_std::Addr;
 
     fn setup_owner(storage: &mut dyn Storage, owner: &Addr) {
         OWNER.save(storage, owner).expect("owner stored");
@@ -167,4 +243,130 @@ mod tests {
             other => panic!("unexpected message: {other:?}"),
         }
     }
+
+    #[test]
+    fn records_votes_and_flags_overwrites() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner(deps.as_mut().storage, &owner);
+
+        let mut env = mock_env();
+        let first = execute_vote(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            5,
+            VoteOption::Yes,
+        )
+        .expect("vote succeeds");
+        assert!(!first
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "previous_vote_overwritten"));
+
+        let stored = VOTES.load(deps.as_ref().storage, 5).expect("vote stored");
+        assert_eq!(
+            stored,
+            VoteRecord {
+                options: vec![WeightedVoteOption {
+                    option: VoteOption::Yes,
+                    weight: Decimal::one(),
+                }],
+                height: env.block.height,
+            }
+        );
+
+        env.block.height += 10;
+        let options = vec![
+            WeightedVoteOption {
+                option: VoteOption::No,
+                weight: Decimal::percent(70),
+            },
+            WeightedVoteOption {
+                option: VoteOption::Abstain,
+                weight: Decimal::percent(30),
+            },
+        ];
+        let second = execute_weighted_vote(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            5,
+            options.clone(),
+        )
+        .expect("re-vote succeeds");
+        assert!(second
+            .attributes
+            .contains(&attr("previous_vote_overwritten", "true")));
+
+        let stored = VOTES.load(deps.as_ref().storage, 5).expect("vote stored");
+        assert_eq!(stored.options, options);
+        assert_eq!(stored.height, env.block.height);
+    }
+
+    #[test]
+    fn vote_batch_rejects_empty_and_duplicate_votes() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner(deps.as_mut().storage, &owner);
+
+        let err = execute_vote_batch(deps.as_mut(), mock_env(), message_info(&owner, &[]), vec![])
+            .unwrap_err();
+        assert!(matches!(err, ContractError::EmptyVoteBatch {}));
+
+        let err = execute_vote_batch(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            vec![
+                (3, VoteOption::Yes),
+                (4, VoteOption::No),
+                (3, VoteOption::Abstain),
+            ],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::DuplicateProposalVote { proposal_id: 3 }
+        ));
+        assert!(VOTES
+            .may_load(deps.as_ref().storage, 3)
+            .expect("vote lookup")
+            .is_none());
+    }
+
+    #[test]
+    fn vote_batch_casts_and_records_each_vote() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner(deps.as_mut().storage, &owner);
+
+        let response = execute_vote_batch(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            vec![(3, VoteOption::Yes), (4, VoteOption::NoWithVeto)],
+        )
+        .expect("batch vote succeeds");
+
+        let messages: Vec<_> = response.messages.iter().map(|m| m.msg.clone()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                cosmwasm_std::CosmosMsg::Gov(GovMsg::Vote {
+                    proposal_id: 3,
+                    option: VoteOption::Yes,
+                }),
+                cosmwasm_std::CosmosMsg::Gov(GovMsg::Vote {
+                    proposal_id: 4,
+                    option: VoteOption::NoWithVeto,
+                }),
+            ]
+        );
+        assert!(response.attributes.contains(&attr("votes_cast", "2")));
+
+        let recorded = VOTES.load(deps.as_ref().storage, 4).expect("vote recorded");
+        assert_eq!(recorded.options[0].option, VoteOption::NoWithVeto);
+        assert!(VOTES.has(deps.as_ref().storage, 3));
+    }
 }
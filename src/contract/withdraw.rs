@@ -1,9 +1,13 @@
 use cosmwasm_std::{
-    attr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128, Uint256,
+    attr, Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    Uint256,
 };
 
 use crate::{
-    helpers::{minimum_collateral_lock_for_denom, require_owner},
+    helpers::{
+        balance_change_attributes, minimum_collateral_lock_for_denom, require_owner,
+        unclaimed_refunds_for_denom,
+    },
     state::{OPEN_INTEREST, OUTSTANDING_DEBT},
     ContractError,
 };
@@ -32,11 +36,83 @@ pub fn execute(
     if withdrawable < requested {
         return Err(ContractError::InsufficientBalance {
             denom: denom.clone(),
-            available: Uint128::try_from(withdrawable).expect("available fits in u128"),
-            requested: Uint128::try_from(requested).expect("requested fits in u128"),
+            available: withdrawable,
+            requested,
         });
     }
 
+    send_withdrawal(&deps, &env, "withdraw", owner, denom, amount, recipient)
+}
+
+pub fn execute_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let owner = require_owner(&deps, &info)?;
+
+    let amount = sweepable_amount(&deps.as_ref(), &env, &denom)?;
+    if amount.is_zero() {
+        let balance = deps
+            .querier
+            .query_balance(env.contract.address.clone(), denom.clone())?;
+        return Err(ContractError::InsufficientBalance {
+            denom,
+            available: Uint256::zero(),
+            requested: balance.amount,
+        });
+    }
+
+    let response = send_withdrawal(&deps, &env, "withdraw_all", owner, denom, amount, recipient)?;
+
+    Ok(response.add_attribute("swept_amount", amount.to_string()))
+}
+
+pub fn execute_excess(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let owner = require_owner(&deps, &info)?;
+
+    let amount = sweepable_amount(&deps.as_ref(), &env, &denom)?;
+    if amount.is_zero() {
+        return Err(ContractError::NoExcessToWithdraw { denom });
+    }
+
+    let response = send_withdrawal(
+        &deps,
+        &env,
+        "withdraw_excess",
+        owner,
+        denom,
+        amount,
+        recipient,
+    )?;
+
+    Ok(response.add_attribute("excess_amount", amount.to_string()))
+}
+
+/// Withdrawable balance capped to what a single bank send can carry; any remainder stays for a
+/// later sweep.
+fn sweepable_amount(deps: &Deps, env: &Env, denom: &str) -> StdResult<Uint128> {
+    let withdrawable = available_to_withdraw(deps, env, denom)?;
+    Ok(Uint128::try_from(withdrawable).unwrap_or(Uint128::MAX))
+}
+
+fn send_withdrawal(
+    deps: &DepsMut,
+    env: &Env,
+    action: &str,
+    owner: Addr,
+    denom: String,
+    amount: Uint128,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
     let recipient_addr = match recipient {
         Some(addr) => deps.api.addr_validate(&addr)?,
         None => owner,
@@ -44,6 +120,8 @@ pub fn execute(
     let recipient_str = recipient_addr.to_string();
 
     let withdraw_coin = Coin::new(amount, denom.clone());
+    let balance_attrs =
+        balance_change_attributes(&deps.as_ref(), env, &denom, Uint256::from(amount))?;
 
     Ok(Response::new()
         .add_message(BankMsg::Send {
@@ -51,11 +129,35 @@ pub fn execute(
             amount: vec![withdraw_coin],
         })
         .add_attributes([
-            attr("action", "withdraw"),
+            attr("action", action),
             attr("denom", denom),
             attr("amount", amount.to_string()),
             attr("recipient", recipient_str),
-        ]))
+        ])
+        .add_attributes(balance_attrs))
+}
+
+/// Balance of `denom` not reserved for debt, collateral, or unclaimed refunds.
+pub(crate) fn available_to_withdraw(deps: &Deps, env: &Env, denom: &str) -> StdResult<Uint256> {
+    let outstanding_debt = OUTSTANDING_DEBT.load(deps.storage)?;
+    let open_interest = OPEN_INTEREST.load(deps.storage)?;
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.to_string())?;
+    let available = balance.amount;
+
+    let collateral_lock =
+        minimum_collateral_lock_for_denom(deps, env, denom, open_interest.as_ref())?;
+    let debt_requirement = match outstanding_debt {
+        Some(debt) if debt.denom == denom => debt.amount,
+        _ => Uint256::zero(),
+    };
+
+    let unclaimed_refunds = unclaimed_refunds_for_denom(deps, denom)?;
+
+    let required_minimum = max(debt_requirement, collateral_lock).checked_add(unclaimed_refunds)?;
+    Ok(available.saturating_sub(required_minimum))
 }
 
 #[cfg(test)]
@@ -63,12 +165,12 @@ mod tests {
     use super::*;
     use crate::{
         contract::open_interest::test_helpers::{build_open_interest, sample_coin},
-        state::{OPEN_INTEREST, OUTSTANDING_DEBT, OWNER},
+        state::{OPEN_INTEREST, OUTSTANDING_DEBT, OWNER, UNCLAIMED_REFUND_TOTALS},
     };
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
     use cosmwasm_std::{
-        coins, Addr, Coin, DecCoin, Decimal, Decimal256, FullDelegation, Storage, Uint128, Uint256,
-        Validator,
+        coins, Addr, Coin, CosmosMsg, DecCoin, Decimal, Decimal256, FullDelegation, Storage,
+        Uint128, Uint256, Validator,
     };
 
     fn setup_owner_and_zero_debt(storage: &mut dyn Storage, owner: &Addr) {
@@ -152,8 +254,8 @@ mod tests {
                 available,
                 requested,
             } if denom == "ucosm"
-                && available == Uint128::zero()
-                && requested == Uint128::from(10u128)
+                && available == Uint256::zero()
+                && requested == Uint256::from(10u128)
         ));
     }
 
@@ -212,6 +314,167 @@ mod tests {
         assert!(matches!(err, ContractError::InsufficientBalance { .. }));
     }
 
+    #[test]
+    fn reserves_unclaimed_refunds() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        UNCLAIMED_REFUND_TOTALS
+            .save(deps.as_mut().storage, "ucosm", &Uint256::from(300u128))
+            .expect("refunds credited");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(400, "ucosm"));
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            "ucosm".to_string(),
+            Uint128::new(101),
+            None,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InsufficientBalance { available, .. } if available == Uint256::new(100)
+        ));
+    }
+
+    #[test]
+    fn withdraw_all_fails_when_nothing_is_withdrawable() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        UNCLAIMED_REFUND_TOTALS
+            .save(deps.as_mut().storage, "ucosm", &Uint256::from(400u128))
+            .expect("refunds credited");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(400, "ucosm"));
+
+        let err = execute_all(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            "ucosm".to_string(),
+            None,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::InsufficientBalance { available, .. } if available.is_zero()
+        ));
+    }
+
+    #[test]
+    fn withdraw_all_sweeps_only_the_unreserved_balance() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        UNCLAIMED_REFUND_TOTALS
+            .save(deps.as_mut().storage, "ucosm", &Uint256::from(300u128))
+            .expect("refunds credited");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(400, "ucosm"));
+
+        let recipient = deps.api.addr_make("cold");
+        let response = execute_all(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            "ucosm".to_string(),
+            Some(recipient.to_string()),
+        )
+        .expect("sweep succeeds");
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(100, "ucosm"),
+            })
+        );
+        assert!(response
+            .attributes
+            .contains(&attr("action", "withdraw_all")));
+        assert!(response.attributes.contains(&attr("swept_amount", "100")));
+    }
+
+    #[test]
+    fn withdraw_excess_sends_only_balance_beyond_debt_and_collateral() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        setup_owner_and_zero_debt(deps.as_mut().storage, &owner);
+        deps.querier.staking.update("ucosm", &[], &[]);
+
+        let open_interest = build_open_interest(
+            sample_coin(100, "uusd"),
+            sample_coin(5, "ujuno"),
+            86_400,
+            sample_coin(200, "uatom"),
+        );
+        OPEN_INTEREST
+            .save(deps.as_mut().storage, &Some(open_interest))
+            .expect("open interest stored");
+        OUTSTANDING_DEBT
+            .save(deps.as_mut().storage, &Some(Coin::new(250u128, "uatom")))
+            .expect("debt stored");
+        UNCLAIMED_REFUND_TOTALS
+            .save(deps.as_mut().storage, "uatom", &Uint256::from(50u128))
+            .expect("refunds credited");
+
+        let env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(400, "uatom"));
+
+        let response = execute_excess(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            "uatom".to_string(),
+            None,
+        )
+        .expect("excess withdrawn");
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: owner.to_string(),
+                amount: coins(100, "uatom"),
+            })
+        );
+        assert!(response.attributes.contains(&attr("excess_amount", "100")));
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.as_str(), coins(300, "uatom"));
+
+        let err = execute_excess(
+            deps.as_mut(),
+            env,
+            message_info(&owner, &[]),
+            "uatom".to_string(),
+            None,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::NoExcessToWithdraw { denom } if denom == "uatom"
+        ));
+    }
+
     #[test]
     fn sends_funds_to_owner_when_no_recipient_provided() {
         let mut deps = mock_dependencies();
@@ -242,6 +505,8 @@ mod tests {
             }
             _ => panic!("unexpected message"),
         }
+        assert!(response.attributes.contains(&attr("balance_before", "400")));
+        assert!(response.attributes.contains(&attr("balance_after", "250")));
     }
 
     #[test]
@@ -358,8 +623,8 @@ mod tests {
                 available,
                 requested,
             } if denom == collateral_denom
-                && available == Uint128::zero()
-                && requested == Uint128::from(10u128)
+                && available == Uint256::zero()
+                && requested == Uint256::from(10u128)
         ));
     }
 
@@ -416,8 +681,8 @@ mod tests {
                 available,
                 requested,
             } if denom == bonded_denom
-                && available == Uint128::from(50u128)
-                && requested == Uint128::from(100u128)
+                && available == Uint256::from(50u128)
+                && requested == Uint256::from(100u128)
         ));
     }
 
@@ -447,23 +712,3 @@ mod tests {
         )
     }
 }
-
-fn available_to_withdraw(deps: &Deps, env: &Env, denom: &str) -> StdResult<Uint256> {
-    let outstanding_debt = OUTSTANDING_DEBT.load(deps.storage)?;
-    let open_interest = OPEN_INTEREST.load(deps.storage)?;
-
-    let balance = deps
-        .querier
-        .query_balance(env.contract.address.clone(), denom.to_string())?;
-    let available = balance.amount;
-
-    let collateral_lock =
-        minimum_collateral_lock_for_denom(deps, env, denom, open_interest.as_ref())?;
-    let debt_requirement = match outstanding_debt {
-        Some(debt) if debt.denom == denom => debt.amount,
-        _ => Uint256::zero(),
-    };
-
-    let required_minimum = max(debt_requirement, collateral_lock);
-    Ok(available.saturating_sub(required_minimum))
-}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Coin, StdError, Uint128, Uint256};
+use cosmwasm_std::{Coin, Decimal, StdError, Timestamp, Uint128, Uint256};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,14 +12,41 @@ pub enum ContractError {
     #[error("New owner must be different from the current owner")]
     OwnershipUnchanged {},
 
+    #[error("Cannot migrate from contract {found}, expected {expected}")]
+    MigrationContractMismatch { expected: String, found: String },
+
+    #[error("Cannot migrate from version {stored} down to {current}")]
+    MigrationDowngrade { stored: String, current: String },
+
+    #[error("Invalid contract version: {version}")]
+    InvalidContractVersion { version: String },
+
+    #[error("New owner {address} is not a compatible contract")]
+    IncompatibleOwnerContract { address: String },
+
+    #[error("No unclaimed refunds for {address}")]
+    NoUnclaimedRefunds { address: String },
+
+    #[error("Vote batch must contain at least one vote")]
+    EmptyVoteBatch {},
+
+    #[error("Proposal {proposal_id} appears more than once in the vote batch")]
+    DuplicateProposalVote { proposal_id: u64 },
+
+    #[error("Invalid configuration")]
+    InvalidConfig {},
+
+    #[error("Memo cannot exceed {max} bytes")]
+    MemoTooLong { max: usize },
+
     #[error("Delegation amount must be greater than zero")]
     InvalidDelegationAmount {},
 
     #[error("Insufficient balance: have {available} {denom}, need {requested}")]
     InsufficientBalance {
         denom: String,
-        available: Uint128,
-        requested: Uint128,
+        available: Uint256,
+        requested: Uint256,
     },
 
     #[error("Outstanding debt of {amount} must be settled before delegating")]
@@ -37,8 +64,17 @@ pub enum ContractError {
     #[error("Withdrawal amount must be greater than zero")]
     InvalidWithdrawalAmount {},
 
-    #[error("Cannot redelegate to the same validator")]
-    RedelegateToSameValidator {},
+    #[error("Cannot redelegate from {validator} to itself")]
+    RedelegateToSameValidator { validator: String },
+
+    #[error(
+        "Redelegation from {validator} is cooling down: can move {redelegatable}, need {requested}"
+    )]
+    RedelegationCooldown {
+        validator: String,
+        redelegatable: Uint256,
+        requested: Uint256,
+    },
 
     #[error("Delegation not found for validator {validator}")]
     DelegationNotFound { validator: String },
@@ -65,18 +101,27 @@ pub enum ContractError {
     #[error("Open interest has not yet expired")]
     OpenInterestNotExpired {},
 
+    #[error("Open interest cannot be closed until {unlockable_at}")]
+    OpenInterestLocked { unlockable_at: Timestamp },
+
     #[error("A lender has already been set")]
     LenderAlreadySet {},
 
     #[error("{field} amount must be greater than zero")]
     InvalidCoinAmount { field: &'static str },
 
-    #[error("{field} denom must not be empty")]
+    #[error("{field} denom must be non-empty, without whitespace, and lowercase outside IBC/factory paths")]
     InvalidCoinDenom { field: &'static str },
 
+    #[error("Denom {denom} is not in the allowed denom list")]
+    DenomNotAllowed { denom: String },
+
     #[error("Expiry duration must be greater than zero seconds")]
     InvalidExpiryDuration {},
 
+    #[error("Expiry duration cannot exceed {maximum} seconds")]
+    ExpiryDurationTooLong { maximum: u64 },
+
     #[error("Liquidation unbonding duration cannot exceed {max} seconds")]
     LiquidationUnbondingDurationTooLong { max: u64 },
 
@@ -103,9 +148,22 @@ pub enum ContractError {
     #[error("Fund request does not match the active open interest")]
     OpenInterestMismatch {},
 
+    #[error("Repayment exceeds remaining debt of {remaining} {denom}")]
+    RepaymentExceedsDebt { denom: String, remaining: Uint256 },
+
+    #[error("Escrowed counter offers total {escrowed} {denom} but tracked debt is {tracked}")]
+    DebtAccountingMismatch {
+        denom: String,
+        escrowed: Uint256,
+        tracked: Uint256,
+    },
+
     #[error("Repayment requirement for {denom} exceeds Uint128 range: {requested}")]
     RepaymentAmountOverflow { denom: String, requested: Uint256 },
 
+    #[error("Liquidation arithmetic failed: {detail}")]
+    LiquidationMathError { detail: String },
+
     #[error("Liquidation payout for {denom} exceeds Uint128 range: {requested}")]
     LiquidationAmountOverflow { denom: String, requested: Uint256 },
 
@@ -123,4 +181,478 @@ pub enum ContractError {
 
     #[error("Counter offer payload for {proposer} does not match stored terms")]
     CounterOfferMismatch { proposer: String },
+
+    #[error("Counter offer from {proposer} expired at {expired_at}")]
+    CounterOfferExpired {
+        proposer: String,
+        expired_at: Timestamp,
+    },
+
+    #[error("Counter offer from {proposer} has not expired")]
+    CounterOfferNotExpired {
+        proposer: String,
+        expires_at: Option<Timestamp>,
+    },
+
+    #[error("Delegation to {validator} failed: {reason}")]
+    DelegationFailed { validator: String, reason: String },
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("No {denom} in excess of vault obligations to withdraw")]
+    NoExcessToWithdraw { denom: String },
+
+    #[error("Interest floor must exceed the current interest of {current}")]
+    InterestFloorNotRaised { current: Coin },
+
+    #[error("Cannot delegate {denom} while collateral is short by {shortfall}")]
+    CannotDelegateWhileUndercollateralized { denom: String, shortfall: Uint256 },
+
+    #[error("New lender must be different from the current lender")]
+    LenderUnchanged {},
+
+    #[error("Restake share {bps} exceeds 10000 basis points")]
+    InvalidRestakeBps { bps: u16 },
+
+    #[error("This message does not accept funds")]
+    FundsNotAccepted {},
+
+    #[error("Repayment deadline must be positive and shorter than the expiry duration")]
+    InvalidRepaymentDeadline {},
+
+    #[error("Unexpected funds attached in {denom}")]
+    UnexpectedFunds { denom: String },
+
+    #[error("No queued interest is waiting to open")]
+    NoQueuedInterest {},
+
+    #[error("Loan reference cannot exceed {max} bytes")]
+    ReferenceTooLong { max: usize },
+
+    #[error("Liquidation already ran in this block")]
+    LiquidationAlreadyProcessed {},
+
+    #[error("Collateral {denom} is not the bonded denom, so staking cannot help: have {liquid} liquid, need {requested}")]
+    UnbondedCollateralShortfall {
+        denom: String,
+        liquid: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("Counter offer cannot be cancelled until {unlockable_at}")]
+    CancelLocked { unlockable_at: Timestamp },
+
+    #[error("Attached {denom} amounts overflow when summed")]
+    EscrowSumOverflow { denom: String },
+
+    #[error("Liquidation found no liquid or delegated collateral to cover the debt")]
+    LiquidationNoCollateral {},
+
+    #[error("Best counter offer of {best} is below the minimum liquidity")]
+    NoQualifyingOffer { best: Uint256 },
+
+    #[error(
+        "Validator {validator} charges {commission} commission, above the maximum of {maximum}"
+    )]
+    ValidatorCommissionTooHigh {
+        validator: String,
+        commission: Decimal,
+        maximum: Decimal,
+    },
+
+    #[error("The vault owner cannot fund their own open interest")]
+    OwnerCannotFund {},
+
+    #[error("Liquidity amount is below the configured minimum of {minimum}")]
+    LiquidityBelowMinimum { minimum: Uint256 },
+
+    #[error("Collateral denom must differ from the liquidity denom")]
+    CollateralLiquidityDenomConflict {},
+
+    #[error("Delegation to {validator} would exceed the per-validator cap of {cap}")]
+    ValidatorDelegationCapExceeded { validator: String, cap: Uint128 },
+
+    #[error("Liquidation payout deductions total {sum} basis points, above 10000")]
+    CombinedBpsTooHigh { sum: u32 },
+}
+
+/// Stable codes and message templates for every `ContractError` variant, in declaration order.
+pub const ERROR_CATALOG: &[(&str, &str)] = &[
+    ("std", "Standard library error"),
+    ("unauthorized", "Unauthorized"),
+    ("ownership_unchanged", "New owner must be different from the current owner"),
+    ("migration_contract_mismatch", "Cannot migrate from contract {found}, expected {expected}"),
+    ("migration_downgrade", "Cannot migrate from version {stored} down to {current}"),
+    ("invalid_contract_version", "Invalid contract version: {version}"),
+    ("incompatible_owner_contract", "New owner {address} is not a compatible contract"),
+    ("no_unclaimed_refunds", "No unclaimed refunds for {address}"),
+    ("empty_vote_batch", "Vote batch must contain at least one vote"),
+    ("duplicate_proposal_vote", "Proposal {proposal_id} appears more than once in the vote batch"),
+    ("invalid_config", "Invalid configuration"),
+    ("memo_too_long", "Memo cannot exceed {max} bytes"),
+    ("invalid_delegation_amount", "Delegation amount must be greater than zero"),
+    ("insufficient_balance", "Insufficient balance: have {available} {denom}, need {requested}"),
+    ("outstanding_debt", "Outstanding debt of {amount} must be settled before delegating"),
+    ("validator_not_found", "Validator not found: {validator}"),
+    ("invalid_undelegation_amount", "Undelegation amount must be greater than zero"),
+    ("invalid_redelegation_amount", "Redelegation amount must be greater than zero"),
+    ("invalid_withdrawal_amount", "Withdrawal amount must be greater than zero"),
+    ("redelegate_to_same_validator", "Cannot redelegate from {validator} to itself"),
+    ("redelegation_cooldown", "Redelegation from {validator} is cooling down: can move {redelegatable}, need {requested}"),
+    ("delegation_not_found", "Delegation not found for validator {validator}"),
+    ("insufficient_delegated_balance", "Insufficient delegated balance for validator {validator}: have {delegated}, need {requested}"),
+    ("no_delegations", "No delegations found to claim rewards from"),
+    ("open_interest_already_exists", "An open interest is already active"),
+    ("no_open_interest", "No open interest is currently active"),
+    ("no_lender", "No lender is currently set for the open interest"),
+    ("open_interest_not_expired", "Open interest has not yet expired"),
+    ("open_interest_locked", "Open interest cannot be closed until {unlockable_at}"),
+    ("lender_already_set", "A lender has already been set"),
+    ("invalid_coin_amount", "{field} amount must be greater than zero"),
+    ("invalid_coin_denom", "{field} denom must be non-empty, without whitespace, and lowercase outside IBC/factory paths"),
+    ("denom_not_allowed", "Denom {denom} is not in the allowed denom list"),
+    ("invalid_expiry_duration", "Expiry duration must be greater than zero seconds"),
+    ("expiry_duration_too_long", "Expiry duration cannot exceed {maximum} seconds"),
+    ("liquidation_unbonding_duration_too_long", "Liquidation unbonding duration cannot exceed {max} seconds"),
+    ("counter_offer_terms_mismatch", "Counter offer terms must match the active open interest"),
+    ("counter_offer_not_smaller", "Counter offer liquidity must be less than the active open interest"),
+    ("counter_offer_escrow_mismatch", "Counter offer escrow must provide {expected} {denom}, received {received}"),
+    ("open_interest_funding_mismatch", "Funding escrow must provide {expected} {denom}, received {received}"),
+    ("open_interest_mismatch", "Fund request does not match the active open interest"),
+    ("repayment_exceeds_debt", "Repayment exceeds remaining debt of {remaining} {denom}"),
+    ("debt_accounting_mismatch", "Escrowed counter offers total {escrowed} {denom} but tracked debt is {tracked}"),
+    ("repayment_amount_overflow", "Repayment requirement for {denom} exceeds Uint128 range: {requested}"),
+    ("liquidation_math_error", "Liquidation arithmetic failed: {detail}"),
+    ("liquidation_amount_overflow", "Liquidation payout for {denom} exceeds Uint128 range: {requested}"),
+    ("undelegation_amount_overflow", "Undelegation amount for {denom} exceeds Uint128 range: {requested}"),
+    ("counter_offer_already_exists", "Proposer already has an active counter offer"),
+    ("counter_offer_not_competitive", "Counter offers are full; liquidity must be greater than {minimum} {denom}"),
+    ("counter_offer_not_found", "Counter offer from {proposer} not found"),
+    ("counter_offer_mismatch", "Counter offer payload for {proposer} does not match stored terms"),
+    ("counter_offer_expired", "Counter offer from {proposer} expired at {expired_at}"),
+    ("counter_offer_not_expired", "Counter offer from {proposer} has not expired"),
+    ("delegation_failed", "Delegation to {validator} failed: {reason}"),
+    ("unknown_reply_id", "Unknown reply id {id}"),
+    ("no_excess_to_withdraw", "No {denom} in excess of vault obligations to withdraw"),
+    ("interest_floor_not_raised", "Interest floor must exceed the current interest of {current}"),
+    ("cannot_delegate_while_undercollateralized", "Cannot delegate {denom} while collateral is short by {shortfall}"),
+    ("lender_unchanged", "New lender must be different from the current lender"),
+    ("invalid_restake_bps", "Restake share {bps} exceeds 10000 basis points"),
+    ("funds_not_accepted", "This message does not accept funds"),
+    ("invalid_repayment_deadline", "Repayment deadline must be positive and shorter than the expiry duration"),
+    ("unexpected_funds", "Unexpected funds attached in {denom}"),
+    ("no_queued_interest", "No queued interest is waiting to open"),
+    ("reference_too_long", "Loan reference cannot exceed {max} bytes"),
+    ("liquidation_already_processed", "Liquidation already ran in this block"),
+    ("unbonded_collateral_shortfall", "Collateral {denom} is not the bonded denom, so staking cannot help: have {liquid} liquid, need {requested}"),
+    ("cancel_locked", "Counter offer cannot be cancelled until {unlockable_at}"),
+    ("escrow_sum_overflow", "Attached {denom} amounts overflow when summed"),
+    ("liquidation_no_collateral", "Liquidation found no liquid or delegated collateral to cover the debt"),
+    ("no_qualifying_offer", "Best counter offer of {best} is below the minimum liquidity"),
+    ("validator_commission_too_high", "Validator {validator} charges {commission} commission, above the maximum of {maximum}"),
+    ("owner_cannot_fund", "The vault owner cannot fund their own open interest"),
+    ("liquidity_below_minimum", "Liquidity amount is below the configured minimum of {minimum}"),
+    ("collateral_liquidity_denom_conflict", "Collateral denom must differ from the liquidity denom"),
+    ("validator_delegation_cap_exceeded", "Delegation to {validator} would exceed the per-validator cap of {cap}"),
+    ("combined_bps_too_high", "Liquidation payout deductions total {sum} basis points, above 10000"),
+];
+
+impl ContractError {
+    /// Stable, machine-readable identifier for this error, as listed in `ERROR_CATALOG`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Std(_) => "std",
+            Self::Unauthorized {} => "unauthorized",
+            Self::OwnershipUnchanged {} => "ownership_unchanged",
+            Self::MigrationContractMismatch { .. } => "migration_contract_mismatch",
+            Self::MigrationDowngrade { .. } => "migration_downgrade",
+            Self::InvalidContractVersion { .. } => "invalid_contract_version",
+            Self::IncompatibleOwnerContract { .. } => "incompatible_owner_contract",
+            Self::NoUnclaimedRefunds { .. } => "no_unclaimed_refunds",
+            Self::EmptyVoteBatch {} => "empty_vote_batch",
+            Self::DuplicateProposalVote { .. } => "duplicate_proposal_vote",
+            Self::InvalidConfig {} => "invalid_config",
+            Self::MemoTooLong { .. } => "memo_too_long",
+            Self::InvalidDelegationAmount {} => "invalid_delegation_amount",
+            Self::InsufficientBalance { .. } => "insufficient_balance",
+            Self::OutstandingDebt { .. } => "outstanding_debt",
+            Self::ValidatorNotFound { .. } => "validator_not_found",
+            Self::InvalidUndelegationAmount {} => "invalid_undelegation_amount",
+            Self::InvalidRedelegationAmount {} => "invalid_redelegation_amount",
+            Self::InvalidWithdrawalAmount {} => "invalid_withdrawal_amount",
+            Self::RedelegateToSameValidator { .. } => "redelegate_to_same_validator",
+            Self::RedelegationCooldown { .. } => "redelegation_cooldown",
+            Self::DelegationNotFound { .. } => "delegation_not_found",
+            Self::InsufficientDelegatedBalance { .. } => "insufficient_delegated_balance",
+            Self::NoDelegations {} => "no_delegations",
+            Self::OpenInterestAlreadyExists {} => "open_interest_already_exists",
+            Self::NoOpenInterest {} => "no_open_interest",
+            Self::NoLender {} => "no_lender",
+            Self::OpenInterestNotExpired {} => "open_interest_not_expired",
+            Self::OpenInterestLocked { .. } => "open_interest_locked",
+            Self::LenderAlreadySet {} => "lender_already_set",
+            Self::InvalidCoinAmount { .. } => "invalid_coin_amount",
+            Self::InvalidCoinDenom { .. } => "invalid_coin_denom",
+            Self::DenomNotAllowed { .. } => "denom_not_allowed",
+            Self::InvalidExpiryDuration {} => "invalid_expiry_duration",
+            Self::ExpiryDurationTooLong { .. } => "expiry_duration_too_long",
+            Self::LiquidationUnbondingDurationTooLong { .. } => {
+                "liquidation_unbonding_duration_too_long"
+            }
+            Self::CounterOfferTermsMismatch {} => "counter_offer_terms_mismatch",
+            Self::CounterOfferNotSmaller {} => "counter_offer_not_smaller",
+            Self::CounterOfferEscrowMismatch { .. } => "counter_offer_escrow_mismatch",
+            Self::OpenInterestFundingMismatch { .. } => "open_interest_funding_mismatch",
+            Self::OpenInterestMismatch {} => "open_interest_mismatch",
+            Self::RepaymentExceedsDebt { .. } => "repayment_exceeds_debt",
+            Self::DebtAccountingMismatch { .. } => "debt_accounting_mismatch",
+            Self::RepaymentAmountOverflow { .. } => "repayment_amount_overflow",
+            Self::LiquidationMathError { .. } => "liquidation_math_error",
+            Self::LiquidationAmountOverflow { .. } => "liquidation_amount_overflow",
+            Self::UndelegationAmountOverflow { .. } => "undelegation_amount_overflow",
+            Self::CounterOfferAlreadyExists {} => "counter_offer_already_exists",
+            Self::CounterOfferNotCompetitive { .. } => "counter_offer_not_competitive",
+            Self::CounterOfferNotFound { .. } => "counter_offer_not_found",
+            Self::CounterOfferMismatch { .. } => "counter_offer_mismatch",
+            Self::CounterOfferExpired { .. } => "counter_offer_expired",
+            Self::CounterOfferNotExpired { .. } => "counter_offer_not_expired",
+            Self::DelegationFailed { .. } => "delegation_failed",
+            Self::UnknownReplyId { .. } => "unknown_reply_id",
+            Self::NoExcessToWithdraw { .. } => "no_excess_to_withdraw",
+            Self::InterestFloorNotRaised { .. } => "interest_floor_not_raised",
+            Self::CannotDelegateWhileUndercollateralized { .. } => {
+                "cannot_delegate_while_undercollateralized"
+            }
+            Self::LenderUnchanged {} => "lender_unchanged",
+            Self::InvalidRestakeBps { .. } => "invalid_restake_bps",
+            Self::FundsNotAccepted {} => "funds_not_accepted",
+            Self::InvalidRepaymentDeadline {} => "invalid_repayment_deadline",
+            Self::UnexpectedFunds { .. } => "unexpected_funds",
+            Self::NoQueuedInterest {} => "no_queued_interest",
+            Self::ReferenceTooLong { .. } => "reference_too_long",
+            Self::LiquidationAlreadyProcessed {} => "liquidation_already_processed",
+            Self::UnbondedCollateralShortfall { .. } => "unbonded_collateral_shortfall",
+            Self::CancelLocked { .. } => "cancel_locked",
+            Self::EscrowSumOverflow { .. } => "escrow_sum_overflow",
+            Self::LiquidationNoCollateral {} => "liquidation_no_collateral",
+            Self::NoQualifyingOffer { .. } => "no_qualifying_offer",
+            Self::ValidatorCommissionTooHigh { .. } => "validator_commission_too_high",
+            Self::OwnerCannotFund {} => "owner_cannot_fund",
+            Self::LiquidityBelowMinimum { .. } => "liquidity_below_minimum",
+            Self::CollateralLiquidityDenomConflict {} => "collateral_liquidity_denom_conflict",
+            Self::ValidatorDelegationCapExceeded { .. } => "validator_delegation_cap_exceeded",
+            Self::CombinedBpsTooHigh { .. } => "combined_bps_too_high",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// One instance of every variant; `code` has no wildcard arm, so new variants must be added.
+    fn every_variant() -> Vec<ContractError> {
+        vec![
+            ContractError::Std(StdError::msg("sample")),
+            ContractError::Unauthorized {},
+            ContractError::OwnershipUnchanged {},
+            ContractError::MigrationContractMismatch {
+                expected: String::new(),
+                found: String::new(),
+            },
+            ContractError::MigrationDowngrade {
+                stored: String::new(),
+                current: String::new(),
+            },
+            ContractError::InvalidContractVersion {
+                version: String::new(),
+            },
+            ContractError::IncompatibleOwnerContract {
+                address: String::new(),
+            },
+            ContractError::NoUnclaimedRefunds {
+                address: String::new(),
+            },
+            ContractError::EmptyVoteBatch {},
+            ContractError::DuplicateProposalVote { proposal_id: 0 },
+            ContractError::InvalidConfig {},
+            ContractError::MemoTooLong { max: 0 },
+            ContractError::InvalidDelegationAmount {},
+            ContractError::InsufficientBalance {
+                denom: String::new(),
+                available: Uint256::zero(),
+                requested: Uint256::zero(),
+            },
+            ContractError::OutstandingDebt {
+                amount: Coin::default(),
+            },
+            ContractError::ValidatorNotFound {
+                validator: String::new(),
+            },
+            ContractError::InvalidUndelegationAmount {},
+            ContractError::InvalidRedelegationAmount {},
+            ContractError::InvalidWithdrawalAmount {},
+            ContractError::RedelegateToSameValidator {
+                validator: String::new(),
+            },
+            ContractError::RedelegationCooldown {
+                validator: String::new(),
+                redelegatable: Uint256::zero(),
+                requested: Uint256::zero(),
+            },
+            ContractError::DelegationNotFound {
+                validator: String::new(),
+            },
+            ContractError::InsufficientDelegatedBalance {
+                validator: String::new(),
+                delegated: Uint256::zero(),
+                requested: Uint256::zero(),
+            },
+            ContractError::NoDelegations {},
+            ContractError::OpenInterestAlreadyExists {},
+            ContractError::NoOpenInterest {},
+            ContractError::NoLender {},
+            ContractError::OpenInterestNotExpired {},
+            ContractError::OpenInterestLocked {
+                unlockable_at: Timestamp::from_seconds(0),
+            },
+            ContractError::LenderAlreadySet {},
+            ContractError::InvalidCoinAmount { field: "" },
+            ContractError::InvalidCoinDenom { field: "" },
+            ContractError::DenomNotAllowed {
+                denom: String::new(),
+            },
+            ContractError::InvalidExpiryDuration {},
+            ContractError::ExpiryDurationTooLong { maximum: 0 },
+            ContractError::LiquidationUnbondingDurationTooLong { max: 0 },
+            ContractError::CounterOfferTermsMismatch {},
+            ContractError::CounterOfferNotSmaller {},
+            ContractError::CounterOfferEscrowMismatch {
+                denom: String::new(),
+                expected: Uint256::zero(),
+                received: Uint256::zero(),
+            },
+            ContractError::OpenInterestFundingMismatch {
+                denom: String::new(),
+                expected: Uint256::zero(),
+                received: Uint256::zero(),
+            },
+            ContractError::OpenInterestMismatch {},
+            ContractError::RepaymentExceedsDebt {
+                denom: String::new(),
+                remaining: Uint256::zero(),
+            },
+            ContractError::DebtAccountingMismatch {
+                denom: String::new(),
+                escrowed: Uint256::zero(),
+                tracked: Uint256::zero(),
+            },
+            ContractError::RepaymentAmountOverflow {
+                denom: String::new(),
+                requested: Uint256::zero(),
+            },
+            ContractError::LiquidationMathError {
+                detail: String::new(),
+            },
+            ContractError::LiquidationAmountOverflow {
+                denom: String::new(),
+                requested: Uint256::zero(),
+            },
+            ContractError::UndelegationAmountOverflow {
+                denom: String::new(),
+                requested: Uint256::zero(),
+            },
+            ContractError::CounterOfferAlreadyExists {},
+            ContractError::CounterOfferNotCompetitive {
+                minimum: Uint256::zero(),
+                denom: String::new(),
+            },
+            ContractError::CounterOfferNotFound {
+                proposer: String::new(),
+            },
+            ContractError::CounterOfferMismatch {
+                proposer: String::new(),
+            },
+            ContractError::CounterOfferExpired {
+                proposer: String::new(),
+                expired_at: Timestamp::default(),
+            },
+            ContractError::CounterOfferNotExpired {
+                proposer: String::new(),
+                expires_at: None,
+            },
+            ContractError::DelegationFailed {
+                validator: String::new(),
+                reason: String::new(),
+            },
+            ContractError::UnknownReplyId { id: 0 },
+            ContractError::NoExcessToWithdraw {
+                denom: String::new(),
+            },
+            ContractError::InterestFloorNotRaised {
+                current: Coin::default(),
+            },
+            ContractError::CannotDelegateWhileUndercollateralized {
+                denom: String::new(),
+                shortfall: Uint256::zero(),
+            },
+            ContractError::LenderUnchanged {},
+            ContractError::InvalidRestakeBps { bps: 0 },
+            ContractError::FundsNotAccepted {},
+            ContractError::InvalidRepaymentDeadline {},
+            ContractError::UnexpectedFunds {
+                denom: String::new(),
+            },
+            ContractError::NoQueuedInterest {},
+            ContractError::ReferenceTooLong { max: 0 },
+            ContractError::LiquidationAlreadyProcessed {},
+            ContractError::UnbondedCollateralShortfall {
+                denom: String::new(),
+                liquid: Uint128::zero(),
+                requested: Uint128::zero(),
+            },
+            ContractError::CancelLocked {
+                unlockable_at: Timestamp::from_seconds(0),
+            },
+            ContractError::EscrowSumOverflow {
+                denom: String::new(),
+            },
+            ContractError::LiquidationNoCollateral {},
+            ContractError::NoQualifyingOffer {
+                best: Uint256::zero(),
+            },
+            ContractError::ValidatorCommissionTooHigh {
+                validator: String::new(),
+                commission: Decimal::zero(),
+                maximum: Decimal::zero(),
+            },
+            ContractError::OwnerCannotFund {},
+            ContractError::LiquidityBelowMinimum {
+                minimum: Uint256::zero(),
+            },
+            ContractError::CollateralLiquidityDenomConflict {},
+            ContractError::ValidatorDelegationCapExceeded {
+                validator: String::new(),
+                cap: Uint128::zero(),
+            },
+            ContractError::CombinedBpsTooHigh { sum: 0 },
+        ]
+    }
+
+    #[test]
+    fn catalog_covers_every_variant_with_unique_codes() {
+        let catalog: BTreeSet<&str> = ERROR_CATALOG.iter().map(|(code, _)| *code).collect();
+        assert_eq!(
+            catalog.len(),
+            ERROR_CATALOG.len(),
+            "duplicate catalog codes"
+        );
+
+        let variants = every_variant();
+        let codes: BTreeSet<&str> = variants.iter().map(ContractError::code).collect();
+        assert_eq!(codes.len(), variants.len(), "duplicate variant codes");
+        assert_eq!(codes, catalog);
+    }
 }
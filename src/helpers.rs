@@ -1,10 +1,15 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, StdError, StdResult, Uint256};
+use cosmwasm_std::{
+    attr, to_json_binary, Addr, Attribute, Deps, DepsMut, Env, MessageInfo, StdError, StdResult,
+    Storage, SubMsg, Uint256, WasmMsg,
+};
 
 use crate::{
+    contract::reply::HOOK_REPLY_ID,
     error::ContractError,
-    state::{LENDER, OWNER},
-    types::OpenInterest,
+    msg::HookMsg,
+    state::{CONFIG, LENDER, MAX_BPS, OPERATOR, OWNER, UNCLAIMED_REFUND_TOTALS},
+    types::{Config, OpenInterest},
 };
 
 /// CwTemplateContract is a wrapper around Addr that provides a lot of helpers
@@ -18,6 +23,21 @@ impl CwTemplateContract {
     }
 }
 
+/// Stored vault tunables, or the instantiate defaults when none were written.
+pub(crate) fn load_config(storage: &dyn Storage) -> StdResult<Config> {
+    Ok(CONFIG.may_load(storage)?.unwrap_or_default())
+}
+
+/// Applies `change` to the stored tunables and saves them.
+pub(crate) fn update_config(
+    storage: &mut dyn Storage,
+    change: impl FnOnce(&mut Config),
+) -> StdResult<()> {
+    let mut config = load_config(storage)?;
+    change(&mut config);
+    CONFIG.save(storage, &config)
+}
+
 pub fn require_owner(deps: &DepsMut, info: &MessageInfo) -> Result<Addr, ContractError> {
     let owner = OWNER.load(deps.storage)?;
     if info.sender != owner {
@@ -27,6 +47,31 @@ pub fn require_owner(deps: &DepsMut, info: &MessageInfo) -> Result<Addr, Contrac
     }
 }
 
+/// Allows the owner or, for staking operations, the configured operator.
+pub fn require_owner_or_operator(
+    deps: &DepsMut,
+    info: &MessageInfo,
+) -> Result<Addr, ContractError> {
+    let owner = OWNER.load(deps.storage)?;
+    if info.sender == owner {
+        return Ok(owner);
+    }
+
+    match OPERATOR.may_load(deps.storage)? {
+        Some(operator) if info.sender == operator => Ok(operator),
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+/// Guards handlers that never take deposits so attached coins are not stranded in the vault.
+pub fn reject_funds(info: &MessageInfo) -> Result<(), ContractError> {
+    if info.funds.is_empty() {
+        Ok(())
+    } else {
+        Err(ContractError::FundsNotAccepted {})
+    }
+}
+
 pub fn require_owner_or_lender(deps: &DepsMut, info: &MessageInfo) -> Result<Addr, ContractError> {
     let owner = OWNER.load(deps.storage)?;
     if info.sender == owner {
@@ -94,7 +139,66 @@ pub fn minimum_collateral_lock_for_denom(
 
     let rewards = query_staking_rewards(deps, env)?;
     let staked = query_staked_balance(deps, env, denom)?;
+    // Slashing can shrink what unbonding returns, so staked stake counts at a discount.
+    let haircut_bps = load_config(deps.storage)?.slashing_haircut_bps;
+    let staked = staked.multiply_ratio(MAX_BPS.saturating_sub(haircut_bps), MAX_BPS);
     let coverage = rewards.checked_add(staked).map_err(StdError::from)?;
 
     Ok(interest.collateral.amount.saturating_sub(coverage))
 }
+
+/// Returns the counter offer escrow of `denom` credited to proposers but not yet claimed.
+pub fn unclaimed_refunds_for_denom(deps: &Deps, denom: &str) -> StdResult<Uint256> {
+    Ok(UNCLAIMED_REFUND_TOTALS
+        .may_load(deps.storage, denom)?
+        .unwrap_or_default())
+}
+
+#[cw_serde]
+enum HookExecuteMsg {
+    VaultHook(HookMsg),
+}
+
+/// Gas available to a hook notification, so a misbehaving receiver cannot exhaust the
+/// transaction gas that `reply` needs to swallow its failure.
+pub(crate) const HOOK_GAS_LIMIT: u64 = 500_000;
+
+/// Wraps `hook` for the configured hook contract, if any. Failures are swallowed in `reply`,
+/// which also records the outcome of every notification.
+pub(crate) fn hook_submessage(storage: &dyn Storage, hook: HookMsg) -> StdResult<Option<SubMsg>> {
+    let Some(hook_contract) = load_config(storage)?.hook_contract else {
+        return Ok(None);
+    };
+
+    let msg = WasmMsg::Execute {
+        contract_addr: hook_contract.into_string(),
+        msg: to_json_binary(&HookExecuteMsg::VaultHook(hook))?,
+        funds: vec![],
+    };
+    Ok(Some(
+        SubMsg::reply_always(msg, HOOK_REPLY_ID).with_gas_limit(HOOK_GAS_LIMIT),
+    ))
+}
+
+/// Builds `balance_before`/`balance_after` attributes for `denom`.
+///
+/// Both values are derived before the response messages execute: `balance_before` is the
+/// queried balance (already including funds attached to the call) and `balance_after` deducts
+/// the `outgoing` amount that the response will send, so message effects are never counted twice.
+pub fn balance_change_attributes(
+    deps: &Deps,
+    env: &Env,
+    denom: &str,
+    outgoing: Uint256,
+) -> StdResult<Vec<Attribute>> {
+    let before = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.to_string())?
+        .amount;
+
+    Ok(vec![
+        attr("balance_denom", denom),
+        attr("balance_before", before.to_string()),
+        attr("balance_after", before.saturating_sub(outgoing).to_string()),
+    ])
+}
@@ -1,20 +1,85 @@
-pub use crate::types::InfoResponse;
 use crate::types::OpenInterest;
+pub use crate::types::{
+    CanOpenResponse, ConfigResponse, DelegationInfo, ErrorCatalogEntry, EstimatedYieldResponse,
+    HealthResponse, HookStatusResponse, InfoResponse, InvariantCheckResponse, LenderResponse,
+    LiquidationSettlementResponse, LoanHistoryEntry, LoanState, NetPositionResponse,
+    OwnershipResponse, ReservedDenom, SettlementRecord, TermSnapshot, UnbondingEntry, VoteRecord,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Uint128, VoteOption, WeightedVoteOption};
+use cosmwasm_std::{Coin, Decimal, Uint128, Uint256, VoteOption, WeightedVoteOption};
 
 #[cw_serde]
+#[derive(Default)]
 pub struct InstantiateMsg {
     pub owner: Option<String>,
     pub liquidation_unbonding_duration: Option<u64>,
+    /// Restricts the denoms usable in open interest terms. `None` allows any denom.
+    pub allowed_denoms: Option<Vec<String>>,
+    /// Bonus paid to third-party liquidators out of the payout, in basis points.
+    pub liquidation_bonus_bps: Option<u16>,
+    /// Chain unbonding period used to estimate when undelegated funds are released.
+    pub unbonding_seconds: Option<u64>,
+    /// Longest `expiry_duration` the owner may request when opening interest.
+    pub max_expiry_duration: Option<u64>,
+    /// Smallest `liquidity_coin.amount` the owner may request when opening interest.
+    pub min_liquidity: Option<Uint256>,
+    /// Most stake the vault may hold with any single validator, enforced whenever stake moves in.
+    pub max_per_validator: Option<Uint128>,
+    /// Counter offer queue depth, between 1 and 50. Defaults to 255 when unset.
+    pub max_counter_offers: Option<u8>,
+    /// Contract notified of fund, repay, and liquidate events. Hook failures never revert them.
+    pub hook_contract: Option<String>,
+    /// Discount applied to staked balance when checking collateral coverage, in basis points.
+    pub slashing_haircut_bps: Option<u16>,
+    /// Send collateral left liquid after a fully settled liquidation to the owner.
+    pub return_liquidation_surplus: Option<bool>,
+    /// Validators liquidation unbonds from first, in order. Unlisted validators are unbonded last.
+    pub undelegation_priority: Option<Vec<String>>,
+    /// Share of repaid interest sent to `fee_recipient`, in basis points.
+    pub fee_bps: Option<u16>,
+    /// Address receiving the protocol fee. No fee is taken when unset.
+    pub fee_recipient: Option<String>,
+    /// Interest opened at instantiation. Its collateral must be attached to the instantiate call.
+    pub initial_open_interest: Option<OpenInterest>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+/// Event sent to the configured hook contract, wrapped as `{"vault_hook": <HookMsg>}`.
+#[cw_serde]
+pub enum HookMsg {
+    /// Sent when a lender funds the interest directly or the owner accepts their counter offer.
+    Funded {
+        lender: String,
+        open_interest: OpenInterest,
+    },
+    Repaid {
+        lender: String,
+        open_interest: OpenInterest,
+    },
+    Liquidated {
+        lender: String,
+        open_interest: OpenInterest,
+        payout_amount: Uint128,
+        outstanding_debt: Uint128,
+    },
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Noop {},
+    Noop {
+        /// Optional tracing data echoed back as a `memo` attribute.
+        memo: Option<String>,
+    },
     Delegate {
         validator: String,
         amount: Uint128,
+        /// Rejects the delegation if the validator's commission is above this rate.
+        max_commission: Option<Decimal>,
+    },
+    DelegateBatch {
+        delegations: Vec<(String, Uint128)>,
     },
     Undelegate {
         validator: String,
@@ -25,12 +90,32 @@ pub enum ExecuteMsg {
         dst_validator: String,
         amount: Uint128,
     },
+    RedelegateBatch {
+        moves: Vec<(String, String, Uint128)>,
+    },
     ClaimDelegatorRewards {},
+    /// Claims all rewards, restakes `restake_bps` of the claimed bonded denom to `validator`,
+    /// and sends the rest to `recipient` (the owner by default).
+    ClaimSplit {
+        validator: String,
+        restake_bps: u16,
+        recipient: Option<String>,
+    },
     Withdraw {
         denom: String,
         amount: Uint128,
         recipient: Option<String>,
     },
+    /// Withdraws everything not reserved for debt, collateral or unclaimed refunds.
+    WithdrawAll {
+        denom: String,
+        recipient: Option<String>,
+    },
+    /// Withdraws the balance in excess of all vault obligations; fails if there is none.
+    WithdrawExcess {
+        denom: String,
+        recipient: Option<String>,
+    },
     Vote {
         proposal_id: u64,
         option: VoteOption,
@@ -39,8 +124,13 @@ pub enum ExecuteMsg {
         proposal_id: u64,
         options: Vec<WeightedVoteOption>,
     },
+    VoteBatch {
+        votes: Vec<(u64, VoteOption)>,
+    },
     TransferOwnership {
         new_owner: String,
+        /// When `true`, the new owner must be a deployed contract.
+        validate_receiver: Option<bool>,
     },
     OpenInterest(OpenInterest),
     FundOpenInterest(OpenInterest),
@@ -49,10 +139,70 @@ pub enum ExecuteMsg {
         proposer: String,
         open_interest: OpenInterest,
     },
+    /// Accepts `proposer`'s offer and queues `next_interest` to open once the loan settles.
+    AcceptAndReopen {
+        proposer: String,
+        next_interest: OpenInterest,
+    },
+    /// Accepts the largest unexpired counter offer if it provides at least `min_liquidity`,
+    /// refunding the others. Owner only.
+    AcceptBestCounterOffer {
+        min_liquidity: Uint256,
+    },
+    /// Drops the interest queued by `AcceptAndReopen` before it opens. Owner only.
+    CancelQueuedInterest {},
     CancelCounterOffer {},
+    /// Adds `additional` to the sender's counter offer. The coins must be attached and the
+    /// raised offer must stay below the active liquidity.
+    IncreaseCounterOffer {
+        additional: Uint128,
+    },
+    /// Removes a counter offer past its `offer_expiry` and refunds its escrow. Callable by anyone.
+    ExpireCounterOffer {
+        proposer: String,
+    },
+    RejectCounterOffer {
+        proposer: String,
+    },
     CloseOpenInterest {},
+    /// Closes the open interest and credits counter offer escrow to `UNCLAIMED_REFUNDS`.
+    CloseToUnclaimed {},
+    ClaimRefund {},
     RepayOpenInterest {},
+    RepayPartial {
+        amount: Uint128,
+    },
+    SetPayoutAddress {
+        address: String,
+    },
+    /// Adds the attached coins to the funded loan's collateral. Owner only.
+    AddCollateral {},
+    /// Hands the funded position to `new_lender`, who receives all future payouts.
+    TransferLenderPosition {
+        new_lender: String,
+    },
     LiquidateOpenInterest {},
+    /// Emits the attributes `LiquidateOpenInterest` would, without messages or state changes.
+    DryRunLiquidate {},
+    SnapshotTerms {},
+    SetMaxCounterOffers {
+        limit: u8,
+    },
+    /// Raises the interest required from bidders, refunding counter offers below it.
+    SetInterestFloor {
+        interest_coin: Coin,
+    },
+    SetUnbondingSeconds {
+        seconds: u64,
+    },
+    /// Caps the vault's stake with any single validator. `None` removes the cap. Owner only.
+    SetMaxPerValidator {
+        cap: Option<Uint128>,
+    },
+    /// Lets `operator` delegate, undelegate, redelegate, and claim rewards. `None` revokes it.
+    SetOperator {
+        operator: Option<String>,
+    },
 }
 
 #[cw_serde]
@@ -60,4 +210,84 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(InfoResponse)]
     Info,
+    #[returns(Option<TermSnapshot>)]
+    TermSnapshot { id: u64 },
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(Option<VoteRecord>)]
+    Vote { proposal_id: u64 },
+    #[returns(InvariantCheckResponse)]
+    InvariantCheck {},
+    /// Interest currently owed to the lender, including any late fee. Empty when no loan is active.
+    #[returns(Vec<Coin>)]
+    EffectiveInterest {},
+    #[returns(LiquidationSettlementResponse)]
+    LiquidationSettlement {},
+    #[returns(Vec<DelegationInfo>)]
+    Delegations {},
+    /// Undelegations sent by the vault whose estimated completion time has not yet passed.
+    #[returns(Vec<UnbondingEntry>)]
+    Unbonding {},
+    #[returns(NetPositionResponse)]
+    NetPosition {},
+    #[returns(Option<String>)]
+    PayoutAddress {},
+    /// Coins the vault must hold for `RepayOpenInterest` to succeed, per denom. Empty without an
+    /// active loan.
+    #[returns(Vec<Coin>)]
+    RepaymentDue {},
+    /// Whether `ExecuteMsg::OpenInterest` would accept these terms right now.
+    #[returns(CanOpenResponse)]
+    CanOpen { open_interest: Box<OpenInterest> },
+    #[returns(Option<OpenInterest>)]
+    CounterOffer { proposer: String },
+    /// Up to `limit` counter offers, best first: largest liquidity amount, then proposer address.
+    #[returns(Vec<(String, OpenInterest)>)]
+    CounterOfferRanking { limit: Option<u32> },
+    #[returns(HealthResponse)]
+    Health {},
+    #[returns(Vec<ErrorCatalogEntry>)]
+    ErrorCatalog {},
+    #[returns(Option<OpenInterest>)]
+    QueuedInterest {},
+    /// Non-zero balances of the bonded denom and every denom referenced by vault state.
+    #[returns(Vec<Coin>)]
+    Balances {},
+    #[returns(HookStatusResponse)]
+    Hook {},
+    /// Rough yearly staking rewards for the current delegations.
+    #[returns(EstimatedYieldResponse)]
+    EstimatedYield {},
+    /// Amount of `denom` the owner can currently withdraw.
+    #[returns(Uint256)]
+    Withdrawable { denom: String },
+    /// Amount of `denom` held back as collateral for the open interest. Zero when no interest is
+    /// open or `denom` is not its collateral denom.
+    #[returns(Uint256)]
+    CollateralLock { denom: String },
+    /// Every non-zero reservation the vault holds, with the reason it is held.
+    #[returns(Vec<ReservedDenom>)]
+    ReservedDenoms {},
+    #[returns(OwnershipResponse)]
+    Ownership {},
+    /// Collateral the owner can withdraw after the last loan was settled by liquidation. Zero
+    /// while an interest is pending or funded, and when no liquidation left collateral behind.
+    #[returns(Coin)]
+    ReclaimableCollateral {},
+    /// How the most recent loan settled. `None` until a loan has been repaid or liquidated.
+    #[returns(Option<SettlementRecord>)]
+    LastSettlement {},
+    /// Settled loans in id order. Only the newest `MAX_LOAN_HISTORY` (100) are retained, so ids
+    /// below `next_id - 100` no longer resolve.
+    #[returns(Vec<LoanHistoryEntry>)]
+    LoanHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// The funding lender and the liquidity they provided, both `None` until the interest is funded.
+    #[returns(LenderResponse)]
+    Lender {},
+    /// Whether the vault is empty, waiting for a lender, funded, or past a settled loan.
+    #[returns(LoanState)]
+    LoanState {},
 }
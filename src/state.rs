@@ -1,25 +1,91 @@
-use crate::types::OpenInterest;
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use crate::types::{
+    Config, LoanHistoryEntry, OpenInterest, SettlementRecord, TermSnapshot, UnbondingEntry,
+    VoteRecord,
+};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint256};
 use cw_storage_plus::{Item, Map};
 
 /// Maximum number of counter offers a vault will record simultaneously.
 pub const MAX_COUNTER_OFFERS: u8 = u8::MAX;
+/// Largest counter offer capacity an owner may configure.
+pub const MAX_CONFIGURABLE_COUNTER_OFFERS: u8 = 50;
+
+/// Maximum length (in bytes) of the memo accepted by `ExecuteMsg::Noop`.
+pub const MAX_NOOP_MEMO_LENGTH: usize = 256;
+/// Maximum length (in bytes) of a loan reference.
+pub const MAX_LOAN_REFERENCE_LENGTH: usize = 128;
 
 pub const OWNER: Item<Addr> = Item::new("owner");
+/// Vault tunables set at instantiate. Read through `load_config`, which falls back to defaults.
+pub const CONFIG: Item<Config> = Item::new("config");
 pub const LENDER: Item<Option<Addr>> = Item::new("lender");
+/// Address receiving repayments and liquidation payouts instead of the lender, if set.
+pub const LENDER_PAYOUT: Item<Option<Addr>> = Item::new("lender_payout");
 pub const OUTSTANDING_DEBT: Item<Option<Coin>> = Item::new("outstanding_debt");
 pub const OPEN_INTEREST: Item<Option<OpenInterest>> = Item::new("open_interest");
 pub const OPEN_INTEREST_EXPIRY: Item<Option<Timestamp>> = Item::new("open_interest_expiry");
+/// Block time at which the active loan was funded.
+pub const OPEN_INTEREST_FUNDED: Item<Timestamp> = Item::new("open_interest_funded");
+/// Off-chain reference supplied when the current open interest was opened.
+pub const LOAN_REFERENCE: Item<Option<String>> = Item::new("loan_reference");
+/// Nonce assigned to the most recently opened interest.
+pub const INTEREST_NONCE: Item<u64> = Item::new("interest_nonce");
+/// Block time at which the active open interest was created.
+pub const OPEN_INTEREST_CREATED: Item<Option<Timestamp>> = Item::new("open_interest_created");
+/// Open interest posted automatically once the active loan settles.
+pub const QUEUED_INTEREST: Item<Option<OpenInterest>> = Item::new("queued_interest");
 pub const COUNTER_OFFERS: Map<&Addr, OpenInterest> = Map::new("counter_offers");
+/// Counter offer escrow credited on close, keyed by proposer and denom, awaiting a claim.
+pub const UNCLAIMED_REFUNDS: Map<(&Addr, &str), Uint256> = Map::new("unclaimed_refunds");
+/// Sum of `UNCLAIMED_REFUNDS` per denom, reserved from withdrawals and delegations.
+pub const UNCLAIMED_REFUND_TOTALS: Map<&str, Uint256> = Map::new("unclaimed_refund_totals");
+/// Principal already returned to the lender through partial repayments.
+pub const REPAID_PRINCIPAL: Item<Uint256> = Item::new("repaid_principal");
 
 /// Safe default for the unstaking delay used in liquidation logic.
 pub const DEFAULT_LIQUIDATION_UNBONDING_SECONDS: u64 = 21 * 24 * 60 * 60;
 /// Hard cap on custom liquidation intervals (30 days in seconds).
 pub const MAX_LIQUIDATION_UNBONDING_SECONDS: u64 = 30 * 24 * 60 * 60;
 
-pub const LIQUIDATION_UNBONDING_DURATION: Item<u64> = Item::new("liquidation_unbonding_duration");
 pub const LAST_LIQUIDATION_UNBONDING: Item<Option<Timestamp>> =
     Item::new("last_liquidation_unbonding");
+/// Undelegations sent by the vault, pruned once their estimated completion time has passed.
+pub const UNBONDING_ENTRIES: Item<Vec<UnbondingEntry>> = Item::new("unbonding_entries");
+/// Block height of the most recent liquidation, so it cannot run twice in one block.
+pub const LAST_LIQUIDATION_HEIGHT: Item<u64> = Item::new("last_liquidation_height");
+/// Collateral paid to the lender so far during the active liquidation.
+pub const LIQUIDATION_PAID: Item<Uint256> = Item::new("liquidation_paid");
+/// Collateral undelegated by the most recent liquidation and still unbonding.
+pub const LIQUIDATION_UNBONDING_AMOUNT: Item<Uint256> = Item::new("liquidation_unbonding_amount");
+
+/// Upper bound for basis point values (100%).
+pub const MAX_BPS: u16 = 10_000;
+/// Caller of the most recent liquidation, kept for keeper accounting.
+pub const LAST_LIQUIDATOR: Item<Option<Addr>> = Item::new("last_liquidator");
+/// Outcome of the most recently settled loan, whether repaid or liquidated.
+pub const LAST_SETTLEMENT: Item<SettlementRecord> = Item::new("last_settlement");
+/// Set when a loan settles and removed when the next interest opens, so `LAST_SETTLEMENT` only
+/// marks the vault as settled until it is reused.
+pub const LOAN_SETTLED: Item<bool> = Item::new("loan_settled");
+/// Number of settled loans `LOAN_HISTORY` retains; older entries are evicted.
+pub const MAX_LOAN_HISTORY: u64 = 100;
+pub const NEXT_LOAN_HISTORY_ID: Item<u64> = Item::new("next_loan_history_id");
+/// The most recent `MAX_LOAN_HISTORY` settled loans, keyed by id.
+pub const LOAN_HISTORY: Map<u64, LoanHistoryEntry> = Map::new("loan_history");
+/// Collateral denom of the most recent loan settled by liquidation.
+pub const LIQUIDATED_COLLATERAL_DENOM: Item<String> = Item::new("liquidated_collateral_denom");
+
+/// Hot wallet allowed to run staking operations on the owner's behalf. Unset means owner only.
+pub const OPERATOR: Item<Addr> = Item::new("operator");
+
+/// Whether the most recent hook notification failed. Unset until the first notification.
+pub const LAST_HOOK_FAILED: Item<bool> = Item::new("last_hook_failed");
+
+/// Most recent governance vote submitted by the vault, keyed by proposal id.
+pub const VOTES: Map<u64, VoteRecord> = Map::new("votes");
+
+pub const NEXT_TERM_SNAPSHOT_ID: Item<u64> = Item::new("next_term_snapshot_id");
+pub const TERM_SNAPSHOTS: Map<u64, TermSnapshot> = Map::new("term_snapshots");
 
 #[cfg(test)]
 mod tests {
@@ -98,6 +164,7 @@ mod tests {
             interest_coin: Coin::new(5u128, "uusd"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(200u128, "ujuno"),
+            ..Default::default()
         };
 
         OPEN_INTEREST
@@ -155,12 +222,14 @@ mod tests {
             interest_coin: Coin::new(5u128, "uusd"),
             expiry_duration: 86_400u64,
             collateral: Coin::new(200u128, "ujuno"),
+            ..Default::default()
         };
         let entry_b = OpenInterest {
             liquidity_coin: Coin::new(250u128, "uusd"),
             interest_coin: Coin::new(15u128, "uusd"),
             expiry_duration: 120_000u64,
             collateral: Coin::new(225u128, "ujuno"),
+            ..Default::default()
         };
 
         COUNTER_OFFERS
@@ -1,5 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128, Uint256, WeightedVoteOption};
+
+use crate::state::{DEFAULT_LIQUIDATION_UNBONDING_SECONDS, MAX_COUNTER_OFFERS};
 
 #[cw_serde]
 pub struct InfoResponse {
@@ -8,9 +10,137 @@ pub struct InfoResponse {
     pub lender: Option<String>,
     pub open_interest: Option<OpenInterest>,
     pub counter_offers: Option<Vec<CounterOffer>>,
+    /// When the active loan becomes liquidatable. `None` while no lender is set.
+    pub expiry: Option<Timestamp>,
+    /// Whether the active loan has passed its expiry and can be liquidated now.
+    pub liquidatable: bool,
+    /// Off-chain reference attached to the current loan, if any.
+    pub reference: Option<String>,
+    /// Nonce of the most recently opened interest; zero if none was ever opened.
+    pub interest_nonce: u64,
+    /// Caller of the most recent liquidation, if the vault was ever liquidated.
+    pub last_liquidator: Option<String>,
+}
+
+/// Vault tunables, written at instantiate and read back through `QueryMsg::Config`.
+#[cw_serde]
+pub struct Config {
+    pub liquidation_unbonding_duration: u64,
+    /// Denoms accepted in open interest terms. `None` means any denom is allowed.
+    pub allowed_denoms: Option<Vec<String>>,
+    /// Maximum number of counter offers recorded simultaneously.
+    pub max_counter_offers: u8,
+    /// Share of each third-party liquidation payout paid to the caller, in basis points.
+    pub liquidation_bonus_bps: u16,
+    /// Chain unbonding period used for settlement estimates. Falls back to the liquidation delay.
+    pub unbonding_seconds: Option<u64>,
+    /// Upper bound on `expiry_duration` for new open interests. Unbounded when `None`.
+    pub max_expiry_duration: Option<u64>,
+    /// Smallest `liquidity_coin.amount` accepted for new open interests. Unbounded when `None`.
+    pub min_liquidity: Option<Uint256>,
+    /// Most stake the vault may hold with any single validator. Uncapped when `None`.
+    pub max_per_validator: Option<Uint128>,
+    /// Contract notified with a `HookMsg` on fund, repay, and liquidate. No hook when `None`.
+    pub hook_contract: Option<Addr>,
+    /// Share of staked balance discounted from collateral coverage to allow for slashing, in
+    /// basis points.
+    pub slashing_haircut_bps: u16,
+    /// Whether a fully settled liquidation sends leftover liquid collateral to the owner.
+    /// Otherwise it simply becomes withdrawable.
+    pub return_liquidation_surplus: bool,
+    /// Validators unbonded first, in order, when liquidation undelegates stake. Others follow in
+    /// querier order.
+    pub undelegation_priority: Vec<String>,
+    /// Share of repaid interest skimmed to `fee_recipient`, in basis points.
+    pub fee_bps: u16,
+    /// Address receiving the protocol fee. No fee is taken while `None`.
+    pub fee_recipient: Option<Addr>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            liquidation_unbonding_duration: DEFAULT_LIQUIDATION_UNBONDING_SECONDS,
+            allowed_denoms: None,
+            max_counter_offers: MAX_COUNTER_OFFERS,
+            liquidation_bonus_bps: 0,
+            unbonding_seconds: None,
+            max_expiry_duration: None,
+            min_liquidity: None,
+            max_per_validator: None,
+            hook_contract: None,
+            slashing_haircut_bps: 0,
+            return_liquidation_surplus: false,
+            undelegation_priority: vec![],
+            fee_bps: 0,
+            fee_recipient: None,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub liquidation_unbonding_duration: u64,
+    /// Denoms accepted in open interest terms. `None` means any denom is allowed.
+    pub allowed_denoms: Option<Vec<String>>,
+    /// Maximum number of counter offers recorded simultaneously.
+    pub max_counter_offers: u8,
+    /// Bonus paid to third-party liquidators, in basis points.
+    pub liquidation_bonus_bps: u16,
+    /// Configured chain unbonding period, if set.
+    pub unbonding_seconds: Option<u64>,
+    /// Longest allowed open interest `expiry_duration`, if bounded.
+    pub max_expiry_duration: Option<u64>,
+    /// Smallest open interest `liquidity_coin.amount`, if bounded.
+    pub min_liquidity: Option<Uint256>,
+    /// Most stake held with any single validator, if capped.
+    pub max_per_validator: Option<Uint128>,
+    /// Contract notified of fund, repay, and liquidate events, if any.
+    pub hook_contract: Option<String>,
+    /// Address allowed to run staking operations alongside the owner, if any.
+    pub operator: Option<String>,
+    /// Discount applied to staked balance when checking collateral coverage, in basis points.
+    pub slashing_haircut_bps: u16,
+    /// Whether collateral left liquid after a full liquidation is sent back to the owner.
+    pub return_liquidation_surplus: bool,
+    /// Validators liquidation unbonds from first, in order.
+    pub undelegation_priority: Vec<String>,
+    /// Share of repaid interest taken as a protocol fee, in basis points.
+    pub fee_bps: u16,
+    /// Address receiving the protocol fee, if any.
+    pub fee_recipient: Option<String>,
+}
+
+#[cw_serde]
+pub struct CanOpenResponse {
+    pub ok: bool,
+    /// Why `OpenInterest` would fail, when `ok` is false.
+    pub reason: Option<String>,
+}
+
+#[cw_serde]
+pub struct OwnershipResponse {
+    pub owner: String,
+    /// Address nominated to take over ownership. Transfers are single-step, so always `None`.
+    pub pending_owner: Option<String>,
+}
+
+#[cw_serde]
+pub struct LenderResponse {
+    pub lender: Option<String>,
+    /// Liquidity the lender provided, taken from the active open interest.
+    pub funded_amount: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct HookStatusResponse {
+    pub hook_contract: Option<String>,
+    /// Whether the most recent hook notification failed.
+    pub last_hook_failed: bool,
 }
 
 #[cw_serde]
+#[derive(Default)]
 pub struct OpenInterest {
     /// Coin the borrower wants to receive as liquidity.
     pub liquidity_coin: Coin,
@@ -20,6 +150,25 @@ pub struct OpenInterest {
     pub expiry_duration: u64,
     /// Collateral provided to secure the open interest.
     pub collateral: Coin,
+    /// Minimum time (in seconds) the open interest must stay open before the owner may close it.
+    pub min_open_duration: Option<u64>,
+    /// Time after which a counter offer can no longer be accepted. Ignored on the owner's terms.
+    pub offer_expiry: Option<Timestamp>,
+    /// Time before which the proposer cannot cancel their counter offer. Ignored on the owner's
+    /// terms.
+    pub cancellable_after: Option<Timestamp>,
+    /// Time (in seconds) after funding by which repayment is due. Liquidation still waits for
+    /// `expiry_duration`.
+    pub repayment_deadline_duration: Option<u64>,
+    /// Fee owed on repayments after the deadline, in basis points of the interest coin.
+    pub late_fee_bps: Option<u16>,
+    /// Off-chain identifier for the loan. Only read by `ExecuteMsg::OpenInterest`, which moves it
+    /// out of the stored terms and into `LOAN_REFERENCE`.
+    pub reference: Option<String>,
+    /// Assigned by the vault each time an interest opens; any value supplied on open is
+    /// overwritten. Funders and proposers must echo the live nonce.
+    #[serde(default)]
+    pub interest_nonce: u64,
 }
 
 #[cw_serde]
@@ -29,3 +178,162 @@ pub struct CounterOffer {
     /// Proposed open interest terms. Only the amount should deviate.
     pub open_interest: OpenInterest,
 }
+
+/// How the most recent loan ended.
+#[cw_serde]
+pub struct SettlementRecord {
+    /// `repaid` or `liquidated`.
+    pub outcome: String,
+    pub lender: String,
+    /// Caller of the liquidation that settled the loan. `None` for repayments.
+    pub liquidator: Option<String>,
+    /// Coins paid out to settle the loan, before any protocol fee.
+    pub repaid: Vec<Coin>,
+    pub settled_at: Timestamp,
+    /// Off-chain reference attached when the loan was opened, if any.
+    pub reference: Option<String>,
+}
+
+#[cw_serde]
+pub enum LoanOutcome {
+    Repaid,
+    Liquidated,
+}
+
+/// Where the vault's loan stands, derived from the open interest, lender, and last settlement.
+#[cw_serde]
+pub enum LoanState {
+    /// No open interest, and no loan has settled since the vault last opened one.
+    Empty,
+    /// Open interest waiting for a lender.
+    Pending,
+    /// Open interest funded by a lender and awaiting repayment or liquidation.
+    Funded,
+    /// No open interest, and the most recent loan was repaid or liquidated since the last open.
+    Settled,
+}
+
+/// Terms and outcome of a settled loan, kept in `LOAN_HISTORY`.
+#[cw_serde]
+pub struct LoanHistoryEntry {
+    /// Sequential identifier assigned when the loan settled.
+    pub id: u64,
+    pub lender: String,
+    pub liquidity: Coin,
+    pub interest: Coin,
+    pub collateral: Coin,
+    pub outcome: LoanOutcome,
+    pub settled_at: Timestamp,
+    /// Sender of the liquidation that settled the loan. `None` for repaid loans.
+    pub liquidator: Option<String>,
+    /// Owner-supplied reference the loan was opened with.
+    pub reference: Option<String>,
+}
+
+#[cw_serde]
+pub struct TermSnapshot {
+    /// Sequential identifier assigned when the snapshot was recorded.
+    pub id: u64,
+    /// Block time at which the snapshot was taken.
+    pub taken_at: Timestamp,
+    /// Address that requested the snapshot.
+    pub taken_by: String,
+    /// Open interest terms active at the time of the snapshot.
+    pub open_interest: OpenInterest,
+    pub lender: Option<String>,
+    pub expiry: Option<Timestamp>,
+    pub outstanding_debt: Option<Coin>,
+    /// Contract balances for every denom referenced by the open interest.
+    pub balances: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct VoteRecord {
+    /// Options submitted by the vault. Standard votes are stored as a single full-weight option.
+    pub options: Vec<WeightedVoteOption>,
+    /// Block height at which the vote was submitted.
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct DelegationInfo {
+    pub validator: String,
+    pub staked: Coin,
+    /// Rewards accrued on this delegation; empty when the chain does not report them.
+    pub accumulated_rewards: Vec<Coin>,
+}
+
+/// Stake leaving a validator, recorded when the vault sends the undelegation. The staking
+/// querier does not expose unbonding delegations, so `completion_time` is estimated from the
+/// configured unbonding period.
+#[cw_serde]
+pub struct UnbondingEntry {
+    pub validator: String,
+    pub amount: Coin,
+    pub completion_time: Timestamp,
+}
+
+#[cw_serde]
+pub struct LiquidationSettlementResponse {
+    /// Collateral already paid to the lender by liquidation.
+    pub immediate_paid: Uint256,
+    /// Collateral undelegated by the latest liquidation and awaiting release.
+    pub unbonding_amount: Uint256,
+    /// When the unbonding collateral should become available, if known.
+    pub estimated_completion: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct NetPositionResponse {
+    /// Non-zero balances held in the bonded denom and the denoms referenced by the vault state.
+    pub liquid: Vec<Coin>,
+    /// Total amount delegated in the bonded denom.
+    pub staked: Coin,
+    /// Staking rewards accrued but not yet withdrawn.
+    pub pending_rewards: Vec<Coin>,
+    /// Counter offer escrow owed back to proposers.
+    pub reserved_debt: Option<Coin>,
+    /// Collateral that must stay in the vault for the open interest.
+    pub collateral_locked: Uint256,
+}
+
+#[cw_serde]
+pub struct EstimatedYieldResponse {
+    /// Projected yearly staking rewards, or `None` when no reward rate can be queried on-chain.
+    pub annual_rewards: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct ReservedDenom {
+    pub denom: String,
+    /// Why the amount is held: `counter_offer_escrow`, `outstanding_debt`, `collateral_lock`, or
+    /// `unclaimed_refunds`.
+    pub reason: String,
+    pub amount: Uint256,
+}
+
+#[cw_serde]
+pub struct HealthResponse {
+    /// Liquid balance of the collateral denom held by the vault.
+    pub collateral_available: Uint256,
+    /// Collateral that must remain liquid once staked funds and rewards are counted.
+    pub required_lock: Uint256,
+    /// True when the liquid balance covers the required lock.
+    pub is_covered: bool,
+}
+
+#[cw_serde]
+pub struct ErrorCatalogEntry {
+    /// Stable identifier clients can match on.
+    pub code: String,
+    /// Message template, with `{field}` placeholders for variant data.
+    pub description: String,
+}
+
+#[cw_serde]
+pub struct InvariantCheckResponse {
+    /// True when no invariant violations were detected.
+    pub healthy: bool,
+    /// Human-readable descriptions of each violated invariant.
+    pub violations: Vec<String>,
+}
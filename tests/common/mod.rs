@@ -4,12 +4,14 @@ use cosmwasm_std::{
     Addr, Coin, Decimal, Decimal256, Empty, Validator,
 };
 use cw_multi_test::{
-    App, AppBuilder, BankKeeper, BankSudo, BasicApp, ContractWrapper, DistributionKeeper,
+    App, AppBuilder, BankKeeper, BankSudo, BasicApp, ContractWrapper, DistributionKeeper, Executor,
     FailingModule, Gov, GovAcceptingModule, GovFailingModule, IbcFailingModule, StakeKeeper,
     StakingInfo, StargateFailing, WasmKeeper,
 };
 
-use wasm_vault::contract::{execute, instantiate, query};
+use wasm_vault::contract::{execute, instantiate, migrate, query, reply};
+use wasm_vault::msg::{ExecuteMsg, InfoResponse, QueryMsg};
+use wasm_vault::types::OpenInterest;
 
 pub const DENOM: &str = "ucosm";
 const CREATOR_FUNDS: u128 = 1_000_000;
@@ -98,7 +100,9 @@ fn build_app_with_gov<G: Gov>(gov: G) -> VaultApp<G> {
 }
 
 pub fn store_contract<G: Gov>(app: &mut VaultApp<G>) -> u64 {
-    let contract = ContractWrapper::new(execute, instantiate, query);
+    let contract = ContractWrapper::new(execute, instantiate, query)
+        .with_migrate(migrate)
+        .with_reply(reply);
     app.store_code(Box::new(contract))
 }
 
@@ -112,3 +116,25 @@ pub fn mint_contract_collateral(app: &mut BasicApp, contract_addr: &Addr, collat
     )
     .expect("mint collateral");
 }
+
+/// Opens `interest` as `owner` and returns it as stored, stamped with the nonce funders must echo.
+pub fn open_stamped_interest(
+    app: &mut BasicApp,
+    contract_addr: &Addr,
+    owner: &Addr,
+    interest: &OpenInterest,
+) -> OpenInterest {
+    app.execute_contract(
+        owner.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::OpenInterest(interest.clone()),
+        &[],
+    )
+    .expect("open interest set");
+
+    let info: InfoResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::Info)
+        .expect("info query succeeds");
+    info.open_interest.expect("open interest stored")
+}
@@ -1,6 +1,7 @@
 mod test_claim_rewards;
 mod test_counter_offer;
 mod test_delegate;
+mod test_hook;
 mod test_instantiate;
 mod test_open_interest;
 mod test_redelegate;
@@ -1,4 +1,4 @@
-use cosmwasm_std::{coins, BankMsg, Uint128};
+use cosmwasm_std::{coins, BankMsg, Uint128, Uint256};
 use cw_multi_test::Executor;
 
 use crate::common::{mock_app, store_contract, DENOM};
@@ -18,6 +18,7 @@ fn owner_can_claim_rewards_from_all_validators() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -42,6 +43,7 @@ fn owner_can_claim_rewards_from_all_validators() {
         &ExecuteMsg::Delegate {
             validator: validator_one,
             amount: Uint128::new(600),
+            max_commission: None,
         },
         &[],
     )
@@ -54,6 +56,7 @@ fn owner_can_claim_rewards_from_all_validators() {
         &ExecuteMsg::Delegate {
             validator: validator_two,
             amount: Uint128::new(300),
+            max_commission: None,
         },
         &[],
     )
@@ -114,6 +117,7 @@ fn non_owner_cannot_claim_rewards() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -132,3 +136,94 @@ fn non_owner_cannot_claim_rewards() {
 
     assert!(err.to_string().contains("Unauthorized"));
 }
+
+#[test]
+fn claim_split_restakes_half_and_withdraws_half() {
+    let mut app = mock_app();
+    let code_id = store_contract(&mut app);
+
+    let owner = app.api().addr_make("creator");
+    let recipient = app.api().addr_make("recipient");
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: Some(owner.to_string()),
+                ..Default::default()
+            },
+            &coins(1_000, DENOM),
+            "vault",
+            None,
+        )
+        .expect("instantiate succeeds");
+
+    let validator = app.api().addr_make("validator").into_string();
+    app.execute_contract(
+        owner.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::Delegate {
+            validator: validator.clone(),
+            amount: Uint128::new(1_000),
+            max_commission: None,
+        },
+        &[],
+    )
+    .expect("delegate succeeds");
+
+    app.update_block(|block| {
+        block.height += 1_000;
+        block.time = block.time.plus_seconds(365 * 24 * 60 * 60);
+    });
+
+    let response = app
+        .execute_contract(
+            owner,
+            contract_addr.clone(),
+            &ExecuteMsg::ClaimSplit {
+                validator: validator.clone(),
+                restake_bps: 5_000,
+                recipient: Some(recipient.to_string()),
+            },
+            &[],
+        )
+        .expect("claim split succeeds");
+
+    let split_attr = |key: &str| -> Uint256 {
+        response
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.parse().expect("amount parses"))
+            .expect("split attribute emitted")
+    };
+    let restaked = split_attr("restaked");
+    let withdrawn = split_attr("withdrawn");
+
+    // 12% APR on 1000 staked, less the 5% validator commission, over one year.
+    assert_eq!(restaked + withdrawn, Uint256::from(114u128));
+    assert_eq!(restaked, withdrawn);
+
+    let delegation = app
+        .wrap()
+        .query_delegation(contract_addr.clone(), validator)
+        .expect("delegation query succeeds")
+        .expect("delegation exists");
+    assert_eq!(
+        delegation.amount.amount,
+        Uint256::from(1_000u128) + restaked
+    );
+
+    let recipient_balance = app
+        .wrap()
+        .query_balance(recipient, DENOM)
+        .expect("balance query succeeds");
+    assert_eq!(recipient_balance.amount, withdrawn);
+
+    let vault_balance = app
+        .wrap()
+        .query_balance(contract_addr, DENOM)
+        .expect("balance query succeeds");
+    assert!(vault_balance.amount.is_zero());
+}
@@ -1,7 +1,9 @@
 use cosmwasm_std::{coins, Addr, Coin, Uint256};
 use cw_multi_test::{BasicApp, Executor};
 
-use crate::common::{mint_contract_collateral, mock_app, store_contract, DENOM};
+use crate::common::{
+    mint_contract_collateral, mock_app, open_stamped_interest, store_contract, DENOM,
+};
 use wasm_vault::msg::{ExecuteMsg, InfoResponse, InstantiateMsg, QueryMsg};
 use wasm_vault::types::OpenInterest;
 
@@ -17,6 +19,7 @@ fn instantiate_vault() -> (BasicApp, Addr, Addr) {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -36,17 +39,12 @@ fn owner_accepts_counter_offer_and_refunds_others() {
         interest_coin: Coin::new(50u128, "uinterest"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(2_000u128, "ucollateral"),
+        ..Default::default()
     };
 
     mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
 
-    app.execute_contract(
-        owner.clone(),
-        contract_addr.clone(),
-        &ExecuteMsg::OpenInterest(open_interest.clone()),
-        &[],
-    )
-    .expect("open interest set");
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
 
     let proposer_a = app.api().addr_make("user");
     let proposer_b = app.api().addr_make("lender-two");
@@ -148,3 +146,92 @@ fn owner_accepts_counter_offer_and_refunds_others() {
     assert!(info.counter_offers.is_none());
     assert_eq!(info.open_interest, Some(offer_a));
 }
+
+#[test]
+fn repay_activates_interest_queued_on_accept() {
+    let (mut app, contract_addr, owner) = instantiate_vault();
+
+    let open_interest = OpenInterest {
+        liquidity_coin: Coin::new(1_000u128, DENOM),
+        interest_coin: Coin::new(50u128, DENOM),
+        expiry_duration: 86_400u64,
+        collateral: Coin::new(2_000u128, "ucollateral"),
+        ..Default::default()
+    };
+    let next_interest = OpenInterest {
+        liquidity_coin: Coin::new(1_500u128, DENOM),
+        expiry_duration: 172_800u64,
+        ..open_interest.clone()
+    };
+
+    mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
+
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
+
+    let lender = app.api().addr_make("user");
+    let mut offer = open_interest.clone();
+    offer.liquidity_coin.amount = Uint256::from(900u128);
+    app.execute_contract(
+        lender.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::ProposeCounterOffer(offer.clone()),
+        &[offer.liquidity_coin.clone()],
+    )
+    .expect("offer stored");
+
+    app.execute_contract(
+        owner.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::AcceptAndReopen {
+            proposer: lender.to_string(),
+            next_interest: next_interest.clone(),
+        },
+        &[],
+    )
+    .expect("accept queues next interest");
+
+    let queued: Option<OpenInterest> = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::QueuedInterest {})
+        .expect("queued interest query succeeds");
+    assert_eq!(queued, Some(next_interest.clone()));
+
+    app.send_tokens(owner.clone(), contract_addr.clone(), &coins(50, DENOM))
+        .expect("deposit interest");
+
+    let response = app
+        .execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::RepayOpenInterest {},
+            &[],
+        )
+        .expect("repay succeeds");
+
+    assert!(response.events.iter().any(|event| {
+        event.ty == "wasm"
+            && event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "open_interest" && attr.value == "1500ucosm")
+    }));
+
+    let info: InfoResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::Info)
+        .expect("info query succeeds");
+    assert_eq!(
+        info.open_interest,
+        Some(OpenInterest {
+            interest_nonce: 2,
+            ..next_interest
+        })
+    );
+    assert!(info.lender.is_none());
+
+    let queued: Option<OpenInterest> = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::QueuedInterest {})
+        .expect("queued interest query succeeds");
+    assert!(queued.is_none());
+}
@@ -3,7 +3,8 @@ use cw_multi_test::Executor;
 
 use crate::common::{mock_app, store_contract, DENOM};
 
-use wasm_vault::msg::{ExecuteMsg, InstantiateMsg};
+use wasm_vault::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use wasm_vault::types::EstimatedYieldResponse;
 
 #[test]
 fn owner_can_delegate_existing_vault_funds() {
@@ -18,6 +19,7 @@ fn owner_can_delegate_existing_vault_funds() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -45,6 +47,7 @@ fn owner_can_delegate_existing_vault_funds() {
             &ExecuteMsg::Delegate {
                 validator: validator.clone(),
                 amount,
+                max_commission: None,
             },
             &[],
         )
@@ -89,6 +92,7 @@ fn non_owner_cannot_delegate() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -103,6 +107,7 @@ fn non_owner_cannot_delegate() {
             &ExecuteMsg::Delegate {
                 validator: app.api().addr_make("validator").into_string(),
                 amount: Uint128::new(100),
+                max_commission: None,
             },
             &[],
         )
@@ -110,3 +115,86 @@ fn non_owner_cannot_delegate() {
 
     assert!(err.to_string().contains("Unauthorized"));
 }
+
+#[test]
+fn estimated_yield_is_unavailable_without_on_chain_apr() {
+    let mut app = mock_app();
+    let code_id = store_contract(&mut app);
+
+    let owner = app.api().addr_make("creator");
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: Some(owner.to_string()),
+                ..Default::default()
+            },
+            &coins(500, DENOM),
+            "vault",
+            None,
+        )
+        .expect("instantiate succeeds");
+
+    app.execute_contract(
+        owner,
+        contract_addr.clone(),
+        &ExecuteMsg::Delegate {
+            validator: app.api().addr_make("validator").into_string(),
+            amount: Uint128::new(400),
+            max_commission: None,
+        },
+        &[],
+    )
+    .expect("delegate succeeds");
+
+    // The staking module accrues rewards at 12% APR, but contracts cannot query that rate.
+    let response: EstimatedYieldResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::EstimatedYield {})
+        .expect("query succeeds");
+
+    assert_eq!(response.annual_rewards, None);
+}
+
+#[test]
+fn delegate_with_attached_funds_is_rejected() {
+    let mut app = mock_app();
+    let code_id = store_contract(&mut app);
+
+    let owner = app.api().addr_make("creator");
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: Some(owner.to_string()),
+                ..Default::default()
+            },
+            &coins(500, DENOM),
+            "vault",
+            None,
+        )
+        .expect("instantiate succeeds");
+
+    let err = app
+        .execute_contract(
+            owner,
+            contract_addr.clone(),
+            &ExecuteMsg::Delegate {
+                validator: app.api().addr_make("validator").into_string(),
+                amount: Uint128::new(100),
+                max_commission: None,
+            },
+            &coins(100, DENOM),
+        )
+        .unwrap_err();
+
+    assert!(err.to_string().contains("does not accept funds"));
+
+    let balance = app
+        .wrap()
+        .query_balance(contract_addr, DENOM)
+        .expect("balance query should succeed");
+    assert_eq!(balance.amount, Uint256::from(500u128));
+}
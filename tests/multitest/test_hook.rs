@@ -0,0 +1,226 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdError, StdResult,
+};
+use cw_multi_test::{AppResponse, BasicApp, ContractWrapper, Executor};
+use cw_storage_plus::Item;
+
+use crate::common::{
+    mint_contract_collateral, mock_app, open_stamped_interest, store_contract, DENOM,
+};
+use wasm_vault::msg::{ExecuteMsg, HookMsg, HookStatusResponse, InstantiateMsg, QueryMsg};
+use wasm_vault::types::OpenInterest;
+
+const RECEIVED: Item<Vec<HookMsg>> = Item::new("received");
+
+#[cw_serde]
+enum ReceiverMsg {
+    VaultHook(HookMsg),
+}
+
+fn receiver_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    RECEIVED.save(deps.storage, &vec![])?;
+    Ok(Response::new())
+}
+
+fn recording_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ReceiverMsg,
+) -> StdResult<Response> {
+    let ReceiverMsg::VaultHook(hook) = msg;
+    RECEIVED.update(deps.storage, |mut received| -> StdResult<_> {
+        received.push(hook);
+        Ok(received)
+    })?;
+    Ok(Response::new())
+}
+
+fn failing_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: ReceiverMsg,
+) -> StdResult<Response> {
+    Err(StdError::msg("hook rejected"))
+}
+
+fn receiver_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&RECEIVED.load(deps.storage)?)
+}
+
+fn instantiate_with_hook(failing: bool) -> (BasicApp, Addr, Addr, Addr) {
+    let mut app = mock_app();
+    let owner = app.api().addr_make("creator");
+
+    let receiver_code = if failing {
+        app.store_code(Box::new(ContractWrapper::new(
+            failing_execute,
+            receiver_instantiate,
+            receiver_query,
+        )))
+    } else {
+        app.store_code(Box::new(ContractWrapper::new(
+            recording_execute,
+            receiver_instantiate,
+            receiver_query,
+        )))
+    };
+    let hook_addr = app
+        .instantiate_contract(receiver_code, owner.clone(), &Empty {}, &[], "hook", None)
+        .expect("hook receiver instantiated");
+
+    let code_id = store_contract(&mut app);
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: Some(owner.to_string()),
+                hook_contract: Some(hook_addr.to_string()),
+                ..Default::default()
+            },
+            &[],
+            "vault",
+            None,
+        )
+        .expect("instantiate succeeds");
+
+    (app, contract_addr, owner, hook_addr)
+}
+
+fn open_and_fund(
+    app: &mut BasicApp,
+    contract_addr: &Addr,
+    owner: &Addr,
+) -> (OpenInterest, Addr, AppResponse) {
+    let open_interest = OpenInterest {
+        liquidity_coin: Coin::new(1_000u128, DENOM),
+        interest_coin: Coin::new(50u128, DENOM),
+        expiry_duration: 86_400u64,
+        collateral: Coin::new(2_000u128, "ucollateral"),
+        ..Default::default()
+    };
+    mint_contract_collateral(app, contract_addr, &open_interest.collateral);
+
+    let open_interest = open_stamped_interest(app, contract_addr, owner, &open_interest);
+
+    let lender = app.api().addr_make("user");
+    let response = app
+        .execute_contract(
+            lender.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::FundOpenInterest(open_interest.clone()),
+            &[open_interest.liquidity_coin.clone()],
+        )
+        .expect("funding succeeds");
+
+    (open_interest, lender, response)
+}
+
+#[test]
+fn hook_receives_fund_and_repay_events() {
+    let (mut app, contract_addr, owner, hook_addr) = instantiate_with_hook(false);
+    let (open_interest, lender, _) = open_and_fund(&mut app, &contract_addr, &owner);
+
+    app.send_tokens(owner.clone(), contract_addr.clone(), &coins(50, DENOM))
+        .expect("deposit interest");
+    app.execute_contract(
+        owner.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::RepayOpenInterest {},
+        &[],
+    )
+    .expect("repay succeeds");
+
+    let received: Vec<HookMsg> = app
+        .wrap()
+        .query_wasm_smart(hook_addr, &Empty {})
+        .expect("hook query succeeds");
+    assert_eq!(
+        received,
+        vec![
+            HookMsg::Funded {
+                lender: lender.to_string(),
+                open_interest: open_interest.clone(),
+            },
+            HookMsg::Repaid {
+                lender: lender.to_string(),
+                open_interest,
+            },
+        ]
+    );
+}
+
+#[test]
+fn hook_receives_funded_event_on_accepted_counter_offer() {
+    let (mut app, contract_addr, owner, hook_addr) = instantiate_with_hook(false);
+    let open_interest = OpenInterest {
+        liquidity_coin: Coin::new(1_000u128, DENOM),
+        interest_coin: Coin::new(50u128, DENOM),
+        expiry_duration: 86_400u64,
+        collateral: Coin::new(2_000u128, "ucollateral"),
+        ..Default::default()
+    };
+    mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
+
+    let proposer = app.api().addr_make("user");
+    let offer = OpenInterest {
+        liquidity_coin: Coin::new(900u128, DENOM),
+        ..open_interest.clone()
+    };
+    app.execute_contract(
+        proposer.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::ProposeCounterOffer(offer.clone()),
+        &[offer.liquidity_coin.clone()],
+    )
+    .expect("offer stored");
+
+    app.execute_contract(
+        owner.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::AcceptBestCounterOffer {
+            min_liquidity: offer.liquidity_coin.amount,
+        },
+        &[],
+    )
+    .expect("accept succeeds");
+
+    let received: Vec<HookMsg> = app
+        .wrap()
+        .query_wasm_smart(hook_addr, &Empty {})
+        .expect("hook query succeeds");
+    assert_eq!(
+        received,
+        vec![HookMsg::Funded {
+            lender: proposer.to_string(),
+            open_interest: offer,
+        }]
+    );
+}
+
+#[test]
+fn failing_hook_does_not_revert_funding() {
+    let (mut app, contract_addr, owner, _) = instantiate_with_hook(true);
+    let (_, _, response) = open_and_fund(&mut app, &contract_addr, &owner);
+
+    assert!(response.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "hook_failed")));
+
+    let status: HookStatusResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::Hook {})
+        .expect("hook status query succeeds");
+    assert!(status.last_hook_failed);
+}
@@ -18,6 +18,7 @@ fn instantiate_respects_explicit_owner() {
     let instantiate_msg = InstantiateMsg {
         owner: Some(explicit_owner.to_string()),
         liquidation_unbonding_duration: None,
+        ..Default::default()
     };
 
     let response = app
@@ -68,6 +69,7 @@ fn instantiate_defaults_to_sender() {
     let instantiate_msg = InstantiateMsg {
         owner: None,
         liquidation_unbonding_duration: None,
+        ..Default::default()
     };
 
     let response = app
@@ -2,7 +2,9 @@ use cosmwasm_std::{coins, Addr, Coin, Uint128, Uint256};
 use cw_multi_test::{BasicApp, Executor};
 use std::convert::TryFrom;
 
-use crate::common::{mint_contract_collateral, mock_app, store_contract, DENOM};
+use crate::common::{
+    mint_contract_collateral, mock_app, open_stamped_interest, store_contract, DENOM,
+};
 use wasm_vault::msg::{ExecuteMsg, InfoResponse, InstantiateMsg, QueryMsg};
 use wasm_vault::types::OpenInterest;
 
@@ -28,6 +30,7 @@ fn instantiate_vault() -> (BasicApp, Addr, Addr) {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -47,6 +50,7 @@ fn owner_can_open_interest_once() {
         interest_coin: Coin::new(50u128, "ujuno"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(2_000u128, "uatom"),
+        ..Default::default()
     };
 
     mint_contract_collateral(&mut app, &contract_addr, &request.collateral);
@@ -73,7 +77,14 @@ fn owner_can_open_interest_once() {
         .query_wasm_smart(contract_addr.clone(), &QueryMsg::Info)
         .expect("info query succeeds");
 
-    assert_eq!(info.open_interest, Some(request));
+    assert_eq!(
+        info.open_interest,
+        Some(OpenInterest {
+            interest_nonce: 1,
+            ..request
+        })
+    );
+    assert_eq!(info.interest_nonce, 1);
 }
 
 #[test]
@@ -85,6 +96,7 @@ fn cannot_open_interest_twice() {
         interest_coin: Coin::new(10u128, "ujuno"),
         expiry_duration: 100,
         collateral: Coin::new(700u128, "uatom"),
+        ..Default::default()
     };
     mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
 
@@ -112,6 +124,7 @@ fn rejects_invalid_inputs() {
         interest_coin: Coin::new(10u128, "ujuno"),
         expiry_duration: 0,
         collateral: Coin::new(700u128, "uatom"),
+        ..Default::default()
     };
     mint_contract_collateral(&mut app, &contract_addr, &invalid_request.collateral);
 
@@ -140,6 +153,7 @@ fn owner_can_close_pending_open_interest() {
         interest_coin: Coin::new(50u128, "ujuno"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(2_000u128, "uatom"),
+        ..Default::default()
     };
     mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
 
@@ -198,17 +212,12 @@ fn lender_can_fund_open_interest_and_refund_counter_offers() {
         interest_coin: Coin::new(50u128, "uinterest"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(2_000u128, "ucollateral"),
+        ..Default::default()
     };
 
     mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
 
-    app.execute_contract(
-        owner.clone(),
-        contract_addr.clone(),
-        &ExecuteMsg::OpenInterest(open_interest.clone()),
-        &[],
-    )
-    .expect("open interest set");
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
 
     let proposer_a = app.api().addr_make("bidder-a");
     let proposer_b = app.api().addr_make("bidder-b");
@@ -301,17 +310,12 @@ fn owner_can_repay_funded_open_interest() {
         interest_coin: Coin::new(50u128, DENOM),
         expiry_duration: 86_400u64,
         collateral: Coin::new(2_000u128, "ucollateral"),
+        ..Default::default()
     };
 
     mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
 
-    app.execute_contract(
-        owner.clone(),
-        contract_addr.clone(),
-        &ExecuteMsg::OpenInterest(open_interest.clone()),
-        &[],
-    )
-    .expect("open interest set");
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
 
     let lender = app.api().addr_make("lender");
     app.send_tokens(owner.clone(), lender.clone(), &coins(5_000, DENOM))
@@ -397,10 +401,11 @@ fn liquidate_claims_rewards_before_payout() {
     let (mut app, contract_addr, owner) = instantiate_vault();
 
     let open_interest = OpenInterest {
-        liquidity_coin: Coin::new(2_000u128, DENOM),
+        liquidity_coin: Coin::new(2_000u128, "uusd"),
         interest_coin: Coin::new(50u128, "uinterest"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(1_000u128, DENOM),
+        ..Default::default()
     };
 
     let liquidity_amount = Uint128::try_from(open_interest.liquidity_coin.amount)
@@ -408,23 +413,13 @@ fn liquidate_claims_rewards_before_payout() {
     let collateral_amount = Uint128::try_from(open_interest.collateral.amount)
         .expect("collateral amount fits in Uint128");
 
-    mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
+    // Half of this is delegated below; the rest stays liquid to cover the debt.
+    mint_contract_collateral(&mut app, &contract_addr, &Coin::new(2_000u128, DENOM));
 
-    app.execute_contract(
-        owner.clone(),
-        contract_addr.clone(),
-        &ExecuteMsg::OpenInterest(open_interest.clone()),
-        &[],
-    )
-    .expect("open interest set");
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
 
     let lender = app.api().addr_make("lender");
-    app.send_tokens(
-        owner.clone(),
-        lender.clone(),
-        &coins(liquidity_amount.u128(), DENOM),
-    )
-    .expect("fund lender");
+    mint_contract_collateral(&mut app, &lender, &open_interest.liquidity_coin);
 
     app.execute_contract(
         lender.clone(),
@@ -439,7 +434,7 @@ fn liquidate_claims_rewards_before_payout() {
         .query_all_validators()
         .expect("validator query succeeds");
     let validator = validators
-        .get(0)
+        .first()
         .expect("at least one validator")
         .address
         .clone();
@@ -450,6 +445,7 @@ fn liquidate_claims_rewards_before_payout() {
         &ExecuteMsg::Delegate {
             validator,
             amount: collateral_amount,
+            max_commission: None,
         },
         &[],
     )
@@ -459,13 +455,13 @@ fn liquidate_claims_rewards_before_payout() {
         owner.clone(),
         contract_addr.clone(),
         &ExecuteMsg::Withdraw {
-            denom: DENOM.to_string(),
-            amount: collateral_amount,
+            denom: "uusd".to_string(),
+            amount: liquidity_amount,
             recipient: None,
         },
         &[],
     )
-    .expect("withdraws collateral before liquidation");
+    .expect("withdraws borrowed liquidity before liquidation");
 
     app.update_block(|block| {
         block.height += 1_000;
@@ -522,31 +518,20 @@ fn liquidate_defers_state_clear_until_funds_arrive() {
     let (mut app, contract_addr, owner) = instantiate_vault();
 
     let open_interest = OpenInterest {
-        liquidity_coin: Coin::new(1_000u128, DENOM),
+        liquidity_coin: Coin::new(1_000u128, "uusd"),
         interest_coin: Coin::new(25u128, "uinterest"),
         expiry_duration: 86_400u64,
         collateral: Coin::new(1_000u128, DENOM),
+        ..Default::default()
     };
 
-    mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
+    // The delegation below must leave the reserved collateral liquid, so mint it twice over.
+    mint_contract_collateral(&mut app, &contract_addr, &Coin::new(2_000u128, DENOM));
 
-    app.execute_contract(
-        owner.clone(),
-        contract_addr.clone(),
-        &ExecuteMsg::OpenInterest(open_interest.clone()),
-        &[],
-    )
-    .expect("open interest set");
+    let open_interest = open_stamped_interest(&mut app, &contract_addr, &owner, &open_interest);
 
     let lender = app.api().addr_make("lender");
-    let liquidity_amount = Uint128::try_from(open_interest.liquidity_coin.amount)
-        .expect("liquidity amount fits in Uint128");
-    app.send_tokens(
-        owner.clone(),
-        lender.clone(),
-        &coins(liquidity_amount.u128(), DENOM),
-    )
-    .expect("fund lender");
+    mint_contract_collateral(&mut app, &lender, &open_interest.liquidity_coin);
 
     app.execute_contract(
         lender.clone(),
@@ -561,7 +546,7 @@ fn liquidate_defers_state_clear_until_funds_arrive() {
         .query_all_validators()
         .expect("validator query succeeds");
     let validator = validators
-        .get(0)
+        .first()
         .expect("at least one validator")
         .address
         .clone();
@@ -574,6 +559,7 @@ fn liquidate_defers_state_clear_until_funds_arrive() {
         &ExecuteMsg::Delegate {
             validator,
             amount: collateral_amount,
+            max_commission: None,
         },
         &[],
     )
@@ -622,6 +608,20 @@ fn liquidate_defers_state_clear_until_funds_arrive() {
 
     mint_contract_collateral(&mut app, &contract_addr, &open_interest.collateral);
 
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::LiquidateOpenInterest {},
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Liquidation already ran in this block"));
+
+    app.update_block(|block| block.height += 1);
+
     app.execute_contract(
         owner.clone(),
         contract_addr.clone(),
@@ -18,6 +18,7 @@ fn owner_can_redelegate_between_validators() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -44,6 +45,7 @@ fn owner_can_redelegate_between_validators() {
         &ExecuteMsg::Delegate {
             validator: src_validator.clone(),
             amount: Uint128::new(600),
+            max_commission: None,
         },
         &[],
     )
@@ -107,6 +109,7 @@ fn non_owner_cannot_redelegate() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
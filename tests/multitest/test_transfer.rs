@@ -22,6 +22,7 @@ fn owner_can_transfer_ownership() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -34,6 +35,7 @@ fn owner_can_transfer_ownership() {
         contract_addr.clone(),
         &ExecuteMsg::TransferOwnership {
             new_owner: new_owner.to_string(),
+            validate_receiver: None,
         },
         &[],
     )
@@ -62,6 +64,7 @@ fn non_owner_cannot_transfer_ownership() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -75,6 +78,7 @@ fn non_owner_cannot_transfer_ownership() {
             contract_addr,
             &ExecuteMsg::TransferOwnership {
                 new_owner: new_owner.to_string(),
+                validate_receiver: None,
             },
             &[],
         )
@@ -82,3 +86,59 @@ fn non_owner_cannot_transfer_ownership() {
 
     assert!(err.to_string().contains("Unauthorized"));
 }
+
+#[test]
+fn validated_transfer_accepts_contract_and_rejects_eoa() {
+    let mut app = mock_app();
+    let code_id = store_contract(&mut app);
+
+    let owner = app.api().addr_make("owner");
+    let eoa = app.api().addr_make("eoa");
+
+    let instantiate_vault = |app: &mut cw_multi_test::BasicApp, label: &str| {
+        app.instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: Some(owner.to_string()),
+                ..Default::default()
+            },
+            &[],
+            label,
+            None,
+        )
+        .expect("instantiate succeeds")
+    };
+    let contract_addr = instantiate_vault(&mut app, "vault");
+    let receiver = instantiate_vault(&mut app, "receiver");
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::TransferOwnership {
+                new_owner: eoa.to_string(),
+                validate_receiver: Some(true),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("is not a compatible contract"));
+
+    app.execute_contract(
+        owner.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::TransferOwnership {
+            new_owner: receiver.to_string(),
+            validate_receiver: Some(true),
+        },
+        &[],
+    )
+    .expect("transfer to contract succeeds");
+
+    let saved_owner = OWNER
+        .query(&app.wrap(), contract_addr)
+        .expect("owner must be stored");
+
+    assert_eq!(saved_owner, receiver);
+}
@@ -18,6 +18,7 @@ fn owner_can_undelegate_staked_funds() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -44,6 +45,7 @@ fn owner_can_undelegate_staked_funds() {
         &ExecuteMsg::Delegate {
             validator: validator.clone(),
             amount: delegate_amount,
+            max_commission: None,
         },
         &[],
     )
@@ -104,6 +106,7 @@ fn non_owner_cannot_undelegate() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -139,6 +142,7 @@ fn undelegate_zero_amount_fails() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -176,6 +180,7 @@ fn undelegate_more_than_delegated_fails() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -202,6 +207,7 @@ fn undelegate_more_than_delegated_fails() {
         &ExecuteMsg::Delegate {
             validator: validator.clone(),
             amount: delegate_amount,
+            max_commission: None,
         },
         &[],
     )
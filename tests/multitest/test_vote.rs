@@ -2,7 +2,7 @@ use cosmwasm_std::{Decimal, VoteOption, WeightedVoteOption};
 use cw_multi_test::Executor;
 
 use crate::common::{mock_app, mock_app_with_gov_accepting, store_contract};
-use wasm_vault::msg::{ExecuteMsg, InstantiateMsg};
+use wasm_vault::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VoteRecord};
 
 #[test]
 fn owner_can_cast_standard_vote_when_gov_accepts() {
@@ -17,6 +17,7 @@ fn owner_can_cast_standard_vote_when_gov_accepts() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -58,6 +59,7 @@ fn owner_can_cast_weighted_vote_when_gov_accepts() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -65,27 +67,36 @@ fn owner_can_cast_weighted_vote_when_gov_accepts() {
         )
         .expect("instantiate succeeds");
 
+    let options = vec![
+        WeightedVoteOption {
+            option: VoteOption::Yes,
+            weight: Decimal::percent(70),
+        },
+        WeightedVoteOption {
+            option: VoteOption::No,
+            weight: Decimal::percent(30),
+        },
+    ];
     let response = app
         .execute_contract(
             owner.clone(),
-            contract_addr,
+            contract_addr.clone(),
             &ExecuteMsg::VoteWeighted {
                 proposal_id: 42,
-                options: vec![
-                    WeightedVoteOption {
-                        option: VoteOption::Yes,
-                        weight: Decimal::percent(70),
-                    },
-                    WeightedVoteOption {
-                        option: VoteOption::No,
-                        weight: Decimal::percent(30),
-                    },
-                ],
+                options: options.clone(),
             },
             &[],
         )
         .expect("weighted vote should succeed when gov accepts");
 
+    let recorded: Option<VoteRecord> = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::Vote { proposal_id: 42 })
+        .expect("vote query succeeds");
+    let recorded = recorded.expect("vote recorded");
+    assert_eq!(recorded.options, options);
+    assert_eq!(recorded.height, app.block_info().height);
+
     assert!(response.events.iter().any(|event| {
         event.ty == "wasm"
             && event.attributes.iter().any(|attr| {
@@ -108,6 +119,7 @@ fn vote_fails_when_gov_module_rejects() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -146,6 +158,7 @@ fn non_owner_cannot_vote_even_when_gov_accepts() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
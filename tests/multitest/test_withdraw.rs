@@ -18,6 +18,7 @@ fn owner_can_withdraw_to_self() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -99,6 +100,7 @@ fn owner_can_withdraw_to_custom_recipient() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -170,6 +172,7 @@ fn non_owner_cannot_withdraw() {
             &InstantiateMsg {
                 owner: Some(owner.to_string()),
                 liquidation_unbonding_duration: None,
+                ..Default::default()
             },
             &[],
             "vault",
@@ -192,3 +195,45 @@ fn non_owner_cannot_withdraw() {
 
     assert!(err.to_string().contains("Unauthorized"));
 }
+
+#[test]
+fn withdraw_with_attached_funds_is_rejected() {
+    let mut app = mock_app();
+    let code_id = store_contract(&mut app);
+
+    let owner = app.api().addr_make("creator");
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: Some(owner.to_string()),
+                ..Default::default()
+            },
+            &coins(100, DENOM),
+            "vault",
+            None,
+        )
+        .expect("instantiate succeeds");
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Withdraw {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(10),
+                recipient: None,
+            },
+            &coins(5, DENOM),
+        )
+        .unwrap_err();
+
+    assert!(err.to_string().contains("does not accept funds"));
+
+    let balance = app
+        .wrap()
+        .query_balance(contract_addr, DENOM)
+        .expect("balance query succeeds");
+    assert_eq!(balance.amount, Uint256::from(100u128));
+}